@@ -0,0 +1,258 @@
+//! Procedural macros for [`ratatui-base16`](https://docs.rs/ratatui-base16).
+//!
+//! This crate is re-exported by `ratatui-base16` when its `macros` feature is
+//! enabled and should not be used directly.
+
+use std::path::{Path, PathBuf};
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use serde_json::{Map, Value};
+use syn::{parse_macro_input, LitStr};
+
+const SLOTS: [&str; 16] = [
+    "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07", "base08",
+    "base09", "base0a", "base0b", "base0c", "base0d", "base0e", "base0f",
+];
+
+/// Embeds a YAML, TOML or JSON scheme file as a `Base16Palette` expression
+/// that can initialize a `const`.
+///
+/// The path is resolved relative to the `CARGO_MANIFEST_DIR` of the crate
+/// invoking the macro, and the file is parsed at compile time, so a missing
+/// or malformed scheme fails the build instead of being reported at runtime.
+/// The crate is rebuilt whenever the file changes.
+///
+/// Colors are read like the runtime loaders of `ratatui-base16` read them,
+/// limited to the notations that name an exact color:
+///
+/// - `"#rrggbb"` strings, also written `"rrggbb"`, `"0xrrggbb"` or
+///   `"0Xrrggbb"`,
+/// - `"#rrggbbaa"` and `"rrggbbaa"` strings with an alpha channel,
+/// - the `"#rgb"` shorthand,
+/// - `"reset"` and `"default"`, in any case, for the terminal's own color,
+/// - `0xRRGGBB` integers, except in YAML, where unquoted digits such as
+///   `282828` are hex digits,
+/// - `[r, g, b]` arrays and `{ r, g, b }` tables, optionally with a fourth,
+///   alpha channel.
+///
+/// Color names, ANSI indexes such as `"4"` and CSS functions such as
+/// `"rgb(40, 41, 54)"`, which the runtime loaders also accept, fail the
+/// build, as do surrounding spaces.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use ratatui_base16::{base16, Base16Palette};
+///
+/// const GRUVBOX: Base16Palette = base16!("themes/gruvbox.yaml");
+/// ```
+#[proc_macro]
+pub fn base16(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    match expand(&literal.value()) {
+        Ok(tokens) => tokens.into(),
+        Err(message) => syn::Error::new(literal.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+fn expand(file: &str) -> Result<TokenStream2, String> {
+    let path = resolve(file)?;
+    let scheme = read(&path)?;
+
+    let text = |keys: &[&str]| -> Result<String, String> {
+        match keys.iter().find_map(|key| scheme.get(*key)) {
+            None => Ok(String::new()),
+            Some(Value::String(value)) => Ok(value.clone()),
+            Some(value) => Err(format!("`{}` must be a string, found {value}", keys[0])),
+        }
+    };
+    let name = text(&["scheme", "name"])?;
+    let author = text(&["author"])?;
+    let slug = text(&["slug"])?;
+
+    let mut colors = Vec::with_capacity(16);
+    let mut alphas = Vec::with_capacity(16);
+    for slot in SLOTS {
+        let value = scheme
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(slot))
+            .map(|(_, value)| value)
+            .ok_or_else(|| format!("missing field `{slot}` in {}", path.display()))?;
+        let (color, alpha) = color(value)
+            .ok_or_else(|| format!("invalid color {value} for `{slot}` in {}", path.display()))?;
+        colors.push(color);
+        alphas.push(alpha);
+    }
+
+    let path = path.to_string_lossy();
+    let slots = SLOTS.map(|slot| syn::Ident::new(slot, proc_macro2::Span::call_site()));
+    Ok(quote! {
+        {
+            const _: &[u8] = include_bytes!(#path);
+            ::ratatui_base16::Base16Palette {
+                name: ::ratatui_base16::__private::Cow::Borrowed(#name),
+                author: ::ratatui_base16::__private::Cow::Borrowed(#author),
+                slug: ::ratatui_base16::__private::Cow::Borrowed(#slug),
+                #(#slots: #colors,)*
+                alpha: [#(#alphas),*],
+            }
+        }
+    })
+}
+
+/// Resolves `file` against the manifest directory of the invoking crate.
+fn resolve(file: &str) -> Result<PathBuf, String> {
+    let file = Path::new(file);
+    if file.is_absolute() {
+        return Ok(file.to_path_buf());
+    }
+    let root = std::env::var_os("CARGO_MANIFEST_DIR")
+        .ok_or_else(|| "CARGO_MANIFEST_DIR is not set".to_string())?;
+    Ok(Path::new(&root).join(file))
+}
+
+/// Reads a scheme file into a map, picking the format from its extension.
+fn read(path: &Path) -> Result<Map<String, Value>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| format!("unable to read {}: {error}", path.display()))?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let value = match extension.as_str() {
+        "yaml" | "yml" => serde_yaml::from_str::<serde_yaml::Value>(&text)
+            .map_err(|error| error.to_string())
+            .and_then(|value| serde_json::to_value(value).map_err(|error| error.to_string()))
+            .map(|mut value| {
+                if let Value::Object(map) = &mut value {
+                    ratatui_base16_yaml::hex_integers(&text, map, |key| {
+                        SLOTS.iter().any(|slot| slot.eq_ignore_ascii_case(key))
+                    });
+                }
+                value
+            }),
+        "toml" => toml::from_str::<toml::Value>(&text)
+            .map_err(|error| error.to_string())
+            .and_then(|value| serde_json::to_value(value).map_err(|error| error.to_string())),
+        "json" => serde_json::from_str::<Value>(&text).map_err(|error| error.to_string()),
+        _ => return Err(format!("unsupported palette format: {extension:?}")),
+    }
+    .map_err(|error| format!("unable to parse {}: {error}", path.display()))?;
+    match value {
+        Value::Object(map) => Ok(map),
+        _ => Err(format!("{} does not contain a table", path.display())),
+    }
+}
+
+/// Converts a slot value into a `Color` expression and its alpha channel.
+fn color(value: &Value) -> Option<(TokenStream2, u8)> {
+    let rgb = |r: u8, g: u8, b: u8| quote!(::ratatui_base16::__private::Color::Rgb(#r, #g, #b));
+    let channel = |value: &Value| value.as_u64().and_then(|value| u8::try_from(value).ok());
+    match value {
+        Value::String(s) => {
+            if s.eq_ignore_ascii_case("reset") || s.eq_ignore_ascii_case("default") {
+                return Some((quote!(::ratatui_base16::__private::Color::Reset), 0xff));
+            }
+            // The lengths the runtime parser reads as hex after each prefix.
+            let (hex, lengths): (&str, &[usize]) = if let Some(hex) = s.strip_prefix('#') {
+                (hex, &[3, 6, 8])
+            } else if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                (hex, &[6])
+            } else {
+                (s, &[6, 8])
+            };
+            if !lengths.contains(&hex.len()) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            let digits = u32::from_str_radix(hex, 16).ok()?;
+            match hex.len() {
+                3 => {
+                    let digit = |shift: u32| ((digits >> shift) & 0xf) as u8 * 17;
+                    Some((rgb(digit(8), digit(4), digit(0)), 0xff))
+                }
+                6 => {
+                    let [_, r, g, b] = digits.to_be_bytes();
+                    Some((rgb(r, g, b), 0xff))
+                }
+                8 => {
+                    let [r, g, b, a] = digits.to_be_bytes();
+                    Some((rgb(r, g, b), a))
+                }
+                _ => None,
+            }
+        }
+        Value::Number(number) => {
+            let value = u32::try_from(number.as_u64()?).ok()?;
+            let [_, r, g, b] = value.to_be_bytes();
+            (value <= 0xff_ff_ff).then(|| (rgb(r, g, b), 0xff))
+        }
+        Value::Array(channels) => match channels.as_slice() {
+            [r, g, b] => Some((rgb(channel(r)?, channel(g)?, channel(b)?), 0xff)),
+            [r, g, b, a] => Some((rgb(channel(r)?, channel(g)?, channel(b)?), channel(a)?)),
+            _ => None,
+        },
+        Value::Object(channels) => {
+            let get = |short: &str, long: &str| channels.get(short).or_else(|| channels.get(long));
+            if channels.keys().any(|key| {
+                !["r", "g", "b", "a", "red", "green", "blue", "alpha"].contains(&key.as_str())
+            }) {
+                return None;
+            }
+            let alpha = match get("a", "alpha") {
+                Some(alpha) => channel(alpha)?,
+                None => 0xff,
+            };
+            let (r, g, b) = (
+                channel(get("r", "red")?)?,
+                channel(get("g", "green")?)?,
+                channel(get("b", "blue")?)?,
+            );
+            Some((rgb(r, g, b), alpha))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn only_exact_runtime_notations_are_read() {
+        for accepted in [
+            json!("#282936"),
+            json!("282936"),
+            json!("0X282936"),
+            json!("#28293680"),
+            json!("28293680"),
+            json!("#fff"),
+            json!("Default"),
+            json!(0x282936),
+            json!([40, 41, 54, 128]),
+            json!({ "red": 40, "g": 41, "b": 54 }),
+        ] {
+            assert!(color(&accepted).is_some(), "{accepted}");
+        }
+        // Either read differently at runtime, as ANSI index 123 or a CSS
+        // color, or not read at all.
+        for rejected in [
+            json!("123"),
+            json!("fff"),
+            json!("coral"),
+            json!("rgb(40, 41, 54)"),
+            json!("0x28293680"),
+            json!(" #282936"),
+            json!(0x1000000),
+            json!([40, 41]),
+        ] {
+            assert!(color(&rejected).is_none(), "{rejected}");
+        }
+    }
+}
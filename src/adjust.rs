@@ -0,0 +1,186 @@
+//! Adjustments applied to every color of a palette.
+
+use crate::{color, Base16Color, Base16Palette};
+
+/// The correlated color temperature of the sRGB white point, D65, in
+/// kelvin.
+const D65_KELVIN: f64 = 6504.0;
+
+/// The Bradford matrix from CIE XYZ to the cone response domain.
+const BRADFORD: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// The inverse of [`BRADFORD`].
+const BRADFORD_INVERSE: [[f64; 3]; 3] = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+/// The matrix from linear sRGB to CIE XYZ.
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.0721750],
+    [0.0193339, 0.1191920, 0.9503041],
+];
+
+/// The matrix from CIE XYZ to linear sRGB.
+const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.9692660, 1.8760108, 0.0415560],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+impl Base16Palette {
+    /// Returns the palette with its colors adapted to a white point `delta`
+    /// kelvin away from the 6504 K of sRGB, as if lit by a warmer light for
+    /// negative `delta` and a cooler one for positive `delta`.
+    ///
+    /// The white points lie on the Planckian locus, between 1667 K and
+    /// 25000 K, and colors are adapted with the Bradford transform, so
+    /// grays take on the tint of the new white while accents keep their
+    /// relative differences. Shifts of a few hundred kelvin are enough to
+    /// compensate for a display that looks too blue or too yellow.
+    /// `Color::Reset` slots are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::DEFAULT_DARK;
+    /// let warm = DEFAULT_DARK.with_temperature(-1500.0);
+    /// let Color::Rgb(r, _, b) = warm.base05 else { unreachable!() };
+    /// assert!(r > b);
+    /// assert_eq!(DEFAULT_DARK.with_temperature(0.0), DEFAULT_DARK);
+    /// ```
+    #[must_use = "method returns a new palette and does not modify the original"]
+    pub fn with_temperature(mut self, delta: f64) -> Self {
+        if delta == 0.0 {
+            return self;
+        }
+        let adaptation = adaptation(white_point(D65_KELVIN), white_point(D65_KELVIN + delta));
+        for slot in Base16Color::ALL {
+            let color = self.color_mut(slot);
+            if let Some(linear) = color::to_linear(*color) {
+                let adapted = multiply(
+                    &XYZ_TO_SRGB,
+                    multiply(&adaptation, multiply(&SRGB_TO_XYZ, linear)),
+                );
+                *color = color::from_linear(adapted);
+            }
+        }
+        self
+    }
+}
+
+impl Base16Palette {
+    /// Returns a palette blending each slot of this palette with the same
+    /// slot of `other`, `weight` of the way towards `other`.
+    ///
+    /// Colors are blended in linear light like [`blend`](crate::blend),
+    /// and the alpha values of the slots are interpolated. The name, author
+    /// and slug are those of this palette, so a mostly-this palette with a
+    /// touch of `other` keeps its identity; rename the result as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{DEFAULT_DARK, DRACULA};
+    /// let hybrid = DEFAULT_DARK.mix(&DRACULA, 0.1);
+    /// assert_eq!(hybrid.name, DEFAULT_DARK.name);
+    /// assert_eq!(DEFAULT_DARK.mix(&DRACULA, 1.0).to_array(), DRACULA.to_array());
+    /// ```
+    #[must_use = "method returns a new palette and does not modify the original"]
+    pub fn mix(&self, other: &Base16Palette, weight: f64) -> Self {
+        let mut mixed = self.clone();
+        for slot in Base16Color::ALL {
+            *mixed.color_mut(slot) = color::blend(self.color(slot), other.color(slot), weight);
+        }
+        let weight = weight.clamp(0.0, 1.0);
+        for (alpha, other) in mixed.alpha.iter_mut().zip(other.alpha) {
+            *alpha =
+                (f64::from(*alpha) + (f64::from(other) - f64::from(*alpha)) * weight).round() as u8;
+        }
+        mixed
+    }
+}
+
+/// Returns the CIE XYZ of the white of a black body at `kelvin`, with a
+/// luminance of one, from the cubic spline approximation of the Planckian
+/// locus by Kim et al.
+fn white_point(kelvin: f64) -> [f64; 3] {
+    let t = kelvin.clamp(1667.0, 25000.0);
+    let x = if t <= 4000.0 {
+        -0.2661239e9 / t.powi(3) - 0.2343589e6 / t.powi(2) + 0.8776956e3 / t + 0.179910
+    } else {
+        -3.0258469e9 / t.powi(3) + 2.1070379e6 / t.powi(2) + 0.2226347e3 / t + 0.240390
+    };
+    let y = if t <= 2222.0 {
+        -1.1063814 * x.powi(3) - 1.34811020 * x.powi(2) + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x.powi(3) - 1.37418593 * x.powi(2) + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x.powi(3) - 5.87338670 * x.powi(2) + 3.75112997 * x - 0.37001483
+    };
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+/// Returns the Bradford adaptation matrix in CIE XYZ from the white point
+/// `from` to the white point `to`.
+fn adaptation(from: [f64; 3], to: [f64; 3]) -> [[f64; 3]; 3] {
+    let (from, to) = (multiply(&BRADFORD, from), multiply(&BRADFORD, to));
+    let mut scaled = BRADFORD;
+    for (row, (to, from)) in scaled.iter_mut().zip(to.into_iter().zip(from)) {
+        for value in row {
+            *value *= to / from;
+        }
+    }
+    let mut matrix = [[0.0; 3]; 3];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| BRADFORD_INVERSE[i][k] * scaled[k][j]).sum();
+        }
+    }
+    matrix
+}
+
+fn multiply(matrix: &[[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+    use crate::DEFAULT_LIGHT;
+
+    #[test]
+    fn cooler_whites_turn_blue() {
+        let cool = DEFAULT_LIGHT.with_temperature(3000.0);
+        let Color::Rgb(r, g, b) = cool.base00 else {
+            panic!("expected an RGB background");
+        };
+        assert!(b > g && g > r, "{:?}", cool.base00);
+        // Small shifts only tint the colors.
+        let slight = DEFAULT_LIGHT.with_temperature(-300.0);
+        assert!(color::delta_e(slight.base0d, DEFAULT_LIGHT.base0d).unwrap() < 3.0);
+        assert_ne!(slight.base0d, DEFAULT_LIGHT.base0d);
+    }
+
+    #[test]
+    fn mixed_palettes_sit_between_their_parents() {
+        let mut other = crate::DRACULA;
+        other.alpha[0] = 0;
+        let half = DEFAULT_LIGHT.mix(&other, 0.5);
+        assert_eq!(half.alpha[0], 0x80);
+        assert_eq!(
+            half.base08,
+            crate::blend(DEFAULT_LIGHT.base08, other.base08, 0.5)
+        );
+        assert_eq!(DEFAULT_LIGHT.mix(&other, 0.0), DEFAULT_LIGHT);
+    }
+}
@@ -0,0 +1,74 @@
+//! Compositing of translucent palette colors.
+
+use ratatui::style::Color;
+
+use crate::{color, Base16Color, Base16Palette};
+
+impl Base16Palette {
+    /// Returns the color of `slot` composited over `background` according to
+    /// its [`alpha`](Base16Palette::alpha) channel, mixing them in linear
+    /// light like [`blend`](crate::blend).
+    ///
+    /// Opaque slots are returned unchanged. If either color has no concrete
+    /// RGB value, such as `Color::Reset`, the result is whichever of the two
+    /// dominates: the slot color when it is at least half opaque, otherwise
+    /// the background.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::{Base16Color, DRACULA};
+    /// let mut palette = DRACULA.with_base01(Color::Rgb(0, 0, 0));
+    /// palette.alpha[Base16Color::Base01 as usize] = 0x80;
+    /// let shadow = palette.blend(Base16Color::Base01, Color::Rgb(255, 255, 255));
+    /// assert_eq!(shadow, Color::Rgb(187, 187, 187));
+    /// ```
+    pub fn blend(&self, slot: Base16Color, background: Color) -> Color {
+        let alpha = self.alpha[slot as usize];
+        color::blend(background, self.color(slot), f64::from(alpha) / 255.0)
+    }
+
+    /// Returns an opaque copy of the palette with every translucent slot
+    /// composited over `background`.
+    ///
+    /// Use this to turn colors such as popup or shadow overlays from a scheme
+    /// with `#rrggbbaa` values into concrete colors ratatui can render.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let palette = DRACULA.blend_over(DRACULA.base00);
+    /// assert_eq!(palette, DRACULA);
+    /// ```
+    #[must_use = "method returns a new palette and does not modify the original"]
+    pub fn blend_over(&self, background: Color) -> Self {
+        let mut palette = self.clone();
+        for slot in Base16Color::ALL {
+            *palette.color_mut(slot) = self.blend(slot, background);
+        }
+        palette.alpha = [0xff; 16];
+        palette
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn blend_over_composites_translucent_slots() {
+        let mut palette = DEFAULT_DARK.with_base0d(Color::Rgb(0xff, 0x00, 0x00));
+        palette.alpha[Base16Color::Base0D as usize] = 0x40;
+        let blended = palette.blend_over(Color::Rgb(0x00, 0x00, 0xff));
+        assert_eq!(blended.base0d, Color::Rgb(0x89, 0x00, 0xe0));
+        assert_eq!(blended.base08, DEFAULT_DARK.base08);
+        assert_eq!(blended.alpha, [0xff; 16]);
+        assert_eq!(
+            palette.blend(Base16Color::Base0D, Color::Reset),
+            Color::Reset
+        );
+    }
+}
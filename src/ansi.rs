@@ -0,0 +1,54 @@
+//! Rendering of palettes with raw terminal escape sequences.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+
+use crate::{color, Base16Color, Base16Palette};
+
+impl Base16Palette {
+    /// Returns a swatch of the palette rendered with 24-bit color escape
+    /// sequences, suitable for printing outside of a ratatui session.
+    ///
+    /// The first line holds the scheme name, followed by one line per slot
+    /// with a colored block, the slot name and its hex value. Slots set to
+    /// `Color::Reset` use the terminal's default background.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let preview = DRACULA.to_ansi_preview();
+    /// assert!(preview.contains("\x1b[48;2;40;41;54m"));
+    /// println!("{preview}");
+    /// ```
+    pub fn to_ansi_preview(&self) -> String {
+        let mut out = format!("{}\n", self.name);
+        for slot in Base16Color::ALL {
+            let color = self.color(slot);
+            let (background, hex) = match color::to_rgb(color) {
+                Some((r, g, b)) => (
+                    format!("\x1b[48;2;{r};{g};{b}m"),
+                    format!("#{r:02x}{g:02x}{b:02x}"),
+                ),
+                None => ("\x1b[49m".to_string(), "reset".to_string()),
+            };
+            let _ = writeln!(out, "{background}      \x1b[0m {} {hex}", slot.name());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn ansi_preview_lists_every_slot() {
+        let preview = DEFAULT_DARK.to_ansi_preview();
+        let lines: Vec<_> = preview.lines().collect();
+        assert_eq!(lines.len(), 17);
+        assert_eq!(lines[0], "Default Dark");
+        assert_eq!(lines[1], "\x1b[48;2;24;24;24m      \x1b[0m base00 #181818");
+    }
+}
@@ -0,0 +1,164 @@
+//! Detection of the light or dark appearance of the desktop.
+
+use std::process::Command;
+
+use crate::{Base16Palette, Variant};
+
+/// The appearance the user chose for their desktop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemAppearance {
+    /// The desktop uses a light appearance.
+    Light,
+    /// The desktop uses a dark appearance.
+    Dark,
+    /// The appearance couldn't be determined, or the user has no preference.
+    Unknown,
+}
+
+impl SystemAppearance {
+    /// Queries the desktop for its appearance.
+    ///
+    /// On macOS this reads the `AppleInterfaceStyle` default, on Windows the
+    /// `AppsUseLightTheme` registry value, and elsewhere the
+    /// `org.freedesktop.appearance color-scheme` setting of the desktop
+    /// portal, falling back to GNOME's `color-scheme` setting. The tools
+    /// shipped with each platform are used for the queries, so this spawns a
+    /// short-lived process and shouldn't be called on every frame.
+    pub fn detect() -> Self {
+        if cfg!(target_os = "macos") {
+            // The key only exists while the dark appearance is active.
+            let output = run("defaults", &["read", "-g", "AppleInterfaceStyle"]);
+            return parse_macos(output.as_deref().unwrap_or_default());
+        }
+        if cfg!(windows) {
+            let key = r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+            let output = run("reg", &["query", key, "/v", "AppsUseLightTheme"]);
+            return output.map_or(Self::Unknown, |output| parse_windows(&output));
+        }
+        let portal = run(
+            "gdbus",
+            &[
+                "call",
+                "--session",
+                "--dest",
+                "org.freedesktop.portal.Desktop",
+                "--object-path",
+                "/org/freedesktop/portal/desktop",
+                "--method",
+                "org.freedesktop.portal.Settings.Read",
+                "org.freedesktop.appearance",
+                "color-scheme",
+            ],
+        );
+        match portal.map(|output| parse_portal(&output)) {
+            Some(appearance) if appearance != Self::Unknown => appearance,
+            _ => run(
+                "gsettings",
+                &["get", "org.gnome.desktop.interface", "color-scheme"],
+            )
+            .map_or(Self::Unknown, |output| parse_gsettings(&output)),
+        }
+    }
+
+    /// Returns the variant matching the appearance, if it is known.
+    pub fn variant(self) -> Option<Variant> {
+        match self {
+            Self::Light => Some(Variant::Light),
+            Self::Dark => Some(Variant::Dark),
+            Self::Unknown => None,
+        }
+    }
+}
+
+impl Base16Palette {
+    /// Returns `light` or `dark` to match the desktop appearance, as
+    /// reported by [`SystemAppearance::detect`].
+    ///
+    /// Terminals are dark more often than not, so `dark` is returned when
+    /// the appearance can't be determined.
+    pub fn for_system(light: Base16Palette, dark: Base16Palette) -> Base16Palette {
+        match SystemAppearance::detect() {
+            SystemAppearance::Light => light,
+            SystemAppearance::Dark | SystemAppearance::Unknown => dark,
+        }
+    }
+}
+
+/// Runs `program` and returns its standard output if it succeeds.
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_macos(output: &str) -> SystemAppearance {
+    if output.trim().eq_ignore_ascii_case("dark") {
+        SystemAppearance::Dark
+    } else {
+        SystemAppearance::Light
+    }
+}
+
+/// Reads the `REG_DWORD` printed by `reg query`, e.g.
+/// `AppsUseLightTheme    REG_DWORD    0x0`.
+fn parse_windows(output: &str) -> SystemAppearance {
+    let value = output
+        .lines()
+        .find(|line| line.contains("AppsUseLightTheme"))
+        .and_then(|line| line.split_whitespace().last());
+    match value {
+        Some("0x0") => SystemAppearance::Dark,
+        Some("0x1") => SystemAppearance::Light,
+        _ => SystemAppearance::Unknown,
+    }
+}
+
+/// Reads the reply of the portal, e.g. `(<<uint32 1>>,)`, where `1` means
+/// dark, `2` light and `0` no preference.
+fn parse_portal(output: &str) -> SystemAppearance {
+    let value = output.split("uint32").nth(1).map(|rest| {
+        rest.trim_start()
+            .trim_end_matches(|c: char| !c.is_ascii_digit())
+    });
+    match value {
+        Some("1") => SystemAppearance::Dark,
+        Some("2") => SystemAppearance::Light,
+        _ => SystemAppearance::Unknown,
+    }
+}
+
+/// Reads GNOME's setting, e.g. `'prefer-dark'`.
+fn parse_gsettings(output: &str) -> SystemAppearance {
+    match output.trim().trim_matches('\'') {
+        "prefer-dark" => SystemAppearance::Dark,
+        "prefer-light" => SystemAppearance::Light,
+        _ => SystemAppearance::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platform_replies_are_parsed() {
+        assert_eq!(parse_macos("Dark\n"), SystemAppearance::Dark);
+        assert_eq!(parse_macos(""), SystemAppearance::Light);
+
+        let reg = "\r\nHKEY_CURRENT_USER\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize\r\n    AppsUseLightTheme    REG_DWORD    0x0\r\n\r\n";
+        assert_eq!(parse_windows(reg), SystemAppearance::Dark);
+        assert_eq!(
+            parse_windows(&reg.replace("0x0", "0x1")),
+            SystemAppearance::Light
+        );
+
+        assert_eq!(parse_portal("(<<uint32 1>>,)\n"), SystemAppearance::Dark);
+        assert_eq!(parse_portal("(<<uint32 2>>,)\n"), SystemAppearance::Light);
+        assert_eq!(parse_portal("(<<uint32 0>>,)\n"), SystemAppearance::Unknown);
+
+        assert_eq!(parse_gsettings("'prefer-dark'\n"), SystemAppearance::Dark);
+        assert_eq!(parse_gsettings("'default'\n"), SystemAppearance::Unknown);
+    }
+}
@@ -0,0 +1,146 @@
+//! Applying a palette to the terminal itself with OSC 4, 10, 11 and 12.
+
+use std::io::{self, Write};
+
+use ratatui::style::Color;
+
+use crate::{color, Base16Palette, Base16PaletteError};
+
+/// Resets the sixteen ANSI colors (OSC 104) and the default foreground,
+/// background and cursor colors (OSC 110, 111 and 112).
+const RESTORE: &[u8] = b"\x1b]104\x1b\\\x1b]110\x1b\\\x1b]111\x1b\\\x1b]112\x1b\\";
+
+impl Base16Palette {
+    /// Sets the sixteen ANSI colors and the default foreground, background
+    /// and cursor colors of the terminal to the palette, like base16-shell.
+    ///
+    /// The ANSI colors follow [`Base16Palette::to_ansi`], the foreground and
+    /// cursor use base05 and the background base00. Output that doesn't go
+    /// through ratatui, such as that of a child process or a panic message,
+    /// then matches the theme as well. Slots set to `Color::Reset` reset the
+    /// terminal's color instead.
+    ///
+    /// The returned guard resets the terminal to its configured colors when
+    /// dropped. Terminals don't offer a way to push and pop their colors, so
+    /// colors changed by another program before the guard is dropped are
+    /// reset as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::Io`] if writing to `writer` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let mut out = Vec::new();
+    /// let guard = DRACULA.apply_to_terminal(&mut out).unwrap();
+    /// drop(guard);
+    /// let out = String::from_utf8(out).unwrap();
+    /// assert!(out.starts_with("\x1b]4;0;rgb:28/29/36\x1b\\"));
+    /// assert!(out.ends_with("\x1b]112\x1b\\"));
+    /// ```
+    pub fn apply_to_terminal<W: Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<TerminalThemeGuard<W>, Base16PaletteError> {
+        let mut sequences = String::new();
+        for (index, color) in self.to_ansi().into_iter().enumerate() {
+            match rgb(color) {
+                Some(rgb) => sequences.push_str(&format!("\x1b]4;{index};{rgb}\x1b\\")),
+                None => sequences.push_str(&format!("\x1b]104;{index}\x1b\\")),
+            }
+        }
+        for (code, color) in [(10, self.base05), (11, self.base00), (12, self.base05)] {
+            match rgb(color) {
+                Some(rgb) => sequences.push_str(&format!("\x1b]{code};{rgb}\x1b\\")),
+                None => sequences.push_str(&format!("\x1b]1{code}\x1b\\")),
+            }
+        }
+        writer.write_all(sequences.as_bytes())?;
+        writer.flush()?;
+        Ok(TerminalThemeGuard {
+            writer: Some(writer),
+        })
+    }
+}
+
+/// Formats a color in the `rgb:rr/gg/bb` notation terminals accept.
+fn rgb(color: Color) -> Option<String> {
+    let (r, g, b) = color::to_rgb(color)?;
+    Some(format!("rgb:{r:02x}/{g:02x}/{b:02x}"))
+}
+
+/// Resets the colors of the terminal when dropped.
+///
+/// Returned by [`Base16Palette::apply_to_terminal`]. Errors while writing
+/// the reset sequences on drop are ignored; call
+/// [`TerminalThemeGuard::restore`] to handle them.
+#[derive(Debug)]
+#[must_use = "the terminal colors are reset as soon as the guard is dropped"]
+pub struct TerminalThemeGuard<W: Write> {
+    writer: Option<W>,
+}
+
+impl<W: Write> TerminalThemeGuard<W> {
+    /// Resets the colors of the terminal now.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::Io`] if writing to the writer fails.
+    pub fn restore(mut self) -> Result<(), Base16PaletteError> {
+        if let Some(writer) = &mut self.writer {
+            reset(writer)?;
+        }
+        self.writer = None;
+        Ok(())
+    }
+
+    /// Drops the guard without resetting the colors of the terminal, so
+    /// they stay in place after the program exits.
+    pub fn keep(mut self) {
+        self.writer = None;
+    }
+}
+
+impl<W: Write> Drop for TerminalThemeGuard<W> {
+    fn drop(&mut self) {
+        if let Some(writer) = &mut self.writer {
+            let _ = reset(writer);
+        }
+    }
+}
+
+fn reset(writer: &mut impl Write) -> io::Result<()> {
+    writer.write_all(RESTORE)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn colors_are_applied_and_restored() {
+        let mut out = Vec::new();
+        let guard = DEFAULT_DARK
+            .with_base08(Color::Reset)
+            .apply_to_terminal(&mut out)
+            .unwrap();
+        drop(guard);
+        let out = String::from_utf8(out).unwrap();
+        let sequences: Vec<_> = out.split_terminator("\x1b\\").collect();
+        assert_eq!(sequences.len(), 16 + 3 + 4);
+        assert_eq!(sequences[0], "\x1b]4;0;rgb:18/18/18");
+        assert_eq!(sequences[1], "\x1b]104;1");
+        assert_eq!(sequences[15], "\x1b]4;15;rgb:f8/f8/f8");
+        assert_eq!(sequences[17], "\x1b]11;rgb:18/18/18");
+        assert_eq!(sequences[19], "\x1b]104");
+
+        let mut out = Vec::new();
+        DEFAULT_DARK.apply_to_terminal(&mut out).unwrap().keep();
+        assert!(!String::from_utf8(out).unwrap().contains("\x1b]104\x1b\\"));
+    }
+}
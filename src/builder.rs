@@ -0,0 +1,207 @@
+//! Programmatic construction of palettes.
+
+use alloc::borrow::Cow;
+
+use ratatui::style::Color;
+
+use crate::{Base16Color, Base16Palette, Base16PaletteError, DEFAULT_DARK};
+
+/// A builder for [`Base16Palette`]s, created with [`Base16Palette::builder`].
+///
+/// Slots that are not set explicitly are taken from a fallback palette,
+/// [`DEFAULT_DARK`] unless configured otherwise. Without a fallback,
+/// [`build`](Self::build) requires every slot to be set.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use ratatui_base16::{Base16Palette, DEFAULT_DARK};
+///
+/// let palette = Base16Palette::builder()
+///     .name("Brand")
+///     .base0d(Color::Rgb(0x00, 0x66, 0xcc))
+///     .build()
+///     .unwrap();
+/// assert_eq!(palette.base0d, Color::Rgb(0x00, 0x66, 0xcc));
+/// assert_eq!(palette.base00, DEFAULT_DARK.base00);
+///
+/// let incomplete = Base16Palette::builder()
+///     .without_fallback()
+///     .base00(Color::Black)
+///     .build();
+/// assert!(incomplete.is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Base16PaletteBuilder {
+    name: Cow<'static, str>,
+    author: Cow<'static, str>,
+    slug: Cow<'static, str>,
+    colors: [Option<Color>; 16],
+    fallback: Option<Base16Palette>,
+}
+
+impl Default for Base16PaletteBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! slot_setters {
+    ($($field:ident => $slot:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Sets the color of `", stringify!($field), "`.")]
+            #[must_use = "method moves the value of self and returns the modified value"]
+            pub fn $field(self, color: Color) -> Self {
+                self.color(Base16Color::$slot, color)
+            }
+        )*
+    };
+}
+
+impl Base16PaletteBuilder {
+    /// Creates a builder with no slots set that falls back to
+    /// [`DEFAULT_DARK`].
+    pub const fn new() -> Self {
+        Self {
+            name: Cow::Borrowed(""),
+            author: Cow::Borrowed(""),
+            slug: Cow::Borrowed(""),
+            colors: [None; 16],
+            fallback: Some(DEFAULT_DARK),
+        }
+    }
+
+    /// Sets the scheme name.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the scheme author.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn author(mut self, author: impl Into<Cow<'static, str>>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    /// Sets the scheme slug.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn slug(mut self, slug: impl Into<Cow<'static, str>>) -> Self {
+        self.slug = slug.into();
+        self
+    }
+
+    /// Sets the color of a slot.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn color(mut self, slot: Base16Color, color: Color) -> Self {
+        self.colors[slot as usize] = Some(color);
+        self
+    }
+
+    slot_setters! {
+        base00 => Base00,
+        base01 => Base01,
+        base02 => Base02,
+        base03 => Base03,
+        base04 => Base04,
+        base05 => Base05,
+        base06 => Base06,
+        base07 => Base07,
+        base08 => Base08,
+        base09 => Base09,
+        base0a => Base0A,
+        base0b => Base0B,
+        base0c => Base0C,
+        base0d => Base0D,
+        base0e => Base0E,
+        base0f => Base0F,
+    }
+
+    /// Takes slots that are not set explicitly, with their alpha channels,
+    /// from `palette`. Slots set explicitly are opaque.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn fallback(mut self, palette: Base16Palette) -> Self {
+        self.fallback = Some(palette);
+        self
+    }
+
+    /// Requires every slot to be set explicitly.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn without_fallback(mut self) -> Self {
+        self.fallback = None;
+        self
+    }
+
+    /// Builds the palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::MissingField`] naming the first slot that
+    /// is neither set nor provided by a fallback palette.
+    pub fn build(self) -> Result<Base16Palette, Base16PaletteError> {
+        let mut colors = [Color::Reset; 16];
+        let mut alpha = [0xff; 16];
+        for slot in Base16Color::ALL {
+            let index = slot as usize;
+            colors[index] = match (self.colors[index], &self.fallback) {
+                (Some(color), _) => color,
+                (None, Some(fallback)) => {
+                    alpha[index] = fallback.alpha[index];
+                    fallback.color(slot)
+                }
+                (None, None) => return Err(Base16PaletteError::MissingField(slot.field_name())),
+            };
+        }
+        let mut palette = Base16Palette::from_array(colors, self.name, self.author);
+        palette.slug = self.slug;
+        palette.alpha = alpha;
+        Ok(palette)
+    }
+}
+
+impl Base16Palette {
+    /// Returns a [`Base16PaletteBuilder`] falling back to [`DEFAULT_DARK`].
+    pub const fn builder() -> Base16PaletteBuilder {
+        Base16PaletteBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DRACULA;
+
+    #[test]
+    fn builder_without_fallback_requires_every_slot() {
+        let builder = DRACULA.iter().fold(
+            Base16Palette::builder().without_fallback(),
+            |builder, (slot, color)| builder.color(slot, color),
+        );
+        assert_eq!(
+            builder.clone().build().unwrap().to_array(),
+            DRACULA.to_array()
+        );
+
+        let mut builder = builder;
+        builder.colors[Base16Color::Base05 as usize] = None;
+        assert!(matches!(
+            builder.build(),
+            Err(Base16PaletteError::MissingField("base05"))
+        ));
+    }
+
+    #[test]
+    fn fallback_slots_keep_their_alpha() {
+        let mut translucent = DRACULA;
+        translucent.alpha = [0x80; 16];
+        let palette = Base16Palette::builder()
+            .fallback(translucent)
+            .base00(Color::Black)
+            .build()
+            .unwrap();
+        assert_eq!(palette.alpha[Base16Color::Base00 as usize], 0xff);
+        assert_eq!(palette.alpha[Base16Color::Base01 as usize], 0x80);
+    }
+}
@@ -0,0 +1,159 @@
+//! Memoized loading of scheme files.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{Base16Palette, Base16PaletteError, LoadOptions};
+
+/// A cache of loaded palettes, keyed by the canonical path of their scheme
+/// file and its modification time.
+///
+/// Applications that reload their configuration often, for example on
+/// `SIGHUP` or from a file watcher, can load their theme through the cache
+/// and only pay for parsing when the file has actually changed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::PaletteCache;
+/// let mut cache = PaletteCache::new();
+/// let palette = cache.load(".config/dracula.yaml").unwrap();
+/// assert_eq!(palette.name, "Dracula");
+///
+/// // Unchanged files are served from the cache.
+/// cache.load(".config/dracula.yaml").unwrap();
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PaletteCache {
+    options: LoadOptions,
+    entries: HashMap<PathBuf, Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    modified: Option<SystemTime>,
+    palette: Base16Palette,
+}
+
+impl PaletteCache {
+    /// Creates an empty cache that loads files with the default
+    /// [`LoadOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty cache that loads files with the given
+    /// [`LoadOptions`].
+    pub fn with_options(options: LoadOptions) -> Self {
+        Self {
+            options,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the palette defined by `file`, parsing it only if it isn't
+    /// cached yet or was modified since it was last loaded.
+    ///
+    /// The format is picked from the file extension, as by
+    /// [`Base16Palette::from_file`]. Files whose modification time can't be
+    /// read are parsed on every call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Base16Palette::from_file_with`]. A file that
+    /// fails to load is removed from the cache.
+    pub fn load(&mut self, file: impl AsRef<Path>) -> Result<&Base16Palette, Base16PaletteError> {
+        let file = file.as_ref();
+        let path = file
+            .canonicalize()
+            .map_err(|_| Base16PaletteError::FileNotFound(file.to_path_buf()))?;
+        let modified = path.metadata()?.modified().ok();
+
+        let fresh = self
+            .entries
+            .get(&path)
+            .is_some_and(|entry| modified.is_some() && entry.modified == modified);
+        if !fresh {
+            self.entries.remove(&path);
+            let palette = Base16Palette::from_file_with(&path, self.options)?;
+            self.entries
+                .insert(path.clone(), Entry { modified, palette });
+        }
+        Ok(&self.entries[&path].palette)
+    }
+
+    /// Drops the cached palette of `file`, so the next [`load`](Self::load)
+    /// parses it again. Returns whether the file was cached.
+    pub fn invalidate(&mut self, file: impl AsRef<Path>) -> bool {
+        let file = file.as_ref();
+        let path = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+        self.entries.remove(&path).is_some()
+    }
+
+    /// Drops all cached palettes.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of cached palettes.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no palettes are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::{self, File},
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::{temp_dir::TempDir, DRACULA};
+
+    #[test]
+    fn modified_files_are_parsed_again() {
+        let dir = TempDir::new("cache");
+        let file = dir.join("theme.yaml");
+        fs::write(&file, DRACULA.to_yaml().unwrap()).unwrap();
+        let modified = fs::metadata(&file).unwrap().modified().unwrap();
+
+        let mut cache = PaletteCache::new();
+        assert_eq!(cache.load(&file).unwrap().name, "Dracula");
+
+        // Rewrite the file but keep its modification time: the cache still
+        // serves the old palette.
+        let renamed = DRACULA.to_yaml().unwrap().replace("Dracula", "Renamed");
+        fs::write(&file, renamed).unwrap();
+        File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert_eq!(cache.load(&file).unwrap().name, "Dracula");
+
+        File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(modified + Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(cache.load(&file).unwrap().name, "Renamed");
+
+        assert!(cache.invalidate(&file));
+        assert!(!cache.invalidate(&file));
+        cache.load(&file).unwrap();
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
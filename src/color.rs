@@ -0,0 +1,507 @@
+//! Color conversion helpers shared by the integrations in this crate.
+
+#[cfg(feature = "serde")]
+use core::str::FromStr;
+
+use alloc::{format, string::String};
+#[cfg(feature = "std")]
+use alloc::{vec, vec::Vec};
+
+use ratatui::style::Color;
+
+#[cfg(feature = "serde")]
+use crate::css;
+
+/// The sixteen standard xterm colors, used to resolve named and low indexed
+/// colors to concrete RGB values.
+const ANSI: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0xcd, 0x00, 0x00),
+    (0x00, 0xcd, 0x00),
+    (0xcd, 0xcd, 0x00),
+    (0x00, 0x00, 0xee),
+    (0xcd, 0x00, 0xcd),
+    (0x00, 0xcd, 0xcd),
+    (0xe5, 0xe5, 0xe5),
+    (0x7f, 0x7f, 0x7f),
+    (0xff, 0x00, 0x00),
+    (0x00, 0xff, 0x00),
+    (0xff, 0xff, 0x00),
+    (0x5c, 0x5c, 0xff),
+    (0xff, 0x00, 0xff),
+    (0x00, 0xff, 0xff),
+    (0xff, 0xff, 0xff),
+];
+
+/// Resolves a ratatui `Color` to an RGB triple.
+///
+/// Named and indexed colors are mapped to the default xterm 256-color
+/// palette. `Color::Reset` has no concrete value and yields `None`.
+pub(crate) fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Indexed(i) => i,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+    };
+    Some(indexed_to_rgb(index))
+}
+
+/// Parses a color from a scheme file, trying these notations in order:
+///
+/// - six hex digits, with or without a leading `#`, `0x` or `0X`,
+/// - `"default"` and `"reset"`, meaning the terminal's own color
+///   `Color::Reset`,
+/// - what ratatui accepts, such as color names like `"blue"` and indexes
+///   like `"4"`,
+/// - the CSS notations understood by `css::parse`.
+///
+/// ANSI names like `"red"` therefore keep their terminal meaning rather than
+/// the CSS one.
+#[cfg(feature = "serde")]
+pub(crate) fn parse(s: &str) -> Option<Color> {
+    let hex = s
+        .strip_prefix('#')
+        .or_else(|| s.strip_prefix("0x"))
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    if hex.len() == 6 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        return Some(Color::from_u32(value));
+    }
+    if s.eq_ignore_ascii_case("default") || s.eq_ignore_ascii_case("reset") {
+        return Some(Color::Reset);
+    }
+    Color::from_str(s)
+        .ok()
+        .or_else(|| css::parse(s).map(|(r, g, b)| Color::Rgb(r, g, b)))
+}
+
+/// Parses a color like [`parse`], additionally reading eight hex digits as
+/// `#rrggbbaa`. Returns the color with its alpha channel, which is `0xff`
+/// (opaque) for every other notation.
+#[cfg(feature = "serde")]
+pub(crate) fn parse_alpha(s: &str) -> Option<(Color, u8)> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if hex.len() == 8 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let [r, g, b, a] = u32::from_str_radix(hex, 16).ok()?.to_be_bytes();
+        return Some((Color::Rgb(r, g, b), a));
+    }
+    parse(s).map(|color| (color, 0xff))
+}
+
+/// Formats a color as a lowercase `#rrggbb` string, or `None` for
+/// `Color::Reset`.
+pub(crate) fn to_hex(color: Color) -> Option<String> {
+    let (r, g, b) = to_rgb(color)?;
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+/// Returns the WCAG relative luminance of a color, between `0.0` (black) and
+/// `1.0` (white).
+#[cfg(feature = "std")]
+pub(crate) fn relative_luminance(color: Color) -> Option<f64> {
+    let (r, g, b) = to_rgb(color)?;
+    Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+}
+
+/// Returns the WCAG contrast ratio between two colors, from `1.0` (no
+/// contrast) to `21.0` (black on white).
+#[cfg(feature = "std")]
+pub(crate) fn contrast_ratio(a: Color, b: Color) -> Option<f64> {
+    let (a, b) = (relative_luminance(a)?, relative_luminance(b)?);
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+/// Converts a color to CIE L*a*b* under the D65 white point.
+#[cfg(feature = "std")]
+pub(crate) fn to_lab(color: Color) -> Option<(f64, f64, f64)> {
+    let (r, g, b) = to_rgb(color)?;
+    let (r, g, b) = (linear(r), linear(g), linear(b));
+    let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    let z = (0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / 1.08883;
+    let f = |t: f64| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    Some((116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)))
+}
+
+/// Returns the CIEDE2000 color difference between two colors. Differences
+/// below about 2 are barely perceptible.
+#[cfg(feature = "std")]
+pub(crate) fn delta_e(a: Color, b: Color) -> Option<f64> {
+    Some(delta_e_lab(to_lab(a)?, to_lab(b)?))
+}
+
+#[cfg(feature = "std")]
+fn delta_e_lab((l1, a1, b1): (f64, f64, f64), (l2, a2, b2): (f64, f64, f64)) -> f64 {
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + 25f64.powi(7))).sqrt());
+    let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |b: f64, a: f64| {
+        let h = b.atan2(a).to_degrees();
+        if h < 0.0 {
+            h + 360.0
+        } else {
+            h
+        }
+    };
+    let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 <= h1 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1 - 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h.to_radians() / 2.0).sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + 25f64.powi(7))).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// Returns the CIE L*C*h chroma of a color, from `0.0` for grays to above
+/// `100.0` for the most saturated colors.
+#[cfg(feature = "std")]
+pub(crate) fn chroma(color: Color) -> Option<f64> {
+    let (_, a, b) = to_lab(color)?;
+    Some(a.hypot(b))
+}
+
+#[cfg(feature = "std")]
+fn linear(c: u8) -> f64 {
+    let c = f64::from(c) / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a color to linear light sRGB channels between `0.0` and `1.0`.
+#[cfg(feature = "std")]
+pub(crate) fn to_linear(color: Color) -> Option<[f64; 3]> {
+    let (r, g, b) = to_rgb(color)?;
+    Some([linear(r), linear(g), linear(b)])
+}
+
+/// Converts linear light sRGB channels to a color, clamping each channel to
+/// the sRGB gamut.
+#[cfg(feature = "std")]
+pub(crate) fn from_linear([r, g, b]: [f64; 3]) -> Color {
+    Color::Rgb(encode(r), encode(g), encode(b))
+}
+
+/// Converts a linear light channel back to an sRGB byte.
+#[cfg(feature = "std")]
+fn encode(c: f64) -> u8 {
+    let c = if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Converts a color to Oklch: perceptual lightness from `0.0` to `1.0`,
+/// chroma from `0.0` for grays to about `0.32`, and hue in degrees.
+#[cfg(feature = "std")]
+pub(crate) fn to_oklch(color: Color) -> Option<(f64, f64, f64)> {
+    let [r, g, b] = to_linear(color)?;
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    let lightness = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+    let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+    let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+    Some((
+        lightness,
+        a.hypot(b),
+        b.atan2(a).to_degrees().rem_euclid(360.0),
+    ))
+}
+
+/// Converts an Oklch color to sRGB, reducing its chroma until it fits the
+/// sRGB gamut so that the lightness and hue are kept.
+#[cfg(feature = "std")]
+pub(crate) fn from_oklch(lightness: f64, chroma: f64, hue: f64) -> Color {
+    let lightness = lightness.clamp(0.0, 1.0);
+    let linear_rgb = |chroma: f64| {
+        let (a, b) = (
+            chroma * hue.to_radians().cos(),
+            chroma * hue.to_radians().sin(),
+        );
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+        [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ]
+    };
+    let in_gamut = |rgb: [f64; 3]| rgb.iter().all(|c| (-1e-6..=1.0 + 1e-6).contains(c));
+    let mut rgb = linear_rgb(chroma.max(0.0));
+    if !in_gamut(rgb) {
+        let (mut low, mut high) = (0.0, chroma);
+        for _ in 0..24 {
+            let mid = (low + high) / 2.0;
+            if in_gamut(linear_rgb(mid)) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        rgb = linear_rgb(low);
+    }
+    from_linear(rgb)
+}
+
+/// Blends two colors in linear light, `t` of the way from `a` to `b`.
+///
+/// Interpolating the linear light values rather than the sRGB bytes, as
+/// light mixes physically, keeps midpoints as bright as their endpoints
+/// instead of turning them muddy. `t` is clamped to `0.0..=1.0`, returning
+/// the endpoints unchanged at either extreme. If either color is
+/// `Color::Reset` the nearest endpoint is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::style::Color;
+/// let red = Color::Rgb(0xff, 0, 0);
+/// let green = Color::Rgb(0, 0xff, 0);
+/// assert_eq!(ratatui_base16::blend(red, green, 0.5), Color::Rgb(0xbc, 0xbc, 0));
+/// ```
+#[cfg(feature = "std")]
+pub fn blend(a: Color, b: Color, t: f64) -> Color {
+    if t <= 0.0 {
+        return a;
+    }
+    if t >= 1.0 {
+        return b;
+    }
+    match (to_linear(a), to_linear(b)) {
+        (Some(a), Some(b)) => from_linear([0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t)),
+        _ if t < 0.5 => a,
+        _ => b,
+    }
+}
+
+/// Mixes colors in linear light by weight, like paints.
+///
+/// Weights are relative, so `[(a, 1.0), (b, 3.0)]` gives a quarter of `a`
+/// and three quarters of `b`, the same as [`blend`]`(a, b, 0.75)`. Colors
+/// that are `Color::Reset`, and weights that aren't positive, are left
+/// out. Returns `None` if nothing is left to mix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::style::Color;
+/// # use ratatui_base16::{blend, mix};
+/// let (white, blue) = (Color::Rgb(0xff, 0xff, 0xff), Color::Rgb(0, 0, 0xff));
+/// assert_eq!(mix([(white, 1.0), (blue, 3.0)]), Some(blend(white, blue, 0.75)));
+/// assert_eq!(mix([(Color::Reset, 1.0)]), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn mix(colors: impl IntoIterator<Item = (Color, f64)>) -> Option<Color> {
+    let mut sum = [0.0; 3];
+    let mut total = 0.0;
+    for (color, weight) in colors {
+        let Some(linear) = to_linear(color).filter(|_| weight > 0.0) else {
+            continue;
+        };
+        for (sum, channel) in sum.iter_mut().zip(linear) {
+            *sum += channel * weight;
+        }
+        total += weight;
+    }
+    (total > 0.0).then(|| from_linear(sum.map(|channel| channel / total)))
+}
+
+/// Samples `steps` evenly spaced colors along a piecewise linear gradient
+/// through `stops`, blended in linear light.
+#[cfg(feature = "std")]
+pub(crate) fn gradient(stops: &[Color], steps: usize) -> Vec<Color> {
+    match (stops, steps) {
+        ([], _) | (_, 0) => Vec::new(),
+        ([only], _) => vec![*only; steps],
+        (_, 1) => vec![stops[0]],
+        _ => {
+            let segments = (stops.len() - 1) as f64;
+            (0..steps)
+                .map(|i| {
+                    let position = i as f64 / (steps - 1) as f64 * segments;
+                    let segment = (position.floor() as usize).min(stops.len() - 2);
+                    blend(
+                        stops[segment],
+                        stops[segment + 1],
+                        position - segment as f64,
+                    )
+                })
+                .collect()
+        }
+    }
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_indexed_colors() {
+        assert_eq!(to_rgb(Color::Rgb(1, 2, 3)), Some((1, 2, 3)));
+        assert_eq!(to_rgb(Color::Red), Some((0xcd, 0x00, 0x00)));
+        assert_eq!(to_rgb(Color::Indexed(16)), Some((0, 0, 0)));
+        assert_eq!(to_rgb(Color::Indexed(231)), Some((255, 255, 255)));
+        assert_eq!(to_rgb(Color::Indexed(255)), Some((238, 238, 238)));
+        assert_eq!(to_rgb(Color::Reset), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parse_hex_with_and_without_prefix() {
+        assert_eq!(parse("#282936"), Some(Color::Rgb(0x28, 0x29, 0x36)));
+        assert_eq!(parse("282936"), Some(Color::Rgb(0x28, 0x29, 0x36)));
+        assert_eq!(parse("0x282936"), Some(Color::Rgb(0x28, 0x29, 0x36)));
+        assert_eq!(parse("0X282936"), Some(Color::Rgb(0x28, 0x29, 0x36)));
+        assert_eq!(parse("reset"), Some(Color::Reset));
+        assert_eq!(parse("Default"), Some(Color::Reset));
+        assert_eq!(parse("12"), Some(Color::Indexed(12)));
+        assert_eq!(parse("#2829"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parse_hex_with_alpha() {
+        assert_eq!(
+            parse_alpha("#28293680"),
+            Some((Color::Rgb(0x28, 0x29, 0x36), 0x80))
+        );
+        assert_eq!(
+            parse_alpha("#282936"),
+            Some((Color::Rgb(0x28, 0x29, 0x36), 0xff))
+        );
+        assert_eq!(parse("#28293680"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parse_css_colors_after_ansi_names() {
+        assert_eq!(parse("red"), Some(Color::Red));
+        assert_eq!(parse("coral"), Some(Color::Rgb(0xff, 0x7f, 0x50)));
+        assert_eq!(parse("rgb(40, 41, 54)"), Some(Color::Rgb(0x28, 0x29, 0x36)));
+    }
+
+    #[test]
+    fn contrast_of_black_and_white() {
+        let ratio = contrast_ratio(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)).unwrap();
+        assert!((ratio - 21.0).abs() < 1e-9);
+        assert_eq!(contrast_ratio(Color::Reset, Color::Black), None);
+    }
+
+    #[test]
+    fn delta_e_matches_reference_values() {
+        // First test pair from Sharma et al., "The CIEDE2000 Color-Difference
+        // Formula: Implementation Notes".
+        let sharma = delta_e_lab((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485));
+        assert!((sharma - 2.0425).abs() < 1e-4);
+
+        let same = delta_e(Color::Rgb(10, 20, 30), Color::Rgb(10, 20, 30)).unwrap();
+        assert_eq!(same, 0.0);
+        let black_white = delta_e(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)).unwrap();
+        assert!((black_white - 100.0).abs() < 0.01);
+        assert!(chroma(Color::Rgb(128, 128, 128)).unwrap() < 0.01);
+    }
+
+    #[test]
+    fn oklch_round_trip() {
+        let (l, c, h) = to_oklch(Color::Rgb(0xff, 0x00, 0x00)).unwrap();
+        assert!((l - 0.628).abs() < 1e-3 && (c - 0.2577).abs() < 1e-3 && (h - 29.23).abs() < 0.1);
+        assert_eq!(from_oklch(l, c, h), Color::Rgb(0xff, 0x00, 0x00));
+        // Out of gamut chroma is reduced rather than clipped per channel.
+        let (_, reduced, _) = to_oklch(from_oklch(0.9, 0.3, 265.0)).unwrap();
+        assert!(reduced < 0.1);
+    }
+
+    #[test]
+    fn gradient_includes_endpoints() {
+        let colors = gradient(&[Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0)], 3);
+        assert_eq!(
+            colors,
+            [
+                Color::Rgb(0, 0, 0),
+                Color::Rgb(146, 71, 0),
+                Color::Rgb(200, 100, 0)
+            ]
+        );
+        assert!(gradient(&[Color::Red], 0).is_empty());
+    }
+}
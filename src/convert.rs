@@ -0,0 +1,234 @@
+//! Conversions between palettes and other color collections.
+
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use ratatui::style::Color;
+
+use crate::Base16Palette;
+#[cfg(feature = "std")]
+use crate::{Base16Color, Base16PaletteError};
+
+impl Base16Palette {
+    /// Returns the sixteen colors in spec order, from base00 to base0F.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let colors = DRACULA.to_array();
+    /// assert_eq!(colors[0x0d], DRACULA.base0d);
+    /// ```
+    pub const fn to_array(&self) -> [Color; 16] {
+        [
+            self.base00,
+            self.base01,
+            self.base02,
+            self.base03,
+            self.base04,
+            self.base05,
+            self.base06,
+            self.base07,
+            self.base08,
+            self.base09,
+            self.base0a,
+            self.base0b,
+            self.base0c,
+            self.base0d,
+            self.base0e,
+            self.base0f,
+        ]
+    }
+
+    /// Returns the colors of the sixteen ANSI slots, from color0 to color15,
+    /// with the mapping of the base16 terminal templates.
+    ///
+    /// The bright colors repeat the normal ones, except for bright black
+    /// (base03) and bright white (base07).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let ansi = DRACULA.to_ansi();
+    /// assert_eq!(ansi[1], DRACULA.base08);
+    /// assert_eq!(ansi[8], DRACULA.base03);
+    /// ```
+    pub const fn to_ansi(&self) -> [Color; 16] {
+        [
+            self.base00,
+            self.base08,
+            self.base0b,
+            self.base0a,
+            self.base0d,
+            self.base0e,
+            self.base0c,
+            self.base05,
+            self.base03,
+            self.base08,
+            self.base0b,
+            self.base0a,
+            self.base0d,
+            self.base0e,
+            self.base0c,
+            self.base07,
+        ]
+    }
+
+    /// Creates a palette from sixteen colors in spec order, from base00 to
+    /// base0F.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Palette, DRACULA};
+    /// let palette = Base16Palette::from_array(DRACULA.to_array(), "Copy", "Me");
+    /// assert_eq!(palette.base0d, DRACULA.base0d);
+    /// assert_eq!(palette.name, "Copy");
+    /// ```
+    pub fn from_array(
+        colors: [Color; 16],
+        name: impl Into<Cow<'static, str>>,
+        author: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let [base00, base01, base02, base03, base04, base05, base06, base07, base08, base09, base0a, base0b, base0c, base0d, base0e, base0f] =
+            colors;
+        Self {
+            name: name.into(),
+            author: author.into(),
+            slug: Cow::Borrowed(""),
+            base00,
+            base01,
+            base02,
+            base03,
+            base04,
+            base05,
+            base06,
+            base07,
+            base08,
+            base09,
+            base0a,
+            base0b,
+            base0c,
+            base0d,
+            base0e,
+            base0f,
+            alpha: [0xff; 16],
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Base16Palette {
+    /// Returns a map from slot names (`"base00"` to `"base0F"`) to colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let map = DRACULA.to_map();
+    /// assert_eq!(map["base0D"], DRACULA.base0d);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Color> {
+        self.iter()
+            .map(|(slot, color)| (slot.name().to_string(), color))
+            .collect()
+    }
+
+    /// Creates an unnamed palette from a map of slot names to colors.
+    ///
+    /// Keys are matched ignoring case, so both `"base0a"` and `"base0A"` are
+    /// accepted. Keys that don't name a slot are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::MissingField`] naming the first slot
+    /// without a color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use ratatui_base16::{Base16Palette, DRACULA};
+    /// let mut map = DRACULA.to_map();
+    /// let palette = Base16Palette::try_from_map(&map).unwrap();
+    /// assert_eq!(palette.to_array(), DRACULA.to_array());
+    ///
+    /// map.remove("base03");
+    /// assert!(Base16Palette::try_from_map(&map).is_err());
+    /// ```
+    pub fn try_from_map<K: AsRef<str>>(
+        map: &HashMap<K, Color>,
+    ) -> Result<Self, Base16PaletteError> {
+        let mut palette = Self::from([Color::Reset; 16]);
+        let mut found = [false; 16];
+        for (key, color) in map {
+            if let Ok(slot) = key.as_ref().parse::<Base16Color>() {
+                *palette.color_mut(slot) = *color;
+                found[slot as usize] = true;
+            }
+        }
+        match Base16Color::ALL
+            .into_iter()
+            .find(|slot| !found[*slot as usize])
+        {
+            Some(missing) => Err(Base16PaletteError::MissingField(missing.field_name())),
+            None => Ok(palette),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&HashMap<String, Color>> for Base16Palette {
+    type Error = Base16PaletteError;
+
+    fn try_from(map: &HashMap<String, Color>) -> Result<Self, Self::Error> {
+        Self::try_from_map(map)
+    }
+}
+
+impl From<[Color; 16]> for Base16Palette {
+    /// Creates an unnamed palette from sixteen colors in spec order.
+    fn from(colors: [Color; 16]) -> Self {
+        Self::from_array(colors, "", "")
+    }
+}
+
+impl From<&Base16Palette> for [Color; 16] {
+    fn from(palette: &Base16Palette) -> Self {
+        palette.to_array()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::ROSE_PINE;
+
+    #[test]
+    fn array_round_trip() {
+        let colors: [Color; 16] = (&ROSE_PINE).into();
+        let palette = Base16Palette::from(colors);
+        assert_eq!(palette.to_array(), ROSE_PINE.to_array());
+        assert_eq!(palette.name, "");
+    }
+
+    #[test]
+    fn map_keys_ignore_case() {
+        let map: HashMap<String, Color> = ROSE_PINE
+            .iter()
+            .map(|(slot, color)| (slot.name().to_uppercase(), color))
+            .collect();
+        let palette = Base16Palette::try_from(&map).unwrap();
+        assert!(palette.diff(&ROSE_PINE).is_empty());
+        assert_ne!(palette, ROSE_PINE);
+
+        let mut map = ROSE_PINE.to_map();
+        map.remove("base0A");
+        assert!(matches!(
+            Base16Palette::try_from_map(&map),
+            Err(Base16PaletteError::MissingField("base0a"))
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+//! Deserialization of palettes from scheme files.
+
+use std::borrow::Cow;
+
+use ratatui::style::Color;
+use serde::{de, Deserialize, Deserializer};
+
+use crate::{color, Base16Palette};
+
+/// The layout of a scheme file, converted into a [`Base16Palette`] once all
+/// slots have been read.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) struct RawPalette {
+    #[serde(rename = "scheme", alias = "name", default)]
+    name: Cow<'static, str>,
+    #[serde(default)]
+    author: Cow<'static, str>,
+    #[serde(default)]
+    slug: Cow<'static, str>,
+    base00: Slot,
+    base01: Slot,
+    base02: Slot,
+    base03: Slot,
+    base04: Slot,
+    base05: Slot,
+    base06: Slot,
+    base07: Slot,
+    base08: Slot,
+    base09: Slot,
+    #[serde(alias = "base0A")]
+    base0a: Slot,
+    #[serde(alias = "base0B")]
+    base0b: Slot,
+    #[serde(alias = "base0C")]
+    base0c: Slot,
+    #[serde(alias = "base0D")]
+    base0d: Slot,
+    #[serde(alias = "base0E")]
+    base0e: Slot,
+    #[serde(alias = "base0F")]
+    base0f: Slot,
+}
+
+impl From<RawPalette> for Base16Palette {
+    fn from(raw: RawPalette) -> Self {
+        let slots = [
+            raw.base00, raw.base01, raw.base02, raw.base03, raw.base04, raw.base05, raw.base06,
+            raw.base07, raw.base08, raw.base09, raw.base0a, raw.base0b, raw.base0c, raw.base0d,
+            raw.base0e, raw.base0f,
+        ];
+        let mut palette = Base16Palette::from_array(slots.map(|slot| slot.0), raw.name, raw.author);
+        palette.slug = raw.slug;
+        palette.alpha = slots.map(|slot| slot.1);
+        palette
+    }
+}
+
+/// A single slot value: its color and alpha channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Slot(pub(crate) Color, pub(crate) u8);
+
+impl<'de> Deserialize<'de> for Slot {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SlotVisitor)
+    }
+}
+
+/// Reads a palette color from any of the value types scheme files use for
+/// them: strings in one of the formats understood by `color::parse_alpha`,
+/// integers such as TOML's `0x181818`, read as `0xRRGGBB`, `[r, g, b]`
+/// arrays and `{ r, g, b }` tables of channels between `0` and `255`.
+/// Arrays and tables may carry a fourth, alpha channel.
+struct SlotVisitor;
+
+impl<'de> de::Visitor<'de> for SlotVisitor {
+    type Value = Slot;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a color string, a 0xRRGGBB integer or red, green and blue channels")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Slot, E> {
+        color::parse_alpha(s)
+            .map(|(color, alpha)| Slot(color, alpha))
+            .ok_or_else(|| E::custom(format!("invalid color {s:?}")))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Slot, E> {
+        u32::try_from(value)
+            .ok()
+            .filter(|value| *value <= 0xff_ff_ff)
+            .map(|value| Slot(Color::from_u32(value), 0xff))
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Slot, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Slot, A::Error> {
+        let mut channel = |index| {
+            seq.next_element::<u8>()?
+                .ok_or_else(|| de::Error::invalid_length(index, &"three or four channels"))
+        };
+        let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+        let alpha = seq.next_element::<u8>()?.unwrap_or(0xff);
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(5, &"three or four channels"));
+        }
+        Ok(Slot(Color::Rgb(r, g, b), alpha))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Slot, A::Error> {
+        let mut channels = [None; 4];
+        while let Some(key) = map.next_key::<String>()? {
+            let index = match key.as_str() {
+                "r" | "red" => 0,
+                "g" | "green" => 1,
+                "b" | "blue" => 2,
+                "a" | "alpha" => 3,
+                _ => return Err(de::Error::unknown_field(&key, &["r", "g", "b", "a"])),
+            };
+            channels[index] = Some(map.next_value::<u8>()?);
+        }
+        match channels {
+            [Some(r), Some(g), Some(b), alpha] => {
+                Ok(Slot(Color::Rgb(r, g, b), alpha.unwrap_or(0xff)))
+            }
+            [None, ..] => Err(de::Error::missing_field("r")),
+            [_, None, ..] => Err(de::Error::missing_field("g")),
+            [_, _, None, _] => Err(de::Error::missing_field("b")),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Human-friendly `Display` and `Debug` output for palettes.
+
+use alloc::string::ToString;
+use core::fmt;
+
+use ratatui::style::Color;
+
+use crate::{color, Base16Color, Base16Palette};
+
+/// Formats a color as `#rrggbb`, falling back to ratatui's representation for
+/// colors without a concrete value.
+///
+/// `Debug` only formats `Color::Rgb` as hex and keeps named, indexed and
+/// reset colors as they are, so unequal palettes never print the same.
+struct Hex(Color);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match color::to_hex(self.0) {
+            Some(hex) => f.pad(&hex),
+            None => f.pad(&self.0.to_string()),
+        }
+    }
+}
+
+impl fmt::Debug for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Color::Rgb(..) => fmt::Display::fmt(self, f),
+            color => fmt::Debug::fmt(&color, f),
+        }
+    }
+}
+
+/// Prints a table with the hex value and role of every slot.
+///
+/// ```text
+/// Dracula by Mike Barkmin (http://github.com/mikebarkmin) based on Dracula Theme (http://github.com/dracula)
+/// base00  #282936  Default Background
+/// base01  #3a3c4e  Lighter Background (Used for status bars, line number and folding marks)
+/// ...
+/// ```
+impl fmt::Display for Base16Palette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.author.is_empty() {
+            writeln!(f, "{}", self.name)?;
+        } else {
+            writeln!(f, "{} by {}", self.name, self.author)?;
+        }
+        for (i, (slot, color)) in self.iter().enumerate() {
+            write!(
+                f,
+                "{}  {:7}  {}",
+                slot.name(),
+                Hex(color),
+                slot.description()
+            )?;
+            if i + 1 < Base16Color::ALL.len() {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Prints colors as compact hex values instead of `Rgb(..)` tuples.
+impl fmt::Debug for Base16Palette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("Base16Palette");
+        s.field("name", &self.name)
+            .field("author", &self.author)
+            .field("slug", &self.slug);
+        for (slot, color) in self {
+            s.field(slot.field_name(), &Hex(color));
+        }
+        if self.alpha != [0xff; 16] {
+            s.field("alpha", &self.alpha);
+        }
+        s.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn display_lists_roles() {
+        let table = DEFAULT_DARK.to_string();
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 17);
+        assert_eq!(
+            lines[0],
+            "Default Dark by Chris Kempson (http://chriskempson.com)"
+        );
+        assert_eq!(lines[1], "base00  #181818  Default Background");
+    }
+
+    #[test]
+    fn debug_uses_hex() {
+        let debug = format!("{DEFAULT_DARK:?}");
+        assert!(debug.contains("base0d: #7cafc2"));
+        assert!(!debug.contains("Rgb"));
+
+        let named = DEFAULT_DARK.with_base08(Color::Red);
+        let indexed = DEFAULT_DARK.with_base08(Color::Indexed(1));
+        assert_ne!(format!("{named:?}"), format!("{indexed:?}"));
+        assert!(format!("{named:?}").contains("base08: Red"));
+        assert!(format!("{indexed:?}").contains("base08: Indexed(1)"));
+    }
+}
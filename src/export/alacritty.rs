@@ -0,0 +1,61 @@
+//! Alacritty color configuration.
+
+use std::fmt::Write;
+
+use super::{extended, header, hex, ANSI_NAMES};
+use crate::Base16Palette;
+
+/// Returns the `[colors]` tables of an Alacritty configuration file,
+/// following the TOML template of base16-alacritty.
+///
+/// Colors 16 to 21 are set as `indexed_colors`, like [`to_shell_script`]
+/// does. The output can be imported from `alacritty.toml` with
+/// `general.import`, or appended to it.
+///
+/// [`to_shell_script`]: super::to_shell_script
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let toml = export::to_alacritty_toml(&DRACULA);
+/// assert!(toml.contains("[colors.primary]\nbackground = \"#282936\"\n"));
+/// ```
+pub fn to_alacritty_toml(palette: &Base16Palette) -> String {
+    let mut out = header(palette, "#");
+    let _ = writeln!(out, "[colors.primary]");
+    let _ = writeln!(out, "background = \"{}\"", hex(palette.base00));
+    let _ = writeln!(out, "foreground = \"{}\"", hex(palette.base05));
+    let _ = writeln!(out, "\n[colors.cursor]");
+    let _ = writeln!(out, "text = \"{}\"", hex(palette.base00));
+    let _ = writeln!(out, "cursor = \"{}\"", hex(palette.base05));
+
+    let ansi = palette.to_ansi();
+    for (table, colors) in [("normal", &ansi[..8]), ("bright", &ansi[8..])] {
+        let _ = writeln!(out, "\n[colors.{table}]");
+        for (name, color) in ANSI_NAMES.into_iter().zip(colors) {
+            let _ = writeln!(out, "{name} = \"{}\"", hex(*color));
+        }
+    }
+    for (index, color) in (16..).zip(extended(palette)) {
+        let _ = writeln!(out, "\n[[colors.indexed_colors]]");
+        let _ = writeln!(out, "index = {index}");
+        let _ = writeln!(out, "color = \"{}\"", hex(color));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn tables_follow_the_base16_template() {
+        let toml = to_alacritty_toml(&DEFAULT_DARK);
+        assert!(toml.contains("[colors.normal]\nblack = \"#181818\"\nred = \"#ab4642\"\n"));
+        assert!(toml.contains("[colors.bright]\nblack = \"#585858\"\n"));
+        assert!(toml.contains("white = \"#f8f8f8\"\n"));
+        assert!(toml.ends_with("[[colors.indexed_colors]]\nindex = 21\ncolor = \"#e8e8e8\"\n"));
+    }
+}
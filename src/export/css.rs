@@ -0,0 +1,85 @@
+//! CSS custom properties.
+
+use std::fmt::Write;
+
+use super::{hex, ALIASES};
+use crate::Base16Palette;
+
+/// Options for [`to_css_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export::{self, CssOptions}, DRACULA};
+/// let options = CssOptions {
+///     prefix: "theme-".to_string(),
+///     aliases: true,
+/// };
+/// let css = export::to_css_with(&DRACULA, &options);
+/// assert!(css.contains("  --theme-base0D: #62d6e8;\n"));
+/// assert!(css.contains("  --theme-blue: var(--theme-base0D);\n"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CssOptions {
+    /// Prepended to the name of every property, e.g. `"theme-"` for
+    /// `--theme-base00`.
+    pub prefix: String,
+    /// Also declares properties named after the role of each slot, such as
+    /// `--background` and `--red`, which refer to the slot properties.
+    pub aliases: bool,
+}
+
+/// Returns a `:root` rule declaring a custom property per slot, from
+/// `--base00` to `--base0F`.
+///
+/// See [`to_css_with`] for prefixed names and semantic aliases.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let css = export::to_css(&DRACULA);
+/// assert!(css.starts_with(":root {\n  --base00: #282936;\n"));
+/// ```
+pub fn to_css(palette: &Base16Palette) -> String {
+    to_css_with(palette, &CssOptions::default())
+}
+
+/// Returns a `:root` rule declaring a custom property per slot with the
+/// given [`CssOptions`].
+pub fn to_css_with(palette: &Base16Palette, options: &CssOptions) -> String {
+    let prefix = &options.prefix;
+    let mut out = String::from(":root {\n");
+    for (slot, color) in palette.iter() {
+        let _ = writeln!(out, "  --{prefix}{}: {};", slot.name(), hex(color));
+    }
+    if options.aliases {
+        for (alias, slot) in ALIASES {
+            let _ = writeln!(out, "  --{prefix}{alias}: var(--{prefix}{});", slot.name());
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn properties_are_declared_per_slot() {
+        let css = to_css(&DEFAULT_DARK);
+        assert_eq!(css.lines().count(), 18);
+        assert!(css.contains("  --base0A: #f7ca88;\n"));
+        assert!(css.ends_with("  --base0F: #a16946;\n}\n"));
+
+        let options = CssOptions {
+            aliases: true,
+            ..CssOptions::default()
+        };
+        let css = to_css_with(&DEFAULT_DARK, &options);
+        assert!(css.contains("  --background: var(--base00);\n"));
+        assert!(css.contains("  --foreground: var(--base05);\n"));
+    }
+}
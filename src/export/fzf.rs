@@ -0,0 +1,57 @@
+//! fzf color options.
+
+use super::hex;
+use crate::Base16Palette;
+
+/// Returns the `--color` option of fzf for the palette, following the
+/// template of base16-fzf.
+///
+/// The list is drawn in base04 on base00 with the current line in base06
+/// on base01, matches are highlighted in base0D and the prompt uses base0A.
+/// The option can be passed on the command line or added to
+/// `FZF_DEFAULT_OPTS`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let option = export::to_fzf(&DRACULA);
+/// assert!(option.starts_with("--color=bg+:#3a3c4e,bg:#282936,"));
+/// ```
+pub fn to_fzf(palette: &Base16Palette) -> String {
+    let colors = [
+        ("bg+", palette.base01),
+        ("bg", palette.base00),
+        ("spinner", palette.base0c),
+        ("hl", palette.base0d),
+        ("fg", palette.base04),
+        ("header", palette.base0d),
+        ("info", palette.base0a),
+        ("pointer", palette.base0c),
+        ("marker", palette.base0c),
+        ("fg+", palette.base06),
+        ("prompt", palette.base0a),
+        ("hl+", palette.base0d),
+    ];
+    let colors: Vec<_> = colors
+        .into_iter()
+        .map(|(name, color)| format!("{name}:{}", hex(color)))
+        .collect();
+    format!("--color={}", colors.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn option_lists_every_element() {
+        let option = to_fzf(&DEFAULT_DARK);
+        assert_eq!(
+            option,
+            "--color=bg+:#282828,bg:#181818,spinner:#86c1b9,hl:#7cafc2,fg:#b8b8b8,header:#7cafc2,\
+             info:#f7ca88,pointer:#86c1b9,marker:#86c1b9,fg+:#e8e8e8,prompt:#f7ca88,hl+:#7cafc2"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Ghostty theme configuration.
+
+use std::fmt::Write;
+
+use super::{extended, header, hex};
+use crate::Base16Palette;
+
+/// Returns a Ghostty theme setting the palette, default, cursor and
+/// selection colors in Ghostty's `key = value` configuration syntax.
+///
+/// Saved in `~/.config/ghostty/themes`, the theme is selected with
+/// `theme = <file name>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let config = export::to_ghostty(&DRACULA);
+/// assert!(config.contains("palette = 1=#ea51b2\n"));
+/// assert!(config.contains("background = #282936\n"));
+/// ```
+pub fn to_ghostty(palette: &Base16Palette) -> String {
+    let mut out = header(palette, "#");
+    let ansi = palette.to_ansi().into_iter();
+    for (index, color) in ansi.chain(extended(palette)).enumerate() {
+        let _ = writeln!(out, "palette = {index}={}", hex(color));
+    }
+    out.push('\n');
+    let keys = [
+        ("background", palette.base00),
+        ("foreground", palette.base05),
+        ("cursor-color", palette.base05),
+        ("cursor-text", palette.base00),
+        ("selection-background", palette.base02),
+        ("selection-foreground", palette.base05),
+    ];
+    for (key, color) in keys {
+        let _ = writeln!(out, "{key} = {}", hex(color));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn palette_entries_are_indexed() {
+        let config = to_ghostty(&DEFAULT_DARK);
+        let lines: Vec<_> = config.lines().collect();
+        assert!(lines.contains(&"palette = 0=#181818"));
+        assert!(lines.contains(&"palette = 8=#585858"));
+        assert!(lines.contains(&"palette = 21=#e8e8e8"));
+        assert_eq!(lines.last(), Some(&"selection-foreground = #d8d8d8"));
+    }
+}
@@ -0,0 +1,108 @@
+//! Themes for the lazygit and gitui git interfaces.
+
+use std::fmt::Write;
+
+use super::hex;
+use crate::Base16Palette;
+
+/// Returns the `gui.theme` section of a lazygit `config.yml`.
+///
+/// Active borders use base0D, inactive ones base03, the selected line
+/// is drawn on base02, and unstaged changes and cherry-picked commits use
+/// base08 and base0E.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let yaml = export::to_lazygit_yaml(&DRACULA);
+/// assert!(yaml.contains("    activeBorderColor:\n      - \"#62d6e8\"\n      - bold\n"));
+/// ```
+pub fn to_lazygit_yaml(palette: &Base16Palette) -> String {
+    let p = palette;
+    let entries = [
+        ("activeBorderColor", p.base0d, Some("bold")),
+        ("inactiveBorderColor", p.base03, None),
+        ("searchingActiveBorderColor", p.base0a, Some("bold")),
+        ("optionsTextColor", p.base0d, None),
+        ("selectedLineBgColor", p.base02, None),
+        ("inactiveViewSelectedLineBgColor", p.base01, None),
+        ("cherryPickedCommitFgColor", p.base0e, None),
+        ("cherryPickedCommitBgColor", p.base02, None),
+        ("markedBaseCommitFgColor", p.base0d, None),
+        ("markedBaseCommitBgColor", p.base02, None),
+        ("unstagedChangesColor", p.base08, None),
+        ("defaultFgColor", p.base05, None),
+    ];
+    let mut out = String::from("gui:\n  theme:\n");
+    for (key, color, attribute) in entries {
+        let _ = writeln!(out, "    {key}:\n      - \"{}\"", hex(color));
+        if let Some(attribute) = attribute {
+            let _ = writeln!(out, "      - {attribute}");
+        }
+    }
+    out
+}
+
+/// Returns a gitui `theme.ron` with the same mapping as
+/// [`to_lazygit_yaml`], plus the diff colors: base0B for added lines,
+/// base08 for removed ones and base0E for modified files.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let ron = export::to_gitui_ron(&DRACULA);
+/// assert!(ron.contains("    diff_line_add: Some(\"#ebff87\"),\n"));
+/// ```
+pub fn to_gitui_ron(palette: &Base16Palette) -> String {
+    let p = palette;
+    let entries = [
+        ("selected_tab", p.base0d),
+        ("command_fg", p.base05),
+        ("selection_bg", p.base02),
+        ("selection_fg", p.base05),
+        ("cmdbar_bg", p.base01),
+        ("cmdbar_extra_lines_bg", p.base01),
+        ("disabled_fg", p.base03),
+        ("diff_line_add", p.base0b),
+        ("diff_line_delete", p.base08),
+        ("diff_file_added", p.base0b),
+        ("diff_file_removed", p.base08),
+        ("diff_file_moved", p.base0c),
+        ("diff_file_modified", p.base0e),
+        ("commit_hash", p.base0e),
+        ("commit_time", p.base0c),
+        ("commit_author", p.base0b),
+        ("danger_fg", p.base08),
+        ("push_gauge_bg", p.base0d),
+        ("push_gauge_fg", p.base00),
+        ("tag_fg", p.base0a),
+        ("branch_fg", p.base0a),
+    ];
+    let mut out = String::from("(\n");
+    for (key, color) in entries {
+        let _ = writeln!(out, "    {key}: Some(\"{}\"),", hex(color));
+    }
+    out.push_str(")\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn configs_map_selection_border_and_diff_colors() {
+        let yaml = to_lazygit_yaml(&DEFAULT_DARK);
+        assert!(yaml.starts_with(
+            "gui:\n  theme:\n    activeBorderColor:\n      - \"#7cafc2\"\n      - bold\n"
+        ));
+        assert!(yaml.contains("    selectedLineBgColor:\n      - \"#383838\"\n"));
+
+        let ron = to_gitui_ron(&DEFAULT_DARK);
+        assert!(ron.contains("    diff_line_delete: Some(\"#ab4642\"),\n"));
+        assert!(ron.ends_with("    branch_fg: Some(\"#f7ca88\"),\n)\n"));
+    }
+}
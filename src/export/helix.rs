@@ -0,0 +1,147 @@
+//! Helix editor themes.
+
+use std::fmt::Write;
+
+use ratatui::style::{Modifier, Style};
+
+use super::hex;
+use crate::{Base16Palette, TREE_SITTER_CAPTURES};
+
+/// The names Helix uses for captures that it names differently from
+/// tree-sitter.
+const RENAMES: &[(&str, &str)] = &[
+    ("boolean", "constant.builtin.boolean"),
+    ("character", "constant.character"),
+    ("escape", "constant.character.escape"),
+    ("float", "constant.numeric.float"),
+    ("markup.link", "markup.link.url"),
+    ("number", "constant.numeric"),
+    ("property", "variable.other.member"),
+];
+
+/// The modifiers Helix understands, with their names in themes.
+const MODIFIERS: [(Modifier, &str); 6] = [
+    (Modifier::BOLD, "bold"),
+    (Modifier::DIM, "dim"),
+    (Modifier::ITALIC, "italic"),
+    (Modifier::UNDERLINED, "underlined"),
+    (Modifier::REVERSED, "reversed"),
+    (Modifier::CROSSED_OUT, "crossed_out"),
+];
+
+/// Returns a Helix theme styling the editor interface and the syntax scopes
+/// after the palette.
+///
+/// Syntax scopes use the same colors as [`Base16Palette::tree_sitter_style`],
+/// so code highlighted in the editor and in the application look alike.
+/// The interface follows the template of base16-helix: base00 for the
+/// editor background, base01 for the status line and popups, base02 for
+/// selections and base03 for line numbers. Saved in `~/.config/helix/themes`,
+/// the theme is selected with `theme = "<file name>"`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let toml = export::to_helix_toml(&DRACULA);
+/// assert!(toml.contains("\"keyword\" = { fg = \"#b45bcf\" }\n"));
+/// assert!(toml.contains("\"ui.background\" = { bg = \"#282936\" }\n"));
+/// ```
+pub fn to_helix_toml(palette: &Base16Palette) -> String {
+    let p = palette;
+    let fg = |color| Style::new().fg(color);
+    let bg = |color| Style::new().bg(color);
+    let ui = [
+        ("ui.background", bg(p.base00)),
+        ("ui.text", fg(p.base05)),
+        ("ui.text.focus", fg(p.base05)),
+        ("ui.cursor", fg(p.base0a).add_modifier(Modifier::REVERSED)),
+        (
+            "ui.cursor.match",
+            fg(p.base0a).add_modifier(Modifier::REVERSED),
+        ),
+        ("ui.cursorline.primary", fg(p.base05).bg(p.base01)),
+        ("ui.gutter", bg(p.base00)),
+        ("ui.linenr", fg(p.base03).bg(p.base00)),
+        (
+            "ui.linenr.selected",
+            fg(p.base04).bg(p.base01).add_modifier(Modifier::BOLD),
+        ),
+        ("ui.selection", bg(p.base02)),
+        ("ui.statusline", fg(p.base04).bg(p.base01)),
+        ("ui.statusline.inactive", fg(p.base03).bg(p.base01)),
+        ("ui.statusline.normal", fg(p.base00).bg(p.base03)),
+        ("ui.statusline.insert", fg(p.base00).bg(p.base0b)),
+        ("ui.statusline.select", fg(p.base00).bg(p.base0f)),
+        ("ui.bufferline", fg(p.base04).bg(p.base00)),
+        (
+            "ui.bufferline.active",
+            fg(p.base00).bg(p.base03).add_modifier(Modifier::BOLD),
+        ),
+        ("ui.popup", bg(p.base01)),
+        ("ui.window", bg(p.base01)),
+        ("ui.help", fg(p.base06).bg(p.base01)),
+        ("ui.menu", fg(p.base05).bg(p.base01)),
+        ("ui.menu.selected", fg(p.base01).bg(p.base04)),
+        ("ui.menu.scroll", fg(p.base03).bg(p.base01)),
+        ("ui.virtual.ruler", bg(p.base01)),
+        ("ui.virtual.indent-guide", fg(p.base03)),
+        ("error", fg(p.base08)),
+        ("warning", fg(p.base09)),
+        ("info", fg(p.base0d)),
+        ("hint", fg(p.base03)),
+    ];
+
+    let mut out = super::header(palette, "#");
+    for (scope, style) in ui {
+        let _ = writeln!(out, "\"{scope}\" = {}", inline(style));
+    }
+    out.push('\n');
+    for (capture, style) in TREE_SITTER_CAPTURES
+        .iter()
+        .zip(palette.tree_sitter_highlight_styles())
+    {
+        let scope = RENAMES
+            .iter()
+            .find(|(name, _)| name == capture)
+            .map_or(*capture, |(_, scope)| scope);
+        let _ = writeln!(out, "\"{scope}\" = {}", inline(style));
+    }
+    out
+}
+
+/// Formats a style as an inline table with `fg`, `bg` and `modifiers`.
+fn inline(style: Style) -> String {
+    let mut fields = Vec::new();
+    if let Some(color) = style.fg {
+        fields.push(format!("fg = \"{}\"", hex(color)));
+    }
+    if let Some(color) = style.bg {
+        fields.push(format!("bg = \"{}\"", hex(color)));
+    }
+    let modifiers: Vec<_> = MODIFIERS
+        .iter()
+        .filter(|(modifier, _)| style.add_modifier.contains(*modifier))
+        .map(|(_, name)| format!("\"{name}\""))
+        .collect();
+    if !modifiers.is_empty() {
+        fields.push(format!("modifiers = [{}]", modifiers.join(", ")));
+    }
+    format!("{{ {} }}", fields.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn scopes_match_the_tree_sitter_styles() {
+        let toml = to_helix_toml(&DEFAULT_DARK);
+        let lines: Vec<_> = toml.lines().collect();
+        assert!(lines.contains(&"\"comment\" = { fg = \"#585858\", modifiers = [\"italic\"] }"));
+        assert!(lines.contains(&"\"constant.numeric\" = { fg = \"#dc9656\" }"));
+        assert!(lines.contains(&"\"ui.statusline\" = { fg = \"#b8b8b8\", bg = \"#282828\" }"));
+        assert!(!lines.contains(&"\"number\" = { fg = \"#dc9656\" }"));
+    }
+}
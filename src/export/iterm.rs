@@ -0,0 +1,84 @@
+//! iTerm2 color presets.
+
+use std::fmt::Write;
+
+use ratatui::style::Color;
+
+use super::rgb;
+use crate::Base16Palette;
+
+/// Returns an iTerm2 color preset, the property list stored in
+/// `.itermcolors` files, following the template of base16-iterm2.
+///
+/// Besides the sixteen ANSI colors, the preset sets the background and
+/// cursor text to base00, the foreground, bold text and cursor to base05,
+/// and the selection to base02 with base05 text. Components are written in
+/// the sRGB color space.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let plist = export::to_itermcolors(&DRACULA);
+/// assert!(plist.contains("\t<key>Ansi 1 Color</key>\n"));
+/// ```
+pub fn to_itermcolors(palette: &Base16Palette) -> String {
+    let mut out = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+        "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+        "<plist version=\"1.0\">\n",
+        "<dict>\n",
+    ));
+    for (index, color) in palette.to_ansi().into_iter().enumerate() {
+        entry(&mut out, &format!("Ansi {index} Color"), color);
+    }
+    let colors = [
+        ("Background Color", palette.base00),
+        ("Bold Color", palette.base05),
+        ("Cursor Color", palette.base05),
+        ("Cursor Text Color", palette.base00),
+        ("Foreground Color", palette.base05),
+        ("Selected Text Color", palette.base05),
+        ("Selection Color", palette.base02),
+    ];
+    for (key, color) in colors {
+        entry(&mut out, key, color);
+    }
+    out.push_str("</dict>\n</plist>\n");
+    out
+}
+
+/// Writes the `key` of a color and its dictionary of components.
+fn entry(out: &mut String, key: &str, color: Color) {
+    let (r, g, b) = rgb(color);
+    let _ = writeln!(out, "\t<key>{key}</key>");
+    out.push_str("\t<dict>\n");
+    out.push_str("\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>\n");
+    for (name, value) in [("Red", r), ("Green", g), ("Blue", b)] {
+        let _ = writeln!(out, "\t\t<key>{name} Component</key>");
+        let _ = writeln!(out, "\t\t<real>{}</real>", f64::from(value) / 255.0);
+    }
+    out.push_str("\t</dict>\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn components_are_fractions() {
+        let plist = to_itermcolors(&DEFAULT_DARK);
+        assert_eq!(plist.matches("<key>Color Space</key>").count(), 23);
+        assert!(plist.contains(concat!(
+            "\t<key>Ansi 15 Color</key>\n",
+            "\t<dict>\n",
+            "\t\t<key>Color Space</key>\n",
+            "\t\t<string>sRGB</string>\n",
+            "\t\t<key>Red Component</key>\n",
+            "\t\t<real>0.9725490196078431</real>\n",
+        )));
+        assert!(plist.ends_with("\t</dict>\n</dict>\n</plist>\n"));
+    }
+}
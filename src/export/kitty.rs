@@ -0,0 +1,68 @@
+//! kitty theme configuration.
+
+use std::fmt::Write;
+
+use super::{extended, header, hex};
+use crate::Base16Palette;
+
+/// Returns a kitty theme setting the default, cursor, selection, tab bar and
+/// sixteen ANSI colors, following the template of base16-kitty.
+///
+/// The output can be saved as a theme file and loaded with `include` from
+/// `kitty.conf`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let conf = export::to_kitty_conf(&DRACULA);
+/// assert!(conf.contains("background #282936\n"));
+/// assert!(conf.contains("color1 #ea51b2\n"));
+/// ```
+pub fn to_kitty_conf(palette: &Base16Palette) -> String {
+    let mut out = header(palette, "#");
+    let directives = [
+        ("background", palette.base00),
+        ("foreground", palette.base05),
+        ("selection_background", palette.base05),
+        ("selection_foreground", palette.base00),
+        ("url_color", palette.base04),
+        ("cursor", palette.base05),
+        ("cursor_text_color", palette.base00),
+        ("active_border_color", palette.base03),
+        ("inactive_border_color", palette.base01),
+        ("active_tab_background", palette.base00),
+        ("active_tab_foreground", palette.base05),
+        ("inactive_tab_background", palette.base01),
+        ("inactive_tab_foreground", palette.base04),
+        ("tab_bar_background", palette.base01),
+    ];
+    for (name, color) in directives {
+        let _ = writeln!(out, "{name} {}", hex(color));
+    }
+    out.push('\n');
+    for (index, color) in palette.to_ansi().into_iter().enumerate() {
+        let _ = writeln!(out, "color{index} {}", hex(color));
+    }
+    for (index, color) in (16..).zip(extended(palette)) {
+        let _ = writeln!(out, "color{index} {}", hex(color));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn directives_cover_every_color() {
+        let conf = to_kitty_conf(&DEFAULT_DARK);
+        let lines: Vec<_> = conf.lines().collect();
+        assert!(lines.contains(&"selection_background #d8d8d8"));
+        assert!(lines.contains(&"cursor #d8d8d8"));
+        assert!(lines.contains(&"color0 #181818"));
+        assert!(lines.contains(&"color9 #ab4642"));
+        assert_eq!(lines.last(), Some(&"color21 #e8e8e8"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Exporters writing palettes in the configuration formats of other tools.
+//!
+//! Each exporter returns the contents of a configuration file as a string,
+//! so applications can offer a matching theme for the terminal, shell or
+//! editor around them:
+//!
+//! ```rust
+//! use ratatui_base16::{export, DRACULA};
+//!
+//! let script = export::to_shell_script(&DRACULA);
+//! assert!(script.starts_with("#!/bin/sh\n"));
+//! ```
+//!
+//! The exporters follow the templates of the base16 project where one
+//! exists, mapping the ANSI colors with [`Base16Palette::to_ansi`]. Slots set
+//! to `Color::Reset` have no concrete value and are exported as black.
+//!
+//! [`Base16Palette::to_ansi`]: crate::Base16Palette::to_ansi
+
+use ratatui::style::Color;
+
+use crate::{color, registry, Base16Color, Base16Palette};
+
+mod alacritty;
+mod css;
+mod fzf;
+mod ghostty;
+mod git;
+mod helix;
+mod iterm;
+mod kitty;
+mod neovim;
+mod shell;
+mod starship;
+mod tmtheme;
+mod tmux;
+mod wezterm;
+mod windows_terminal;
+mod xresources;
+mod zellij;
+
+pub use alacritty::to_alacritty_toml;
+pub use css::{to_css, to_css_with, CssOptions};
+pub use fzf::to_fzf;
+pub use ghostty::to_ghostty;
+pub use git::{to_gitui_ron, to_lazygit_yaml};
+pub use helix::to_helix_toml;
+pub use iterm::to_itermcolors;
+pub use kitty::to_kitty_conf;
+pub use neovim::to_neovim_lua;
+pub use shell::to_shell_script;
+pub use starship::to_starship_toml;
+pub use tmtheme::{to_delta_gitconfig, to_tmtheme};
+pub use tmux::to_tmux;
+pub use wezterm::to_wezterm_lua;
+pub use windows_terminal::to_windows_terminal_json;
+pub use xresources::to_xresources;
+pub use zellij::to_zellij_kdl;
+
+#[cfg(feature = "syntect")]
+pub(crate) use tmtheme::rules as textmate_rules;
+
+/// The names of the eight ANSI colors, in order.
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// Names for the roles the slots play in the base16 styling guidelines, used
+/// by exporters that offer semantic aliases.
+const ALIASES: [(&str, Base16Color); 16] = [
+    ("background", Base16Color::Base00),
+    ("background-alt", Base16Color::Base01),
+    ("selection", Base16Color::Base02),
+    ("comment", Base16Color::Base03),
+    ("foreground-alt", Base16Color::Base04),
+    ("foreground", Base16Color::Base05),
+    ("foreground-light", Base16Color::Base06),
+    ("background-light", Base16Color::Base07),
+    ("red", Base16Color::Base08),
+    ("orange", Base16Color::Base09),
+    ("yellow", Base16Color::Base0A),
+    ("green", Base16Color::Base0B),
+    ("cyan", Base16Color::Base0C),
+    ("blue", Base16Color::Base0D),
+    ("magenta", Base16Color::Base0E),
+    ("brown", Base16Color::Base0F),
+];
+
+/// Resolves a color to its RGB components, using black for `Color::Reset`.
+fn rgb(color: Color) -> (u8, u8, u8) {
+    color::to_rgb(color).unwrap_or_default()
+}
+
+/// Formats a color as a lowercase `#rrggbb` string.
+fn hex(color: Color) -> String {
+    let (r, g, b) = rgb(color);
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Returns a comment naming the scheme and its author, followed by an empty
+/// line, for formats whose comments start with `marker`.
+fn header(palette: &Base16Palette, marker: &str) -> String {
+    if palette.author.is_empty() {
+        format!("{marker} {}\n\n", palette.name)
+    } else {
+        format!("{marker} {} by {}\n\n", palette.name, palette.author)
+    }
+}
+
+/// Returns the colors base16 templates assign to indexes 16 to 21 of the
+/// 256-color palette: base09, base0F, base01, base02, base04 and base06.
+fn extended(palette: &Base16Palette) -> [Color; 6] {
+    [
+        palette.base09,
+        palette.base0f,
+        palette.base01,
+        palette.base02,
+        palette.base04,
+        palette.base06,
+    ]
+}
+
+/// Turns a scheme name into an identifier for formats that name themes, by
+/// lowercasing it, stripping accents and joining its words with hyphens,
+/// e.g. `rose-pine-moon` for "Rosé Pine Moon".
+///
+/// Names without letters or digits yield `"base16"`.
+fn slug(name: &str) -> String {
+    let words: Vec<String> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            word.chars()
+                .flat_map(char::to_lowercase)
+                .map(registry::strip_accent)
+                .collect()
+        })
+        .collect();
+    if words.is_empty() {
+        "base16".to_string()
+    } else {
+        words.join("-")
+    }
+}
@@ -0,0 +1,174 @@
+//! Neovim colorschemes.
+
+use std::fmt::Write;
+
+use ratatui::style::{Modifier, Style};
+
+use super::{header, hex, slug};
+use crate::Base16Palette;
+
+/// The attributes of `nvim_set_hl` for the modifiers of a style.
+const ATTRIBUTES: [(Modifier, &str); 5] = [
+    (Modifier::BOLD, "bold"),
+    (Modifier::ITALIC, "italic"),
+    (Modifier::UNDERLINED, "underline"),
+    (Modifier::REVERSED, "reverse"),
+    (Modifier::CROSSED_OUT, "strikethrough"),
+];
+
+/// Returns a Neovim colorscheme written in Lua, setting the terminal colors
+/// and the core highlight groups after the palette.
+///
+/// Highlight groups follow the mapping of base16-vim, e.g. `Comment` uses
+/// base03, `String` base0B and `Function` base0D, and the terminal colors
+/// follow [`Base16Palette::to_ansi`]. Saved as `colors/base16-<slug>.lua`
+/// in the runtime path, the scheme is loaded with `:colorscheme`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let lua = export::to_neovim_lua(&DRACULA);
+/// assert!(lua.contains("vim.g.colors_name = \"base16-dracula\"\n"));
+/// assert!(lua.contains("hi(0, \"String\", { fg = \"#ebff87\" })\n"));
+/// ```
+pub fn to_neovim_lua(palette: &Base16Palette) -> String {
+    let p = palette;
+    let fg = |color| Style::new().fg(color);
+    let bg = |color| Style::new().bg(color);
+    let groups = [
+        // Editor
+        ("Normal", fg(p.base05).bg(p.base00)),
+        ("Bold", Style::new().add_modifier(Modifier::BOLD)),
+        ("Italic", Style::new().add_modifier(Modifier::ITALIC)),
+        ("Debug", fg(p.base08)),
+        ("Directory", fg(p.base0d)),
+        ("Error", fg(p.base00).bg(p.base08)),
+        ("ErrorMsg", fg(p.base08).bg(p.base00)),
+        ("Exception", fg(p.base08)),
+        ("FoldColumn", fg(p.base0c).bg(p.base01)),
+        ("Folded", fg(p.base03).bg(p.base01)),
+        ("IncSearch", fg(p.base01).bg(p.base09)),
+        ("Macro", fg(p.base08)),
+        ("MatchParen", bg(p.base03)),
+        ("ModeMsg", fg(p.base0b)),
+        ("MoreMsg", fg(p.base0b)),
+        ("Question", fg(p.base0d)),
+        ("Search", fg(p.base01).bg(p.base0a)),
+        ("Substitute", fg(p.base01).bg(p.base0a)),
+        ("SpecialKey", fg(p.base03)),
+        ("TooLong", fg(p.base08)),
+        ("Underlined", fg(p.base08)),
+        ("Visual", bg(p.base02)),
+        ("WarningMsg", fg(p.base08)),
+        ("WildMenu", fg(p.base08).bg(p.base0a)),
+        ("Title", fg(p.base0d)),
+        ("Conceal", fg(p.base0d).bg(p.base00)),
+        ("Cursor", fg(p.base00).bg(p.base05)),
+        ("NonText", fg(p.base03)),
+        ("LineNr", fg(p.base03).bg(p.base01)),
+        ("SignColumn", fg(p.base03).bg(p.base01)),
+        ("StatusLine", fg(p.base04).bg(p.base02)),
+        ("StatusLineNC", fg(p.base03).bg(p.base01)),
+        ("VertSplit", fg(p.base02).bg(p.base02)),
+        ("ColorColumn", bg(p.base01)),
+        ("CursorColumn", bg(p.base01)),
+        ("CursorLine", bg(p.base01)),
+        ("CursorLineNr", fg(p.base04).bg(p.base01)),
+        ("QuickFixLine", bg(p.base01)),
+        ("PMenu", fg(p.base05).bg(p.base01)),
+        ("PMenuSel", fg(p.base01).bg(p.base05)),
+        ("TabLine", fg(p.base03).bg(p.base01)),
+        ("TabLineFill", fg(p.base03).bg(p.base01)),
+        ("TabLineSel", fg(p.base0b).bg(p.base01)),
+        // Syntax
+        ("Boolean", fg(p.base09)),
+        ("Character", fg(p.base08)),
+        ("Comment", fg(p.base03)),
+        ("Conditional", fg(p.base0e)),
+        ("Constant", fg(p.base09)),
+        ("Define", fg(p.base0e)),
+        ("Delimiter", fg(p.base0f)),
+        ("Float", fg(p.base09)),
+        ("Function", fg(p.base0d)),
+        ("Identifier", fg(p.base08)),
+        ("Include", fg(p.base0d)),
+        ("Keyword", fg(p.base0e)),
+        ("Label", fg(p.base0a)),
+        ("Number", fg(p.base09)),
+        ("Operator", fg(p.base05)),
+        ("PreProc", fg(p.base0a)),
+        ("Repeat", fg(p.base0a)),
+        ("Special", fg(p.base0c)),
+        ("SpecialChar", fg(p.base0f)),
+        ("Statement", fg(p.base08)),
+        ("StorageClass", fg(p.base0a)),
+        ("String", fg(p.base0b)),
+        ("Structure", fg(p.base0e)),
+        ("Tag", fg(p.base0a)),
+        ("Todo", fg(p.base0a).bg(p.base01)),
+        ("Type", fg(p.base0a)),
+        ("Typedef", fg(p.base0a)),
+        // Diffs
+        ("DiffAdd", fg(p.base0b).bg(p.base01)),
+        ("DiffChange", fg(p.base03).bg(p.base01)),
+        ("DiffDelete", fg(p.base08).bg(p.base01)),
+        ("DiffText", fg(p.base0d).bg(p.base01)),
+    ];
+
+    let mut out = header(palette, "--");
+    out.push_str("vim.cmd(\"highlight clear\")\n");
+    out.push_str("if vim.fn.exists(\"syntax_on\") == 1 then\n  vim.cmd(\"syntax reset\")\nend\n");
+    let _ = writeln!(out, "vim.o.background = \"{}\"", palette.variant());
+    let _ = writeln!(
+        out,
+        "vim.g.colors_name = \"base16-{}\"",
+        slug(&palette.name)
+    );
+    out.push('\n');
+    for (index, color) in palette.to_ansi().into_iter().enumerate() {
+        let _ = writeln!(out, "vim.g.terminal_color_{index} = \"{}\"", hex(color));
+    }
+    out.push_str("\nlocal hi = vim.api.nvim_set_hl\n");
+    for (group, style) in groups {
+        let _ = writeln!(out, "hi(0, \"{group}\", {})", attributes(style));
+    }
+    out
+}
+
+/// Formats a style as the attribute table of `nvim_set_hl`.
+fn attributes(style: Style) -> String {
+    let mut fields = Vec::new();
+    if let Some(color) = style.fg {
+        fields.push(format!("fg = \"{}\"", hex(color)));
+    }
+    if let Some(color) = style.bg {
+        fields.push(format!("bg = \"{}\"", hex(color)));
+    }
+    for (modifier, name) in ATTRIBUTES {
+        if style.add_modifier.contains(modifier) {
+            fields.push(format!("{name} = true"));
+        }
+    }
+    format!("{{ {} }}", fields.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_LIGHT;
+
+    #[test]
+    fn groups_follow_base16_vim() {
+        let lua = to_neovim_lua(&DEFAULT_LIGHT);
+        let lines: Vec<_> = lua.lines().collect();
+        assert!(lines.contains(&"vim.o.background = \"light\""));
+        assert!(lines.contains(&"vim.g.terminal_color_8 = \"#b8b8b8\""));
+        assert!(lines.contains(&"hi(0, \"Normal\", { fg = \"#383838\", bg = \"#f8f8f8\" })"));
+        assert!(lines.contains(&"hi(0, \"Bold\", { bold = true })"));
+        assert_eq!(
+            lines.last(),
+            Some(&"hi(0, \"DiffText\", { fg = \"#7cafc2\", bg = \"#e8e8e8\" })")
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Shell scripts setting the terminal colors, as generated by base16-shell.
+
+use std::fmt::Write;
+
+use super::{extended, rgb};
+use crate::Base16Palette;
+
+/// The names of the slots behind the ANSI colors, for the comments of the
+/// script.
+const ANSI_SLOTS: [&str; 16] = [
+    "Base 00 - Black",
+    "Base 08 - Red",
+    "Base 0B - Green",
+    "Base 0A - Yellow",
+    "Base 0D - Blue",
+    "Base 0E - Magenta",
+    "Base 0C - Cyan",
+    "Base 05 - White",
+    "Base 03 - Bright Black",
+    "Base 08 - Bright Red",
+    "Base 0B - Bright Green",
+    "Base 0A - Bright Yellow",
+    "Base 0D - Bright Blue",
+    "Base 0E - Bright Magenta",
+    "Base 0C - Bright Cyan",
+    "Base 07 - Bright White",
+];
+
+/// The escape sequence writers of base16-shell, passing the sequences
+/// through tmux and GNU screen and falling back to `ESC ] P` on the Linux
+/// console.
+const TEMPLATES: &str = r#"if [ -n "$TMUX" ]; then
+  # Tell tmux to pass the escape sequences through
+  put_template() { printf '\033Ptmux;\033\033]4;%d;rgb:%s\033\033\\\033\\' $@; }
+  put_template_var() { printf '\033Ptmux;\033\033]%d;rgb:%s\033\033\\\033\\' $@; }
+  put_template_custom() { printf '\033Ptmux;\033\033]%s%s\033\033\\\033\\' $@; }
+elif [ "${TERM%%[-.]*}" = "screen" ]; then
+  # GNU screen (screen, screen-256color, screen-256color-bce)
+  put_template() { printf '\033P\033]4;%d;rgb:%s\007\033\\' $@; }
+  put_template_var() { printf '\033P\033]%d;rgb:%s\007\033\\' $@; }
+  put_template_custom() { printf '\033P\033]%s%s\007\033\\' $@; }
+elif [ "${TERM%%-*}" = "linux" ]; then
+  put_template() { [ $1 -lt 16 ] && printf "\e]P%x%s" $1 $(echo $2 | sed 's/\///g'); }
+  put_template_var() { true; }
+  put_template_custom() { true; }
+else
+  put_template() { printf '\033]4;%d;rgb:%s\033\\' $@; }
+  put_template_var() { printf '\033]%d;rgb:%s\033\\' $@; }
+  put_template_custom() { printf '\033]%s%s\033\\' $@; }
+fi
+"#;
+
+/// Returns a POSIX shell script that sets the terminal colors to the
+/// palette, following the template of base16-shell.
+///
+/// Besides the sixteen ANSI colors, the script sets colors 16 to 21 to
+/// base09, base0F, base01, base02, base04 and base06, as the 256-color
+/// base16 themes of vim and other programs expect, and the default
+/// foreground and background. Setting `BASE16_SHELL_SET_BACKGROUND=false`
+/// keeps the terminal background as it is.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let script = export::to_shell_script(&DRACULA);
+/// assert!(script.contains("color00=\"28/29/36\" # Base 00 - Black\n"));
+/// assert!(script.contains("put_template 15 $color15\n"));
+/// ```
+pub fn to_shell_script(palette: &Base16Palette) -> String {
+    let color = |color| {
+        let (r, g, b) = rgb(color);
+        format!("\"{r:02x}/{g:02x}/{b:02x}\"")
+    };
+    let plain = |color| {
+        let (r, g, b) = rgb(color);
+        format!("{r:02x}{g:02x}{b:02x}")
+    };
+
+    let mut out = String::from("#!/bin/sh\n");
+    let _ = writeln!(out, "# base16-shell script for {}", palette.name);
+    if !palette.author.is_empty() {
+        let _ = writeln!(out, "# Scheme author: {}", palette.author);
+    }
+    out.push('\n');
+
+    for (index, (value, slot)) in palette.to_ansi().into_iter().zip(ANSI_SLOTS).enumerate() {
+        // The bright colors repeat the normal ones, except black and white.
+        if (9..15).contains(&index) {
+            let _ = writeln!(out, "color{index:02}=$color{:02} # {slot}", index - 8);
+        } else {
+            let _ = writeln!(out, "color{index:02}={} # {slot}", color(value));
+        }
+    }
+    let slots = ["09", "0F", "01", "02", "04", "06"];
+    for (index, (slot, value)) in (16..).zip(slots.into_iter().zip(extended(palette))) {
+        let _ = writeln!(out, "color{index}={} # Base {slot}", color(value));
+    }
+    let _ = writeln!(out, "color_foreground={} # Base 05", color(palette.base05));
+    let _ = writeln!(out, "color_background={} # Base 00", color(palette.base00));
+    out.push('\n');
+    out.push_str(TEMPLATES);
+
+    out.push_str("\n# 16 color space\n");
+    for index in 0..16 {
+        let _ = writeln!(out, "put_template {index} $color{index:02}");
+    }
+    out.push_str("\n# 256 color space\n");
+    for index in 16..22 {
+        let _ = writeln!(out, "put_template {index} $color{index}");
+    }
+
+    let (foreground, background) = (plain(palette.base05), plain(palette.base00));
+    let selection = plain(palette.base02);
+    let _ = write!(
+        out,
+        r#"
+# foreground / background / cursor color
+if [ -n "$ITERM_SESSION_ID" ]; then
+  # iTerm2 proprietary escape codes
+  put_template_custom Pg {foreground} # foreground
+  put_template_custom Ph {background} # background
+  put_template_custom Pi {foreground} # bold color
+  put_template_custom Pj {selection} # selection color
+  put_template_custom Pk {foreground} # selected text color
+  put_template_custom Pl {foreground} # cursor
+  put_template_custom Pm {background} # cursor text
+else
+  put_template_var 10 $color_foreground
+  if [ "$BASE16_SHELL_SET_BACKGROUND" != false ]; then
+    put_template_var 11 $color_background
+    if [ "${{TERM%%-*}}" = "rxvt" ]; then
+      put_template_var 708 $color_background # internal border (rxvt)
+    fi
+  fi
+  put_template_custom 12 ";7" # cursor (reverse video)
+fi
+
+# clean up
+unset -f put_template
+unset -f put_template_var
+unset -f put_template_custom
+"#
+    );
+    for index in 0..22 {
+        let _ = writeln!(out, "unset color{index:02}");
+    }
+    out.push_str("unset color_foreground\nunset color_background\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn script_matches_the_base16_shell_template() {
+        let script = to_shell_script(&DEFAULT_DARK);
+        assert!(script.contains("color01=\"ab/46/42\" # Base 08 - Red\n"));
+        assert!(script.contains("color09=$color01 # Base 08 - Bright Red\n"));
+        assert!(script.contains("color15=\"f8/f8/f8\" # Base 07 - Bright White\n"));
+        assert!(script.contains("color16=\"dc/96/56\" # Base 09\n"));
+        assert!(script.contains("put_template_custom Ph 181818 # background\n"));
+        assert!(script.ends_with("unset color21\nunset color_foreground\nunset color_background\n"));
+    }
+}
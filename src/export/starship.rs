@@ -0,0 +1,49 @@
+//! Starship prompt palettes.
+
+use std::fmt::Write;
+
+use super::{hex, slug, ALIASES};
+use crate::Base16Palette;
+
+/// Returns a Starship configuration selecting a `[palettes.<slug>]` table
+/// with the sixteen slots and their semantic aliases.
+///
+/// Prompt modules can then refer to colors by slot or role, e.g.
+/// `style = "bold base0D"` or `style = "fg:red bg:background-alt"`. The
+/// output can be appended to `starship.toml`, as long as it doesn't
+/// already set `palette`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let toml = export::to_starship_toml(&DRACULA);
+/// assert!(toml.starts_with("palette = \"dracula\"\n\n[palettes.dracula]\n"));
+/// assert!(toml.contains("base0D = \"#62d6e8\"\n"));
+/// ```
+pub fn to_starship_toml(palette: &Base16Palette) -> String {
+    let name = slug(&palette.name);
+    let mut out = format!("palette = \"{name}\"\n\n[palettes.{name}]\n");
+    for (slot, color) in palette.iter() {
+        let _ = writeln!(out, "{} = \"{}\"", slot.name(), hex(color));
+    }
+    for (alias, slot) in ALIASES {
+        let _ = writeln!(out, "{alias} = \"{}\"", hex(palette.color(slot)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ROSE_PINE_MOON;
+
+    #[test]
+    fn palette_table_is_named_after_the_slug() {
+        let toml = to_starship_toml(&ROSE_PINE_MOON);
+        let lines: Vec<_> = toml.lines().collect();
+        assert_eq!(lines[2], "[palettes.rose-pine-moon]");
+        assert_eq!(lines.len(), 3 + 16 + 16);
+        assert!(lines.contains(&"background-alt = \"#2a273f\""));
+    }
+}
@@ -0,0 +1,239 @@
+//! TextMate themes for bat and delta.
+
+use std::fmt::Write;
+
+use ratatui::style::{Color, Modifier};
+
+use super::{hex, slug};
+use crate::{color, Base16Palette};
+
+/// Returns the scope rules of the base16 TextMate template, as the scope
+/// selector, the foreground and the font style of each rule.
+///
+/// Only `BOLD` and `ITALIC` are used as font styles.
+pub(crate) fn rules(p: &Base16Palette) -> [(&'static str, Color, Modifier); 31] {
+    let none = Modifier::empty();
+    [
+        ("text, source", p.base05, none),
+        ("comment, punctuation.definition.comment", p.base03, none),
+        ("punctuation, keyword.operator", p.base05, none),
+        ("variable, entity.name.tag, markup.list", p.base08, none),
+        (
+            "constant, constant.numeric, constant.language",
+            p.base09,
+            none,
+        ),
+        (
+            "entity.other.attribute-name, support.other.variable",
+            p.base09,
+            none,
+        ),
+        ("markup.underline.link, string.other.link", p.base09, none),
+        (
+            "entity.name, entity.name.class, entity.name.type",
+            p.base0a,
+            none,
+        ),
+        ("support.type, support.class", p.base0a, none),
+        ("markup.bold", p.base0a, Modifier::BOLD),
+        ("string, markup.raw, markup.inserted", p.base0b, none),
+        ("entity.other.inherited-class", p.base0b, none),
+        ("string.regexp, constant.character.escape", p.base0c, none),
+        ("support.function, markup.quote", p.base0c, none),
+        (
+            "entity.name.function, meta.function-call, support.method",
+            p.base0d,
+            none,
+        ),
+        ("entity.name.section, markup.heading", p.base0d, none),
+        (
+            "keyword, storage, storage.type, storage.modifier",
+            p.base0e,
+            none,
+        ),
+        ("markup.changed", p.base0e, none),
+        ("markup.italic", p.base0e, Modifier::ITALIC),
+        ("markup.deleted", p.base08, none),
+        ("invalid.deprecated", p.base0f, none),
+        (
+            "punctuation.section.embedded, variable.interpolation",
+            p.base0f,
+            none,
+        ),
+        ("invalid.illegal", p.base08, none),
+        ("meta.separator", p.base02, none),
+        ("variable.parameter", p.base05, none),
+        ("variable.language", p.base08, none),
+        ("constant.other.color", p.base0c, none),
+        ("constant.character", p.base09, none),
+        ("support.constant", p.base09, none),
+        ("keyword.other.unit", p.base09, none),
+        ("meta.diff.header, meta.diff.range", p.base0d, none),
+    ]
+}
+
+/// Returns a TextMate theme, the `.tmTheme` property list read by bat,
+/// delta and Sublime Text, following the base16 TextMate template.
+///
+/// The scopes are the same as those of `Base16Palette::to_syntect_theme`
+/// with the `syntect` feature. bat and delta read themes from
+/// `$(bat --config-dir)/themes` after `bat cache --build`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let theme = export::to_tmtheme(&DRACULA);
+/// assert!(theme.contains("\t<key>name</key>\n\t<string>Dracula</string>\n"));
+/// ```
+pub fn to_tmtheme(palette: &Base16Palette) -> String {
+    let p = palette;
+    let mut out = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+        "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+        "<plist version=\"1.0\">\n",
+        "<dict>\n",
+    ));
+    let _ = writeln!(
+        out,
+        "\t<key>name</key>\n\t<string>{}</string>",
+        escape(&p.name)
+    );
+    if !p.author.is_empty() {
+        let _ = writeln!(
+            out,
+            "\t<key>author</key>\n\t<string>{}</string>",
+            escape(&p.author)
+        );
+    }
+    out.push_str("\t<key>settings</key>\n\t<array>\n\t\t<dict>\n");
+    out.push_str("\t\t\t<key>settings</key>\n\t\t\t<dict>\n");
+    let settings = [
+        ("background", p.base00),
+        ("foreground", p.base05),
+        ("caret", p.base05),
+        ("invisibles", p.base03),
+        ("lineHighlight", p.base01),
+        ("selection", p.base02),
+        ("gutter", p.base01),
+        ("gutterForeground", p.base03),
+        ("findHighlight", p.base0a),
+        ("findHighlightForeground", p.base00),
+    ];
+    for (key, color) in settings {
+        let _ = writeln!(
+            out,
+            "\t\t\t\t<key>{key}</key>\n\t\t\t\t<string>{}</string>",
+            hex(color)
+        );
+    }
+    out.push_str("\t\t\t</dict>\n\t\t</dict>\n");
+
+    for (scope, foreground, modifier) in rules(p) {
+        out.push_str("\t\t<dict>\n");
+        let _ = writeln!(
+            out,
+            "\t\t\t<key>scope</key>\n\t\t\t<string>{scope}</string>"
+        );
+        out.push_str("\t\t\t<key>settings</key>\n\t\t\t<dict>\n");
+        let _ = writeln!(
+            out,
+            "\t\t\t\t<key>foreground</key>\n\t\t\t\t<string>{}</string>",
+            hex(foreground)
+        );
+        let font_style = match modifier {
+            Modifier::BOLD => Some("bold"),
+            Modifier::ITALIC => Some("italic"),
+            _ => None,
+        };
+        if let Some(font_style) = font_style {
+            let _ = writeln!(
+                out,
+                "\t\t\t\t<key>fontStyle</key>\n\t\t\t\t<string>{font_style}</string>"
+            );
+        }
+        out.push_str("\t\t\t</dict>\n\t\t</dict>\n");
+    }
+    out.push_str("\t</array>\n</dict>\n</plist>\n");
+    out
+}
+
+/// Returns a `[delta]` section for `.gitconfig` highlighting diffs with the
+/// theme written by [`to_tmtheme`] and tinting changed lines with the diff
+/// colors of the palette.
+///
+/// Removed lines get a background of base08 blended into base00 and added
+/// lines one of base0B, with stronger tints for the changed words. The
+/// theme is expected under the slug of the palette, e.g. `dracula.tmTheme`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let config = export::to_delta_gitconfig(&DRACULA);
+/// assert!(config.contains("\tsyntax-theme = dracula\n"));
+/// ```
+pub fn to_delta_gitconfig(palette: &Base16Palette) -> String {
+    let p = palette;
+    let tint = |accent, amount| hex(color::blend(p.base00, accent, amount));
+    let options = [
+        ("syntax-theme", slug(&p.name)),
+        ("minus-style", format!("syntax \"{}\"", tint(p.base08, 0.2))),
+        (
+            "minus-emph-style",
+            format!("syntax \"{}\"", tint(p.base08, 0.4)),
+        ),
+        ("plus-style", format!("syntax \"{}\"", tint(p.base0b, 0.2))),
+        (
+            "plus-emph-style",
+            format!("syntax \"{}\"", tint(p.base0b, 0.4)),
+        ),
+        ("line-numbers-minus-style", format!("\"{}\"", hex(p.base08))),
+        ("line-numbers-plus-style", format!("\"{}\"", hex(p.base0b))),
+        ("line-numbers-zero-style", format!("\"{}\"", hex(p.base03))),
+        ("file-style", format!("\"{}\"", hex(p.base0d))),
+        (
+            "hunk-header-decoration-style",
+            format!("\"{}\" box", hex(p.base03)),
+        ),
+    ];
+    let mut out = String::from("[delta]\n");
+    for (option, value) in options {
+        let _ = writeln!(out, "\t{option} = {value}");
+    }
+    out
+}
+
+/// Escapes the characters with a special meaning in XML text.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn theme_and_delta_config_use_the_palette() {
+        let theme = to_tmtheme(&DEFAULT_DARK);
+        assert_eq!(theme.matches("<key>scope</key>").count(), 31);
+        assert!(theme.contains(concat!(
+            "\t\t\t<key>scope</key>\n",
+            "\t\t\t<string>markup.bold</string>\n",
+            "\t\t\t<key>settings</key>\n",
+            "\t\t\t<dict>\n",
+            "\t\t\t\t<key>foreground</key>\n",
+            "\t\t\t\t<string>#f7ca88</string>\n",
+            "\t\t\t\t<key>fontStyle</key>\n",
+            "\t\t\t\t<string>bold</string>\n",
+        )));
+        assert_eq!(escape("Tom & Jerry <3"), "Tom &amp; Jerry &lt;3");
+
+        let config = to_delta_gitconfig(&DEFAULT_DARK);
+        assert!(config.contains("\tminus-style = syntax \"#542625\"\n"));
+    }
+}
@@ -0,0 +1,69 @@
+//! tmux status bar and pane styles.
+
+use std::fmt::Write;
+
+use super::{header, hex};
+use crate::Base16Palette;
+
+/// Returns tmux commands styling the status bar, pane borders, messages and
+/// copy mode after the palette, following the template of base16-tmux.
+///
+/// The status bar is drawn in base04 on base01 with the current window in
+/// base0A, pane borders use base01 and the active one base0D, and copy mode
+/// selections base04 on base02. The output can be saved as a file and
+/// loaded with `source-file` from `tmux.conf`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let conf = export::to_tmux(&DRACULA);
+/// assert!(conf.contains("set -g status-style \"fg=#62d6e8,bg=#3a3c4e\"\n"));
+/// ```
+pub fn to_tmux(palette: &Base16Palette) -> String {
+    let style = |fg, bg| format!("\"fg={},bg={}\"", hex(fg), hex(bg));
+    let fg = |fg| format!("\"fg={}\"", hex(fg));
+    let fg_default = |fg| format!("\"fg={},bg=default\"", hex(fg));
+    let colour = |color| format!("\"{}\"", hex(color));
+
+    let options = [
+        ("status-style", style(palette.base04, palette.base01)),
+        ("window-status-style", fg_default(palette.base04)),
+        ("window-status-current-style", fg_default(palette.base0a)),
+        (
+            "window-status-bell-style",
+            style(palette.base01, palette.base08),
+        ),
+        ("pane-border-style", fg(palette.base01)),
+        ("pane-active-border-style", fg(palette.base0d)),
+        ("message-style", style(palette.base05, palette.base01)),
+        (
+            "message-command-style",
+            style(palette.base05, palette.base01),
+        ),
+        ("mode-style", style(palette.base04, palette.base02)),
+        ("display-panes-active-colour", colour(palette.base0b)),
+        ("display-panes-colour", colour(palette.base0a)),
+        ("clock-mode-colour", colour(palette.base0b)),
+    ];
+    let mut out = header(palette, "#");
+    for (option, value) in options {
+        let _ = writeln!(out, "set -g {option} {value}");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn chrome_is_styled_from_the_palette() {
+        let conf = to_tmux(&DEFAULT_DARK);
+        let lines: Vec<_> = conf.lines().collect();
+        assert!(lines.contains(&"set -g pane-active-border-style \"fg=#7cafc2\""));
+        assert!(lines.contains(&"set -g window-status-current-style \"fg=#f7ca88,bg=default\""));
+        assert!(lines.contains(&"set -g mode-style \"fg=#b8b8b8,bg=#383838\""));
+    }
+}
@@ -0,0 +1,96 @@
+//! WezTerm color schemes.
+
+use std::fmt::Write;
+
+use super::{extended, header, hex};
+use crate::Base16Palette;
+
+/// Returns a Lua module evaluating to a WezTerm color scheme, with the
+/// sixteen ANSI colors and a tab bar drawn in the background shades.
+///
+/// The tab bar uses base01 for its background and inactive tabs, base00 for
+/// the active tab and base02 for hovered tabs. Saved as `base16.lua` next
+/// to `wezterm.lua`, the scheme is registered with:
+///
+/// ```lua
+/// config.color_schemes = { ["Dracula"] = require("base16") }
+/// config.color_scheme = "Dracula"
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let lua = export::to_wezterm_lua(&DRACULA);
+/// assert!(lua.contains("  background = \"#282936\",\n"));
+/// ```
+pub fn to_wezterm_lua(palette: &Base16Palette) -> String {
+    let mut out = header(palette, "--");
+    out.push_str("return {\n");
+    let colors = [
+        ("foreground", palette.base05),
+        ("background", palette.base00),
+        ("cursor_bg", palette.base05),
+        ("cursor_fg", palette.base00),
+        ("cursor_border", palette.base05),
+        ("selection_bg", palette.base02),
+        ("selection_fg", palette.base05),
+        ("scrollbar_thumb", palette.base02),
+        ("split", palette.base03),
+    ];
+    for (key, color) in colors {
+        let _ = writeln!(out, "  {key} = \"{}\",", hex(color));
+    }
+    let ansi = palette.to_ansi();
+    for (key, colors) in [("ansi", &ansi[..8]), ("brights", &ansi[8..])] {
+        let colors: Vec<_> = colors
+            .iter()
+            .map(|color| format!("\"{}\"", hex(*color)))
+            .collect();
+        let _ = writeln!(out, "  {key} = {{ {} }},", colors.join(", "));
+    }
+    out.push_str("  indexed = {\n");
+    for (index, color) in (16..).zip(extended(palette)) {
+        let _ = writeln!(out, "    [{index}] = \"{}\",", hex(color));
+    }
+    out.push_str("  },\n");
+
+    let _ = writeln!(out, "  tab_bar = {{");
+    let _ = writeln!(out, "    background = \"{}\",", hex(palette.base01));
+    let tabs = [
+        ("active_tab", palette.base00, palette.base05),
+        ("inactive_tab", palette.base01, palette.base04),
+        ("inactive_tab_hover", palette.base02, palette.base05),
+        ("new_tab", palette.base01, palette.base04),
+        ("new_tab_hover", palette.base02, palette.base05),
+    ];
+    for (key, background, foreground) in tabs {
+        let _ = writeln!(
+            out,
+            "    {key} = {{ bg_color = \"{}\", fg_color = \"{}\" }},",
+            hex(background),
+            hex(foreground)
+        );
+    }
+    out.push_str("  },\n}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn scheme_lists_the_ansi_colors() {
+        let lua = to_wezterm_lua(&DEFAULT_DARK);
+        assert!(lua.contains(
+            "  ansi = { \"#181818\", \"#ab4642\", \"#a1b56c\", \"#f7ca88\", \"#7cafc2\", \"#ba8baf\", \"#86c1b9\", \"#d8d8d8\" },\n"
+        ));
+        assert!(lua.contains("  brights = { \"#585858\", "));
+        assert!(
+            lua.contains("    active_tab = { bg_color = \"#181818\", fg_color = \"#d8d8d8\" },\n")
+        );
+        assert!(lua.ends_with("  },\n}\n"));
+    }
+}
@@ -0,0 +1,99 @@
+//! Windows Terminal color schemes.
+
+use std::fmt::Write;
+
+use super::hex;
+use crate::Base16Palette;
+
+/// The names Windows Terminal gives the ANSI colors, in order.
+const KEYS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "purple",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightPurple",
+    "brightCyan",
+    "brightWhite",
+];
+
+/// Returns a JSON object describing the palette as a Windows Terminal color
+/// scheme, to be added to the `schemes` list of `settings.json`.
+///
+/// The scheme is named after the palette, which is how profiles refer to it
+/// with their `colorScheme` setting.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let json = export::to_windows_terminal_json(&DRACULA);
+/// assert!(json.contains("  \"name\": \"Dracula\",\n"));
+/// assert!(json.contains("  \"brightBlack\": \"#626483\",\n"));
+/// ```
+pub fn to_windows_terminal_json(palette: &Base16Palette) -> String {
+    let mut entries = vec![
+        ("name", string(&palette.name)),
+        ("background", string(&hex(palette.base00))),
+        ("foreground", string(&hex(palette.base05))),
+        ("cursorColor", string(&hex(palette.base05))),
+        ("selectionBackground", string(&hex(palette.base02))),
+    ];
+    for (key, color) in KEYS.into_iter().zip(palette.to_ansi()) {
+        entries.push((key, string(&hex(color))));
+    }
+
+    let mut out = String::from("{\n");
+    for (index, (key, value)) in entries.iter().enumerate() {
+        let separator = if index + 1 < entries.len() { "," } else { "" };
+        let _ = writeln!(out, "  \"{key}\": {value}{separator}");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Formats `value` as a JSON string literal.
+fn string(value: &str) -> String {
+    let mut out = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn scheme_is_a_settings_entry() {
+        let json = to_windows_terminal_json(&DEFAULT_DARK);
+        assert!(
+            json.starts_with("{\n  \"name\": \"Default Dark\",\n  \"background\": \"#181818\",\n")
+        );
+        assert!(json.contains("  \"purple\": \"#ba8baf\",\n"));
+        assert!(json.ends_with("  \"brightWhite\": \"#f8f8f8\"\n}\n"));
+        assert_eq!(
+            string("a \"quoted\"\tname"),
+            "\"a \\\"quoted\\\"\\u0009name\""
+        );
+    }
+}
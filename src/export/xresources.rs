@@ -0,0 +1,58 @@
+//! X resources for xterm, urxvt and other X terminals.
+
+use std::fmt::Write;
+
+use super::{extended, header, hex};
+use crate::Base16Palette;
+
+/// Returns X resources setting the foreground, background, cursor and
+/// `color0` to `color21` of X terminals, following the template of
+/// base16-xresources.
+///
+/// The output can be loaded with `xrdb -merge`. Colors 16 to 21 are set
+/// like [`to_shell_script`] does, although not every terminal reads them.
+///
+/// [`to_shell_script`]: super::to_shell_script
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, DRACULA};
+/// let resources = export::to_xresources(&DRACULA);
+/// assert!(resources.contains("*background: #282936\n"));
+/// assert!(resources.contains("*color1: #ea51b2\n"));
+/// ```
+pub fn to_xresources(palette: &Base16Palette) -> String {
+    let mut out = header(palette, "!");
+    let _ = writeln!(out, "*foreground: {}", hex(palette.base05));
+    let _ = writeln!(out, "*background: {}", hex(palette.base00));
+    let _ = writeln!(out, "*cursorColor: {}", hex(palette.base05));
+    out.push('\n');
+    for (index, color) in palette.to_ansi().into_iter().enumerate() {
+        let _ = writeln!(out, "*color{index}: {}", hex(color));
+    }
+    out.push_str("! Not every terminal reads the colors beyond 15.\n");
+    for (index, color) in (16..).zip(extended(palette)) {
+        let _ = writeln!(out, "*color{index}: {}", hex(color));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn resources_use_the_ansi_mapping() {
+        let resources = to_xresources(&DEFAULT_DARK);
+        let lines: Vec<_> = resources.lines().collect();
+        assert_eq!(
+            lines[0],
+            "! Default Dark by Chris Kempson (http://chriskempson.com)"
+        );
+        assert!(lines.contains(&"*color8: #585858"));
+        assert!(lines.contains(&"*color15: #f8f8f8"));
+        assert_eq!(lines.last(), Some(&"*color21: #e8e8e8"));
+    }
+}
@@ -0,0 +1,66 @@
+//! Zellij themes.
+
+use std::fmt::Write;
+
+use super::{hex, slug};
+use crate::Base16Palette;
+
+/// Returns a Zellij `themes` block in KDL defining a theme named after the
+/// slug of the palette, e.g. `rose-pine` for Rosé Pine.
+///
+/// The fields follow the template of base16-zellij: `fg` is base05, `bg`
+/// base02 and the colors from `black` to `white` are the normal ANSI
+/// colors, except that `white` is base07, with `orange` for base09. The
+/// block can be appended to `config.kdl` or saved in the themes directory.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, ROSE_PINE};
+/// let kdl = export::to_zellij_kdl(&ROSE_PINE);
+/// assert!(kdl.contains("themes {\n    rose-pine {\n"));
+/// ```
+pub fn to_zellij_kdl(palette: &Base16Palette) -> String {
+    let fields = [
+        ("fg", palette.base05),
+        ("bg", palette.base02),
+        ("black", palette.base00),
+        ("red", palette.base08),
+        ("green", palette.base0b),
+        ("yellow", palette.base0a),
+        ("blue", palette.base0d),
+        ("magenta", palette.base0e),
+        ("cyan", palette.base0c),
+        ("white", palette.base07),
+        ("orange", palette.base09),
+    ];
+    let mut name = slug(&palette.name);
+    // Bare KDL identifiers can't start with a digit, as in "3024".
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name = format!("\"{name}\"");
+    }
+    let mut out = format!("themes {{\n    {name} {{\n");
+    for (field, color) in fields {
+        let _ = writeln!(out, "        {field} \"{}\"", hex(color));
+    }
+    out.push_str("    }\n}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn theme_is_named_after_the_slug() {
+        let kdl = to_zellij_kdl(&DEFAULT_DARK);
+        assert!(kdl.starts_with("themes {\n    default-dark {\n        fg \"#d8d8d8\"\n"));
+        assert!(kdl.ends_with("        orange \"#dc9656\"\n    }\n}\n"));
+
+        let mut palette = DEFAULT_DARK;
+        palette.name = "3024 Night".into();
+        let kdl = to_zellij_kdl(&palette);
+        assert!(kdl.starts_with("themes {\n    \"3024-night\" {\n"));
+    }
+}
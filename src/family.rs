@@ -0,0 +1,170 @@
+//! Scheme families pairing the dark and light members of related schemes.
+
+use crate::{
+    Base16Palette, DEFAULT_DARK, DEFAULT_LIGHT, ROSE_PINE, ROSE_PINE_DAWN, ROSE_PINE_MOON,
+};
+#[cfg(feature = "schemes-ayu")]
+use crate::{AYU_DARK, AYU_LIGHT, AYU_MIRAGE};
+#[cfg(feature = "schemes-catppuccin")]
+use crate::{CATPPUCCIN_FRAPPE, CATPPUCCIN_LATTE, CATPPUCCIN_MACCHIATO, CATPPUCCIN_MOCHA};
+#[cfg(feature = "schemes-everforest")]
+use crate::{EVERFOREST_DARK, EVERFOREST_LIGHT};
+#[cfg(feature = "schemes-gruvbox")]
+use crate::{
+    GRUVBOX_DARK, GRUVBOX_DARK_HARD, GRUVBOX_DARK_SOFT, GRUVBOX_LIGHT, GRUVBOX_LIGHT_HARD,
+    GRUVBOX_LIGHT_SOFT,
+};
+#[cfg(feature = "schemes-one")]
+use crate::{ONE_DARK, ONE_LIGHT};
+#[cfg(feature = "schemes-solarized")]
+use crate::{SOLARIZED_DARK, SOLARIZED_LIGHT};
+#[cfg(feature = "schemes-tokyo-night")]
+use crate::{TOKYO_NIGHT, TOKYO_NIGHT_LIGHT, TOKYO_NIGHT_STORM};
+
+/// A group of builtin schemes designed together, such as Rosé Pine with its
+/// Moon and Dawn variants.
+///
+/// Members are listed by variant, with the canonical member of each
+/// variant first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemeFamily {
+    /// Name of the family, e.g. `"Rosé Pine"`
+    pub name: &'static str,
+    /// The dark members of the family
+    pub dark: &'static [Base16Palette],
+    /// The light members of the family
+    pub light: &'static [Base16Palette],
+}
+
+impl SchemeFamily {
+    /// Returns whether `palette` is one of the members of the family, going
+    /// by its scheme name.
+    pub fn contains(&self, palette: &Base16Palette) -> bool {
+        self.members().any(|member| member.name == palette.name)
+    }
+
+    /// Returns all members of the family, dark ones first.
+    pub fn members(&self) -> impl Iterator<Item = &'static Base16Palette> {
+        self.dark.iter().chain(self.light)
+    }
+}
+
+/// The families of the builtin palettes that have both dark and light
+/// members.
+pub static SCHEME_FAMILIES: &[SchemeFamily] = &[
+    SchemeFamily {
+        name: "Default",
+        dark: &[DEFAULT_DARK],
+        light: &[DEFAULT_LIGHT],
+    },
+    SchemeFamily {
+        name: "Rosé Pine",
+        dark: &[ROSE_PINE, ROSE_PINE_MOON],
+        light: &[ROSE_PINE_DAWN],
+    },
+    #[cfg(feature = "schemes-gruvbox")]
+    SchemeFamily {
+        name: "Gruvbox",
+        dark: &[GRUVBOX_DARK, GRUVBOX_DARK_HARD, GRUVBOX_DARK_SOFT],
+        light: &[GRUVBOX_LIGHT, GRUVBOX_LIGHT_HARD, GRUVBOX_LIGHT_SOFT],
+    },
+    #[cfg(feature = "schemes-solarized")]
+    SchemeFamily {
+        name: "Solarized",
+        dark: &[SOLARIZED_DARK],
+        light: &[SOLARIZED_LIGHT],
+    },
+    #[cfg(feature = "schemes-catppuccin")]
+    SchemeFamily {
+        name: "Catppuccin",
+        dark: &[CATPPUCCIN_MOCHA, CATPPUCCIN_MACCHIATO, CATPPUCCIN_FRAPPE],
+        light: &[CATPPUCCIN_LATTE],
+    },
+    #[cfg(feature = "schemes-tokyo-night")]
+    SchemeFamily {
+        name: "Tokyo Night",
+        dark: &[TOKYO_NIGHT, TOKYO_NIGHT_STORM],
+        light: &[TOKYO_NIGHT_LIGHT],
+    },
+    #[cfg(feature = "schemes-everforest")]
+    SchemeFamily {
+        name: "Everforest",
+        dark: &[EVERFOREST_DARK],
+        light: &[EVERFOREST_LIGHT],
+    },
+    #[cfg(feature = "schemes-one")]
+    SchemeFamily {
+        name: "One",
+        dark: &[ONE_DARK],
+        light: &[ONE_LIGHT],
+    },
+    #[cfg(feature = "schemes-ayu")]
+    SchemeFamily {
+        name: "Ayu",
+        dark: &[AYU_DARK, AYU_MIRAGE],
+        light: &[AYU_LIGHT],
+    },
+];
+
+impl Base16Palette {
+    /// Returns the family of builtin schemes the palette belongs to, going
+    /// by its scheme name.
+    pub fn family(&self) -> Option<&'static SchemeFamily> {
+        SCHEME_FAMILIES.iter().find(|family| family.contains(self))
+    }
+
+    /// Returns the light member of the palette's family, or the builtin
+    /// palette itself if it's the light member, for implementing a light and
+    /// dark toggle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{DEFAULT_DARK, DEFAULT_LIGHT, DRACULA, ROSE_PINE, ROSE_PINE_DAWN};
+    /// assert_eq!(DEFAULT_DARK.light_variant(), Some(&DEFAULT_LIGHT));
+    /// assert_eq!(ROSE_PINE.light_variant(), Some(&ROSE_PINE_DAWN));
+    /// assert_eq!(ROSE_PINE_DAWN.dark_variant(), Some(&ROSE_PINE));
+    /// assert_eq!(DRACULA.light_variant(), None);
+    /// ```
+    pub fn light_variant(&self) -> Option<&'static Base16Palette> {
+        let family = self.family()?;
+        family
+            .light
+            .iter()
+            .find(|member| member.name == self.name)
+            .or_else(|| family.light.first())
+    }
+
+    /// Returns the dark member of the palette's family, or the builtin
+    /// palette itself if it's a dark member.
+    ///
+    /// See [`Base16Palette::light_variant`].
+    pub fn dark_variant(&self) -> Option<&'static Base16Palette> {
+        let family = self.family()?;
+        family
+            .dark
+            .iter()
+            .find(|member| member.name == self.name)
+            .or_else(|| family.dark.first())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::Variant;
+
+    #[test]
+    fn members_are_listed_under_their_variant() {
+        for family in SCHEME_FAMILIES {
+            for member in family.dark {
+                assert_eq!(member.variant(), Variant::Dark, "{}", member.name);
+                assert_eq!(member.dark_variant(), Some(member));
+            }
+            for member in family.light {
+                assert_eq!(member.variant(), Variant::Light, "{}", member.name);
+                assert_eq!(member.light_variant(), Some(member));
+            }
+        }
+    }
+}
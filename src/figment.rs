@@ -0,0 +1,134 @@
+//! Loading palettes through [`figment`], for applications that layer scheme
+//! files with environment variables or other configuration providers.
+
+use ::figment::{
+    error::Kind,
+    providers::Serialized,
+    value::{Dict, Value},
+    Figment,
+};
+
+use crate::{Base16Color, Base16Palette, Base16PaletteError};
+
+impl Base16Palette {
+    /// Extracts a `Base16Palette` from a [`Figment`].
+    ///
+    /// Slots may be spelled `base0a` or `base0A` and hold any of the color
+    /// formats understood by the file loaders. When a slot is set under both
+    /// spellings, as when an environment variable such as
+    /// `MY_APP_THEME_BASE0D` overrides the `base0D` of a scheme file, the
+    /// value of the provider added last wins, as with [`Figment::merge`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::InvalidColor`] or
+    /// [`Base16PaletteError::MissingField`] for bad or missing slots and
+    /// [`Base16PaletteError::ExtractionFailed`] for any other figment error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use figment::{providers::{Env, Format, Yaml}, Figment};
+    /// # use ratatui_base16::Base16Palette;
+    /// let figment = Figment::new()
+    ///     .merge(Yaml::file(".config/dracula.yaml"))
+    ///     .merge(Env::prefixed("MY_APP_THEME_"));
+    /// let palette = Base16Palette::from_figment(&figment).unwrap();
+    /// assert_eq!(palette.name, "Dracula");
+    /// ```
+    pub fn from_figment(figment: &Figment) -> Result<Self, Base16PaletteError> {
+        one_spelling(figment)?
+            .extract::<Self>()
+            .map_err(|error| classify(figment, error))
+    }
+}
+
+/// Returns `figment` with every slot under a single spelling, keeping the
+/// value of whichever spelling was provided last.
+fn one_spelling(figment: &Figment) -> Result<Figment, Base16PaletteError> {
+    let mut dict = figment
+        .extract::<Dict>()
+        .map_err(|error| classify(figment, error))?;
+    let mut doubled = false;
+    for slot in Base16Color::ALL {
+        let (lower, upper) = (slot.field_name(), slot.name());
+        if lower == upper || !dict.contains_key(lower) || !dict.contains_key(upper) {
+            continue;
+        }
+        let tag = |key| figment.find_value(key).map(|value| value.tag()).ok();
+        dict.remove(if tag(lower) < tag(upper) {
+            lower
+        } else {
+            upper
+        });
+        doubled = true;
+    }
+    Ok(if doubled {
+        Figment::from(Serialized::defaults(dict))
+    } else {
+        figment.clone()
+    })
+}
+
+/// Maps a figment error onto the most specific [`Base16PaletteError`].
+fn classify(figment: &Figment, error: ::figment::Error) -> Base16PaletteError {
+    if let Kind::MissingField(field) = &error.kind {
+        if let Ok(slot) = field.parse::<Base16Color>() {
+            return Base16PaletteError::MissingField(slot.field_name());
+        }
+    }
+    let slot = error
+        .path
+        .last()
+        .and_then(|key| key.parse::<Base16Color>().ok());
+    match (&error.kind, slot) {
+        (Kind::Message(_) | Kind::InvalidType(..) | Kind::InvalidValue(..), Some(slot)) => {
+            let value = figment
+                .find_value(&error.path.join("."))
+                .map(|value| describe(&value))
+                .unwrap_or_default();
+            Base16PaletteError::InvalidColor {
+                field: slot.field_name(),
+                value,
+                source: Box::new(error),
+            }
+        }
+        _ => error.into(),
+    }
+}
+
+fn describe(value: &Value) -> String {
+    match value.as_str() {
+        Some(value) => value.to_string(),
+        None => value.to_actual().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::figment::providers::{Format, Toml};
+
+    use super::*;
+
+    #[test]
+    fn providers_are_layered_and_classified() {
+        let toml = crate::DRACULA.to_toml().unwrap();
+        let figment =
+            Figment::from(Toml::string(&toml)).merge(Serialized::default("base08", "#ff0000"));
+        let palette = Base16Palette::from_figment(&figment).unwrap();
+        assert_eq!(palette.base08, ratatui::style::Color::Rgb(0xff, 0, 0));
+        assert_eq!(palette.base00, crate::DRACULA.base00);
+
+        let override_blue = figment
+            .clone()
+            .merge(Serialized::default("base0d", "#0000ff"));
+        let palette = Base16Palette::from_figment(&override_blue).unwrap();
+        assert_eq!(palette.base0d, ratatui::style::Color::Rgb(0, 0, 0xff));
+
+        let figment = figment.merge(Serialized::default("base0d", "nope"));
+        assert!(matches!(
+            Base16Palette::from_figment(&figment),
+            Err(Base16PaletteError::InvalidColor { field: "base0d", value, .. }) if value == "nope"
+        ));
+    }
+}
@@ -0,0 +1,487 @@
+//! Procedural generation of palettes.
+//!
+//! [`PaletteGenerator`] builds a palette from a few parameters: the shades
+//! form a lightness ramp in Oklch from the background towards the opposite
+//! extreme, and the accents are evenly spaced around the hue wheel from a
+//! starting hue, with the lightness needed to stand out from the
+//! background.
+//!
+//! ```rust
+//! use ratatui::style::Color;
+//! use ratatui_base16::{generate::PaletteGenerator, Variant};
+//!
+//! let palette = PaletteGenerator::new()
+//!     .background(Color::Rgb(0x1e, 0x1e, 0x2e))
+//!     .accent_hue(15.0)
+//!     .variant(Variant::Dark)
+//!     .name("Generated Night")
+//!     .build();
+//! assert_eq!(palette.base00, Color::Rgb(0x1e, 0x1e, 0x2e));
+//! assert_eq!(palette.variant(), Variant::Dark);
+//! ```
+
+use alloc::borrow::Cow;
+
+use ratatui::style::Color;
+
+use crate::{color, Base16Color, Base16Palette, Variant};
+
+/// Where base00 to base07 sit between the background and the opposite end
+/// of the lightness ramp, following the spacing of hand-made schemes: three
+/// close background shades, the comments, and the foregrounds.
+const SHADE_STOPS: [f64; 8] = [0.0, 0.06, 0.13, 0.33, 0.55, 0.78, 0.89, 1.0];
+
+/// The WCAG contrast ratio accents are given against the background, the
+/// minimum for graphical objects.
+const ACCENT_CONTRAST: f64 = 3.0;
+
+/// A generator of spec-compliant palettes from a background color and an
+/// accent hue.
+///
+/// Unset parameters fall back to a slightly blue background and a red
+/// accent hue. The variant defaults to that of the background, dark if
+/// there is none.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteGenerator {
+    name: Cow<'static, str>,
+    author: Cow<'static, str>,
+    background: Option<Color>,
+    variant: Option<Variant>,
+    accent_hue: f64,
+    pub(crate) hue_step: f64,
+    pub(crate) accent_lightness: Option<f64>,
+    pub(crate) accent_chroma: f64,
+}
+
+impl Default for PaletteGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaletteGenerator {
+    /// Creates a generator of a dark palette with the default parameters.
+    pub const fn new() -> Self {
+        Self {
+            name: Cow::Borrowed("Generated"),
+            author: Cow::Borrowed(""),
+            background: None,
+            variant: None,
+            accent_hue: 25.0,
+            hue_step: 60.0,
+            accent_lightness: None,
+            accent_chroma: 0.13,
+        }
+    }
+
+    /// Sets the scheme name, `"Generated"` by default.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the scheme author.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn author(mut self, author: impl Into<Cow<'static, str>>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    /// Sets base00. Its hue and chroma tint the other shades, so a
+    /// slightly colored background gives slightly colored grays.
+    ///
+    /// The background should suit the variant, dark for [`Variant::Dark`],
+    /// since the shades ramp from it towards white or black.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Sets the Oklch hue of base08 in degrees, from which the other
+    /// accents are spaced 60° apart in the order of the spec: base0A,
+    /// base0B, base0C, base0D and base0E. base09 and base0F sit halfway to
+    /// base0A, with base0F darker and duller.
+    ///
+    /// The default of 25° gives the usual red, yellow, green, cyan, blue and
+    /// magenta accents.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn accent_hue(mut self, hue: f64) -> Self {
+        self.accent_hue = hue;
+        self
+    }
+
+    /// Sets whether the palette is dark or light, which decides the
+    /// direction of the shade ramp.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Generates the palette.
+    pub fn build(self) -> Base16Palette {
+        let background = self.background.and_then(color::to_oklch);
+        let variant = self.variant.unwrap_or(match background {
+            Some((lightness, _, _)) if lightness > 0.6 => Variant::Light,
+            _ => Variant::Dark,
+        });
+        let (end, default_background, accent_lightness) = match variant {
+            Variant::Dark => (0.96, (0.22, 0.02, 265.0), 0.74),
+            Variant::Light => (0.22, (0.97, 0.01, 265.0), 0.55),
+        };
+        let (lightness, chroma, hue) = background.unwrap_or(default_background);
+
+        let mut colors = [Color::Reset; 16];
+        for (slot, t) in SHADE_STOPS.into_iter().enumerate() {
+            colors[slot] = color::from_oklch(
+                lightness + (end - lightness) * t,
+                chroma * (1.0 - 0.6 * t),
+                hue,
+            );
+        }
+        if let (Some(color), Some(_)) = (self.background, background) {
+            colors[0] = color;
+        }
+
+        let accent_lightness = self.accent_lightness.unwrap_or(accent_lightness);
+        let step = self.hue_step;
+        let accents = [
+            (0x08, 0.0, 0.0, 1.0),
+            (0x09, 0.5, 0.0, 1.0),
+            (0x0a, 1.0, 0.0, 1.0),
+            (0x0b, 2.0, 0.0, 1.0),
+            (0x0c, 3.0, 0.0, 1.0),
+            (0x0d, 4.0, 0.0, 1.0),
+            (0x0e, 5.0, 0.0, 1.0),
+            (0x0f, 0.5, -0.18, 0.6),
+        ];
+        for (slot, steps, darken, dull) in accents {
+            let accent = Accent {
+                lightness: accent_lightness + darken,
+                chroma: self.accent_chroma * dull,
+                hue: (self.accent_hue + steps * step).rem_euclid(360.0),
+            };
+            colors[slot] = accent.readable_on(colors[0], variant);
+        }
+        Base16Palette::from_array(colors, self.name, self.author)
+    }
+}
+
+impl Base16Palette {
+    /// Generates a random palette of the given variant, always the same
+    /// for the same `seed`.
+    ///
+    /// The parameters of [`PaletteGenerator`] are drawn from ranges that
+    /// keep the result usable: grays tinted by at most a little chroma, a
+    /// background close to black or white, and accents near their usual
+    /// hues with moderate chroma. The foreground and every accent stay
+    /// readable on the background. The palette is named after the seed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Palette, Variant};
+    /// let palette = Base16Palette::random(42, Variant::Light);
+    /// assert_eq!(palette, Base16Palette::random(42, Variant::Light));
+    /// assert_eq!(palette.variant(), Variant::Light);
+    /// ```
+    pub fn random(seed: u64, variant: Variant) -> Self {
+        let mut random = SplitMix64(seed);
+        let lightness = match variant {
+            Variant::Dark => random.between(0.16, 0.27),
+            Variant::Light => random.between(0.93, 0.99),
+        };
+        let background = color::from_oklch(
+            lightness,
+            random.between(0.0, 0.04),
+            random.between(0.0, 360.0),
+        );
+        let mut generator = PaletteGenerator::new()
+            .name(alloc::format!("Random {seed:016x}"))
+            .background(background)
+            .accent_hue(random.between(0.0, 50.0))
+            .variant(variant);
+        generator.hue_step = random.between(52.0, 64.0);
+        generator.accent_chroma = random.between(0.08, 0.17);
+        generator.accent_lightness = Some(match variant {
+            Variant::Dark => random.between(0.68, 0.8),
+            Variant::Light => random.between(0.48, 0.6),
+        });
+        generator.build()
+    }
+
+    /// Generates a palette whose shades and accents are all tints and tones
+    /// of one Oklch `hue`, in degrees.
+    ///
+    /// The shades are slightly tinted grays. The accents tell slots apart
+    /// by lightness and chroma alone, and each stays readable on the
+    /// background.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Palette, Variant};
+    /// let palette = Base16Palette::monochrome(150.0, Variant::Dark);
+    /// assert_eq!(palette.variant(), Variant::Dark);
+    /// assert_ne!(palette.base08, palette.base0d);
+    /// ```
+    pub fn monochrome(hue: f64, variant: Variant) -> Self {
+        let lightness = match variant {
+            Variant::Dark => 0.21,
+            Variant::Light => 0.97,
+        };
+        let mut palette = PaletteGenerator::new()
+            .name("Monochrome")
+            .background(color::from_oklch(lightness, 0.025, hue))
+            .variant(variant)
+            .build();
+        // Lightness offsets from the middle accent lightness and chroma,
+        // spread so that neighbouring slots differ.
+        let accents = [
+            (Base16Color::Base08, -0.08, 0.16),
+            (Base16Color::Base09, -0.02, 0.12),
+            (Base16Color::Base0A, 0.14, 0.1),
+            (Base16Color::Base0B, 0.04, 0.14),
+            (Base16Color::Base0C, 0.1, 0.06),
+            (Base16Color::Base0D, 0.0, 0.09),
+            (Base16Color::Base0E, -0.05, 0.05),
+            (Base16Color::Base0F, -0.14, 0.07),
+        ];
+        let (middle, direction) = match variant {
+            Variant::Dark => (0.72, 1.0),
+            Variant::Light => (0.52, -1.0),
+        };
+        for (slot, offset, chroma) in accents {
+            let accent = Accent {
+                lightness: middle + direction * offset,
+                chroma,
+                hue,
+            };
+            *palette.color_mut(slot) = accent.readable_on(palette.base00, variant);
+        }
+        palette
+    }
+}
+
+/// A rule for picking the hues of the accents from an anchor hue, by
+/// their positions on the color wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Harmony {
+    /// The anchor and its opposite.
+    Complementary,
+    /// The anchor and its two opposite neighbours, 150° and 210° away.
+    SplitComplementary,
+    /// The anchor and its neighbours 30° to either side.
+    Analogous,
+    /// Three hues 120° apart.
+    Triadic,
+    /// Four hues 90° apart.
+    Tetradic,
+}
+
+impl Harmony {
+    /// Returns the offsets of the hues from the anchor, in degrees.
+    const fn offsets(self) -> &'static [f64] {
+        match self {
+            Self::Complementary => &[0.0, 180.0],
+            Self::SplitComplementary => &[0.0, 150.0, 210.0],
+            Self::Analogous => &[0.0, -30.0, 30.0],
+            Self::Triadic => &[0.0, 120.0, 240.0],
+            Self::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+        }
+    }
+}
+
+impl Base16Palette {
+    /// Returns the palette with the hues of base08 to base0F replaced by
+    /// hues of `strategy` around `anchor_hue`, an Oklch hue in degrees.
+    ///
+    /// base08 takes the anchor hue and the other accents cycle through the
+    /// hues of the harmony, turning a further 12° on each pass so that no
+    /// two accents end up the same. Each accent keeps its lightness and
+    /// chroma, so the accents stay as readable and as vivid as in the
+    /// original scheme and the shades are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{generate::Harmony, DRACULA};
+    /// let palette = DRACULA.harmonize_accents(250.0, Harmony::Complementary);
+    /// assert_eq!(palette.base00, DRACULA.base00);
+    /// assert_ne!(palette.base08, DRACULA.base08);
+    /// ```
+    #[must_use = "method returns a new palette and does not modify the original"]
+    pub fn harmonize_accents(mut self, anchor_hue: f64, strategy: Harmony) -> Self {
+        let offsets = strategy.offsets();
+        for (index, (slot, accent)) in self.accents().enumerate() {
+            let Some((lightness, chroma, _)) = color::to_oklch(accent) else {
+                continue;
+            };
+            let turn = (index / offsets.len()) as f64 * 12.0;
+            let hue = (anchor_hue + offsets[index % offsets.len()] + turn).rem_euclid(360.0);
+            *self.color_mut(slot) = color::from_oklch(lightness, chroma, hue);
+        }
+        self
+    }
+}
+
+/// Returns `color`, made lighter on dark backgrounds or darker on light
+/// ones until its contrast ratio against `background` reaches `floor`.
+#[cfg(feature = "test-util")]
+pub(crate) fn with_contrast(
+    color: Color,
+    background: Color,
+    variant: Variant,
+    floor: f64,
+) -> Color {
+    match color::to_oklch(color) {
+        Some((lightness, chroma, hue)) => Accent {
+            lightness,
+            chroma,
+            hue,
+        }
+        .contrasting(background, variant, floor),
+        None => color,
+    }
+}
+
+/// The SplitMix64 generator, which is enough for picking colors and keeps
+/// the output stable across releases, unlike the generators of `std`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number uniformly drawn from `low..high`.
+    fn between(&mut self, low: f64, high: f64) -> f64 {
+        let unit = (self.next() >> 11) as f64 / (1u64 << 53) as f64;
+        low + (high - low) * unit
+    }
+}
+
+/// An accent color in Oklch.
+struct Accent {
+    lightness: f64,
+    chroma: f64,
+    hue: f64,
+}
+
+impl Accent {
+    /// Returns the accent, moved away from the lightness of `background`
+    /// until it reaches [`ACCENT_CONTRAST`] or the end of the range.
+    fn readable_on(&self, background: Color, variant: Variant) -> Color {
+        self.contrasting(background, variant, ACCENT_CONTRAST)
+    }
+
+    /// Returns the accent, moved away from the lightness of `background`
+    /// until it reaches a contrast ratio of `floor` or the end of the range.
+    fn contrasting(&self, background: Color, variant: Variant, floor: f64) -> Color {
+        let step = match variant {
+            Variant::Dark => 0.02,
+            Variant::Light => -0.02,
+        };
+        let mut lightness = self.lightness;
+        loop {
+            let accent = color::from_oklch(lightness, self.chroma, self.hue);
+            let readable =
+                color::contrast_ratio(accent, background).is_none_or(|ratio| ratio >= floor);
+            if readable || !(0.0..=1.0).contains(&(lightness + step)) {
+                return accent;
+            }
+            lightness += step;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shades_ramp_and_accents_stand_out() {
+        for variant in [Variant::Dark, Variant::Light] {
+            let palette = PaletteGenerator::new().variant(variant).build();
+            assert_eq!(palette.variant(), variant);
+            let luminance: Vec<_> = palette
+                .shades()
+                .map(|(_, shade)| color::relative_luminance(shade).unwrap())
+                .collect();
+            assert!(luminance.windows(2).all(|pair| match variant {
+                Variant::Dark => pair[0] < pair[1],
+                Variant::Light => pair[0] > pair[1],
+            }));
+            for (slot, accent) in palette.accents() {
+                let ratio = color::contrast_ratio(accent, palette.base00).unwrap();
+                assert!(ratio >= ACCENT_CONTRAST, "{slot} of {variant} is {ratio}");
+            }
+        }
+
+        let light = PaletteGenerator::new()
+            .background(Color::Rgb(0xfd, 0xf6, 0xe3))
+            .build();
+        assert_eq!(light.variant(), Variant::Light);
+        assert_eq!(light.base00, Color::Rgb(0xfd, 0xf6, 0xe3));
+        let (_, _, hue) = color::to_oklch(light.color(Base16Color::Base0D)).unwrap();
+        assert!((hue - 265.0).abs() < 3.0);
+    }
+
+    #[test]
+    fn random_palettes_are_deterministic_and_readable() {
+        assert_ne!(
+            Base16Palette::random(1, Variant::Dark),
+            Base16Palette::random(2, Variant::Dark)
+        );
+        for seed in 0..64 {
+            for variant in [Variant::Dark, Variant::Light] {
+                let palette = Base16Palette::random(seed, variant);
+                assert_eq!(palette, Base16Palette::random(seed, variant));
+                assert_eq!(palette.variant(), variant);
+                let foreground = color::contrast_ratio(palette.base05, palette.base00).unwrap();
+                assert!(foreground >= 4.5, "{seed} {variant}: {foreground}");
+                assert!(palette.accents().all(|(_, accent)| {
+                    color::contrast_ratio(accent, palette.base00).unwrap() >= ACCENT_CONTRAST
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn monochrome_accents_share_the_hue() {
+        let palette = Base16Palette::monochrome(300.0, Variant::Light);
+        let mut accents: Vec<_> = palette.accents().map(|(_, accent)| accent).collect();
+        for accent in &accents {
+            let (_, _, hue) = color::to_oklch(*accent).unwrap();
+            assert!((hue - 300.0).abs() < 4.0, "{accent} has hue {hue}");
+            assert!(color::contrast_ratio(*accent, palette.base00).unwrap() >= ACCENT_CONTRAST);
+        }
+        accents.sort_by_key(|accent| color::to_hex(*accent));
+        accents.dedup();
+        assert_eq!(accents.len(), 8);
+    }
+
+    #[test]
+    fn harmonized_accents_keep_their_lightness() {
+        let original = crate::DEFAULT_DARK;
+        let palette = original.clone().harmonize_accents(200.0, Harmony::Triadic);
+        assert!(palette.shades().eq(original.shades()));
+        let hues: Vec<_> = palette
+            .accents()
+            .map(|(_, accent)| color::to_oklch(accent).unwrap().2.round())
+            .collect();
+        assert!((hues[0] - 200.0).abs() <= 2.0 && (hues[1] - 320.0).abs() <= 2.0);
+        assert!((hues[3] - 212.0).abs() <= 2.0);
+        let (before, after) = (
+            color::to_oklch(original.base0b).unwrap().0,
+            color::to_oklch(palette.base0b).unwrap().0,
+        );
+        assert!((before - after).abs() < 0.02);
+    }
+}
@@ -0,0 +1,131 @@
+//! Color ramps and sequences for gauges, sparklines and charts.
+
+use ratatui::style::Color;
+
+use crate::{color, Base16Color, Base16Palette};
+
+impl Base16Palette {
+    /// Returns `steps` colors smoothly interpolated from one slot to another,
+    /// blended in linear light like [`blend`](crate::blend).
+    ///
+    /// Both endpoints are included, so `steps == 2` returns just the two slot
+    /// colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Color, DRACULA};
+    /// let ramp = DRACULA.gradient(Base16Color::Base0D, Base16Color::Base0E, 8);
+    /// assert_eq!(ramp.len(), 8);
+    /// assert_eq!(ramp[0], DRACULA.base0d);
+    /// ```
+    pub fn gradient(&self, from: Base16Color, to: Base16Color, steps: usize) -> Vec<Color> {
+        color::gradient(&[self.color(from), self.color(to)], steps)
+    }
+
+    /// Returns `steps` colors ramping from green (base0B) through yellow
+    /// (base0A) to red (base08), the common scale for utilization gauges.
+    pub fn utilization_gradient(&self, steps: usize) -> Vec<Color> {
+        color::gradient(&self.utilization_stops(), steps)
+    }
+
+    /// Returns the color for a utilization `ratio` between `0.0` and `1.0` on
+    /// the scale used by [`utilization_gradient`](Self::utilization_gradient).
+    ///
+    /// This is useful for coloring a `Gauge` or `LineGauge` by its current
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// assert_eq!(DRACULA.utilization_color(0.0), DRACULA.base0b);
+    /// assert_eq!(DRACULA.utilization_color(1.0), DRACULA.base08);
+    /// ```
+    pub fn utilization_color(&self, ratio: f64) -> Color {
+        let ratio = ratio.clamp(0.0, 1.0);
+        if ratio <= 0.5 {
+            color::blend(self.base0b, self.base0a, ratio * 2.0)
+        } else {
+            color::blend(self.base0a, self.base08, (ratio - 0.5) * 2.0)
+        }
+    }
+
+    /// Returns an infinite iterator cycling through the accent colors base08
+    /// to base0F, for assigning colors to the datasets of a `Chart` or
+    /// `BarChart`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let colors: Vec<_> = DRACULA.accent_cycle().take(9).collect();
+    /// assert_eq!(colors[0], DRACULA.base08);
+    /// assert_eq!(colors[8], DRACULA.base08);
+    /// ```
+    pub fn accent_cycle(&self) -> impl Iterator<Item = Color> + Clone {
+        self.accents().map(|(_, color)| color).cycle()
+    }
+
+    /// Like [`accent_cycle`](Self::accent_cycle), but skips accents whose
+    /// contrast ratio against the background (base00) is below `min_contrast`.
+    ///
+    /// A ratio of `3.0` is the WCAG minimum for graphical objects. If no
+    /// accent meets the threshold, all accents are cycled through so the
+    /// iterator never ends early.
+    pub fn readable_accent_cycle(&self, min_contrast: f64) -> impl Iterator<Item = Color> + Clone {
+        let accents: Vec<Color> = self.accents().map(|(_, color)| color).collect();
+        let readable: Vec<Color> = accents
+            .iter()
+            .copied()
+            .filter(|accent| {
+                color::contrast_ratio(*accent, self.base00)
+                    .is_some_and(|ratio| ratio >= min_contrast)
+            })
+            .collect();
+        let colors = if readable.is_empty() {
+            accents
+        } else {
+            readable
+        };
+        colors.into_iter().cycle()
+    }
+
+    fn utilization_stops(&self) -> [Color; 3] {
+        [self.base0b, self.base0a, self.base08]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DEFAULT_DARK, GITHUB_LIGHT};
+
+    #[test]
+    fn utilization_gradient_passes_through_yellow() {
+        let ramp = DEFAULT_DARK.utilization_gradient(5);
+        assert_eq!(
+            [ramp[0], ramp[2], ramp[4]],
+            [
+                DEFAULT_DARK.base0b,
+                DEFAULT_DARK.base0a,
+                DEFAULT_DARK.base08
+            ]
+        );
+        assert_eq!(DEFAULT_DARK.utilization_color(0.5), DEFAULT_DARK.base0a);
+    }
+
+    #[test]
+    fn readable_accent_cycle_skips_low_contrast() {
+        // The orange and cyan accents are too light for the white background.
+        let colors: Vec<_> = GITHUB_LIGHT.readable_accent_cycle(4.5).take(12).collect();
+        assert!(!colors.contains(&GITHUB_LIGHT.base08));
+        assert!(!colors.contains(&GITHUB_LIGHT.base09));
+        assert_eq!(colors[0], GITHUB_LIGHT.base0a);
+
+        let fallback: Vec<_> = DEFAULT_DARK.readable_accent_cycle(100.0).take(8).collect();
+        assert_eq!(
+            fallback,
+            DEFAULT_DARK.accent_cycle().take(8).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Alacritty color configurations.
+
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::{file_name, invalid_color, missing, parse, parse_color, Terminal, ANSI_SLOTS};
+use crate::{
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+/// The names of the eight ANSI colors in the `normal` and `bright` tables.
+const NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// Reads the `[colors]` tables of an Alacritty configuration into a
+/// palette, with the mapping of [`from_ansi`].
+///
+/// Both `alacritty.toml` and the legacy `alacritty.yml` are read, picking
+/// the format from the file extension, and colors may be written as
+/// `#rrggbb` or `0xrrggbb`. The `primary` colors give the background and
+/// foreground and the `selection` background gives base02. The palette is
+/// named after the file name without extension.
+///
+/// [`from_ansi`]: super::from_ansi
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it can't be parsed,
+/// [`Base16PaletteError::InvalidColor`] for unreadable colors and
+/// [`Base16PaletteError::MissingField`] if it has neither a primary
+/// background nor a normal black, or neither a foreground nor a white, all
+/// wrapped in [`Base16PaletteError::InFile`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, import, DRACULA};
+/// # let dir = std::env::temp_dir().join(format!("ratatui-base16-doc-{}", std::process::id()));
+/// # std::fs::create_dir_all(&dir)?;
+/// let file = dir.join("dracula-alacritty.toml");
+/// std::fs::write(&file, export::to_alacritty_toml(&DRACULA))?;
+/// let palette = import::from_alacritty(&file)?;
+/// assert_eq!(palette.base08, DRACULA.base08);
+/// # std::fs::remove_dir_all(&dir)?;
+/// # Ok::<(), ratatui_base16::Base16PaletteError>(())
+/// ```
+pub fn from_alacritty(file: impl AsRef<Path>) -> Result<Base16Palette, Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of an Alacritty configuration; `file` names it in errors.
+fn from_text(file: &Path, text: &str) -> Result<Base16Palette, Base16PaletteError> {
+    let yaml = file
+        .extension()
+        .is_some_and(|extension| extension == "yml" || extension == "yaml");
+    let format = if yaml { Format::Yaml } else { Format::Toml };
+    let config = parse(file, text, format)?;
+    let empty = Map::new();
+    let colors = config
+        .get("colors")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    let color = |table: &str, key: &str, slot: Base16Color| {
+        let Some(value) = colors
+            .get(table)
+            .and_then(|table| table.get(key))
+            .and_then(Value::as_str)
+        else {
+            return Ok(None);
+        };
+        parse_color(value, None)
+            .map(Some)
+            .ok_or_else(|| invalid_color(file, text, key, slot, value))
+    };
+    let mut terminal = Terminal {
+        background: color("primary", "background", Base16Color::Base00)?,
+        foreground: color("primary", "foreground", Base16Color::Base05)?,
+        selection: color("selection", "background", Base16Color::Base02)?,
+        ..Terminal::default()
+    };
+    for (index, name) in NAMES.into_iter().enumerate() {
+        terminal.ansi[index] = color("normal", name, ANSI_SLOTS[index])?;
+        terminal.ansi[index + 8] = color("bright", name, ANSI_SLOTS[index + 8])?;
+    }
+    let (palette, _) = terminal
+        .into_palette(file_name(file), String::new())
+        .map_err(|slot| match slot {
+            Base16Color::Base00 => missing(file, "colors.primary.background", "background"),
+            _ => missing(file, "colors.primary.foreground", "foreground"),
+        })?;
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn legacy_yaml_configs_are_read() {
+        let file = Path::new("gruvbox.yml");
+        let palette = from_text(
+            file,
+            "colors:\n  primary:\n    background: '0x282828'\n    foreground: '0xebdbb2'\n  normal:\n    red: '0xcc241d'\n  bright:\n    black: '0x928374'\n    blue: '0x83a598'\n",
+        )
+        .unwrap();
+        assert_eq!(palette.name, "gruvbox");
+        assert_eq!(palette.base00, Color::Rgb(0x28, 0x28, 0x28));
+        assert_eq!(palette.base03, Color::Rgb(0x92, 0x83, 0x74));
+        assert_eq!(palette.base08, Color::Rgb(0xcc, 0x24, 0x1d));
+        assert_eq!(palette.base0d, Color::Rgb(0x83, 0xa5, 0x98));
+
+        assert!(matches!(
+            from_text(file, "colors:\n  normal:\n    red: '0xcc241d'\n")
+                .unwrap_err()
+                .root(),
+            Base16PaletteError::MissingField("background")
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+//! Gogh terminal themes.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{file_name, invalid_color, missing, parse, parse_color, Terminal, ANSI_SLOTS};
+use crate::{
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+/// Reads a theme of the Gogh collection into a palette, with the mapping
+/// of [`from_ansi`].
+///
+/// `color_01` to `color_16` give the ANSI colors and `background` and
+/// `foreground` base00 and base05. The palette takes the `name` and
+/// `author` of the theme, falling back to the file name without extension.
+///
+/// [`from_ansi`]: super::from_ansi
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it isn't valid YAML,
+/// [`Base16PaletteError::InvalidColor`] for unreadable colors and
+/// [`Base16PaletteError::MissingField`] if it has neither a background nor
+/// a `color_01`, or neither a foreground nor a `color_08`, all wrapped in
+/// [`Base16PaletteError::InFile`].
+pub fn from_gogh_yaml(file: impl AsRef<Path>) -> Result<Base16Palette, Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of a Gogh theme; `file` names it in errors.
+fn from_text(file: &Path, text: &str) -> Result<Base16Palette, Base16PaletteError> {
+    let theme = parse(file, text, Format::Yaml)?;
+    let string = |key: &str| theme.get(key).and_then(Value::as_str);
+
+    let color = |key: &str, slot| {
+        let Some(value) = string(key) else {
+            return Ok(None);
+        };
+        parse_color(value, None)
+            .map(Some)
+            .ok_or_else(|| invalid_color(file, text, key, slot, value))
+    };
+    let mut terminal = Terminal {
+        background: color("background", Base16Color::Base00)?,
+        foreground: color("foreground", Base16Color::Base05)?,
+        ..Terminal::default()
+    };
+    for (index, slot) in ANSI_SLOTS.into_iter().enumerate() {
+        terminal.ansi[index] = color(&format!("color_{:02}", index + 1), slot)?;
+    }
+    let name = string("name").map_or_else(|| file_name(file), str::to_string);
+    let author = string("author").unwrap_or_default().to_string();
+    let (palette, _) = terminal
+        .into_palette(name, author)
+        .map_err(|slot| match slot {
+            Base16Color::Base00 => missing(file, "background", "background"),
+            _ => missing(file, "foreground", "foreground"),
+        })?;
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn numbered_colors_are_read() {
+        let file = Path::new("argonaut.yml");
+        let colors: String = (1..=16)
+            .map(|index| format!("color_{index:02}: '#{index:02x}{index:02x}{index:02x}'\n"))
+            .collect();
+        let palette = from_text(
+            file,
+            &format!(
+                "---\nname: 'Argonaut'\n{colors}background: '#0e1019'\nforeground: '#fffaf4'\ncursor: '#ff0018'\n"
+            ),
+        )
+        .unwrap();
+        assert_eq!(palette.name, "Argonaut");
+        assert_eq!(palette.author, "");
+        assert_eq!(palette.base00, Color::Rgb(0x0e, 0x10, 0x19));
+        assert_eq!(palette.base08, Color::Rgb(2, 2, 2));
+        assert_eq!(palette.base07, Color::Rgb(16, 16, 16));
+
+        assert!(matches!(
+            from_text(file, "color_02: 'red-ish'\n").unwrap_err().root(),
+            Base16PaletteError::InvalidColor {
+                field: "base08",
+                ..
+            }
+        ));
+    }
+}
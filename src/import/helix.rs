@@ -0,0 +1,208 @@
+//! Helix editor themes.
+
+use std::path::Path;
+
+use ratatui::style::Color;
+use serde_json::{Map, Value};
+
+use super::{complete, file_name, invalid_color, missing, parse, parse_color};
+use crate::{
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+/// The scopes and attributes holding each slot, most specific first,
+/// mirroring [`to_helix_toml`].
+///
+/// [`to_helix_toml`]: crate::export::to_helix_toml
+const SCOPES: [(Base16Color, &[(&str, &str)]); 15] = [
+    (Base16Color::Base00, &[("ui.background", "bg")]),
+    (
+        Base16Color::Base01,
+        &[
+            ("ui.statusline", "bg"),
+            ("ui.cursorline.primary", "bg"),
+            ("ui.popup", "bg"),
+            ("ui.window", "bg"),
+        ],
+    ),
+    (
+        Base16Color::Base02,
+        &[("ui.selection", "bg"), ("ui.selection.primary", "bg")],
+    ),
+    (
+        Base16Color::Base03,
+        &[("comment", "fg"), ("ui.linenr", "fg")],
+    ),
+    (
+        Base16Color::Base04,
+        &[("ui.statusline", "fg"), ("ui.linenr.selected", "fg")],
+    ),
+    (
+        Base16Color::Base05,
+        &[
+            ("ui.text", "fg"),
+            ("ui.background", "fg"),
+            ("ui.menu", "fg"),
+        ],
+    ),
+    (Base16Color::Base06, &[("ui.help", "fg")]),
+    (
+        Base16Color::Base08,
+        &[("variable", "fg"), ("diff.minus", "fg"), ("error", "fg")],
+    ),
+    (
+        Base16Color::Base09,
+        &[
+            ("constant.numeric", "fg"),
+            ("constant", "fg"),
+            ("warning", "fg"),
+        ],
+    ),
+    (Base16Color::Base0A, &[("type", "fg"), ("attribute", "fg")]),
+    (
+        Base16Color::Base0B,
+        &[("string", "fg"), ("diff.plus", "fg")],
+    ),
+    (
+        Base16Color::Base0C,
+        &[("constant.character.escape", "fg"), ("string.regexp", "fg")],
+    ),
+    (Base16Color::Base0D, &[("function", "fg"), ("info", "fg")]),
+    (
+        Base16Color::Base0E,
+        &[("keyword", "fg"), ("diff.delta", "fg")],
+    ),
+    (
+        Base16Color::Base0F,
+        &[("punctuation.special", "fg"), ("embedded", "fg")],
+    ),
+];
+
+/// Reads a Helix theme into a palette, returning the palette and the slots
+/// that the theme doesn't determine.
+///
+/// Each slot is read from the scopes [`to_helix_toml`] writes it to, e.g.
+/// base00 from the background of `ui.background`, base03 from `comment` and
+/// base0E from `keyword`, so exported themes are read back unchanged except
+/// for base07, which Helix themes don't use. Colors may be written inline
+/// or refer to the `[palette]` table. The remaining slots are blended from
+/// the others, e.g. base09 halfway between base08 and base0A, and listed in
+/// the report. Themes that only `inherits` another theme can't be resolved
+/// and miss most colors. The palette is named after the file name without
+/// extension.
+///
+/// [`to_helix_toml`]: crate::export::to_helix_toml
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it isn't valid TOML,
+/// [`Base16PaletteError::InvalidColor`] for unreadable colors and
+/// [`Base16PaletteError::MissingField`] if it sets neither the text nor the
+/// background color, all wrapped in [`Base16PaletteError::InFile`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, import, Base16Color, DRACULA};
+/// # let dir = std::env::temp_dir().join(format!("ratatui-base16-doc-{}", std::process::id()));
+/// # std::fs::create_dir_all(&dir)?;
+/// let file = dir.join("dracula.toml");
+/// std::fs::write(&file, export::to_helix_toml(&DRACULA))?;
+/// let (palette, defaulted) = import::from_helix_toml(&file)?;
+/// assert_eq!(palette.base0e, DRACULA.base0e);
+/// assert_eq!(defaulted, [Base16Color::Base07]);
+/// # std::fs::remove_dir_all(&dir)?;
+/// # Ok::<(), ratatui_base16::Base16PaletteError>(())
+/// ```
+pub fn from_helix_toml(
+    file: impl AsRef<Path>,
+) -> Result<(Base16Palette, Vec<Base16Color>), Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of a Helix theme; `file` names it in errors.
+fn from_text(
+    file: &Path,
+    text: &str,
+) -> Result<(Base16Palette, Vec<Base16Color>), Base16PaletteError> {
+    let theme = parse(file, text, Format::Toml)?;
+    let empty = Map::new();
+    let named = theme
+        .get("palette")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    let mut slots: [Option<Color>; 16] = [None; 16];
+    for (slot, scopes) in SCOPES {
+        let found = scopes
+            .iter()
+            .find_map(|(scope, attribute)| Some((*scope, attribute_of(&theme, scope, attribute)?)));
+        let Some((scope, value)) = found else {
+            continue;
+        };
+        let value = named.get(value).and_then(Value::as_str).unwrap_or(value);
+        let color = parse_color(value, None)
+            .ok_or_else(|| invalid_color(file, text, scope, slot, value))?;
+        slots[slot as usize] = Some(color);
+    }
+    complete(slots, file_name(file), String::new()).map_err(|slot| match slot {
+        Base16Color::Base00 => missing(file, "ui.background", "ui.background"),
+        _ => missing(file, "ui.text", "ui.text"),
+    })
+}
+
+/// Returns the `fg` or `bg` of `scope`, which is either a table or, for the
+/// foreground only, a plain color.
+///
+/// Scopes are looked up both as quoted keys like `"ui.background"` and as
+/// nested tables.
+fn attribute_of<'a>(
+    theme: &'a Map<String, Value>,
+    scope: &str,
+    attribute: &str,
+) -> Option<&'a str> {
+    let style = theme.get(scope).or_else(|| {
+        let mut parts = scope.split('.');
+        let first = theme.get(parts.next()?)?;
+        parts.try_fold(first, |value, part| value.get(part))
+    })?;
+    match style {
+        Value::String(color) if attribute == "fg" => Some(color),
+        Value::Object(style) => style.get(attribute)?.as_str(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_references_are_resolved() {
+        let (palette, defaulted) = from_text(
+            Path::new("mine.toml"),
+            r##"
+"ui.background" = { bg = "black" }
+"ui.text" = "white"
+"keyword" = { fg = "purple", modifiers = ["bold"] }
+ui.selection = { bg = "#303030" }
+
+[palette]
+black = "#101010"
+white = "#e0e0e0"
+purple = "#b48ead"
+"##,
+        )
+        .unwrap();
+        assert_eq!(palette.name, "mine");
+        assert_eq!(palette.base00, Color::Rgb(0x10, 0x10, 0x10));
+        assert_eq!(palette.base02, Color::Rgb(0x30, 0x30, 0x30));
+        assert_eq!(palette.base0e, Color::Rgb(0xb4, 0x8e, 0xad));
+        assert!(!defaulted.contains(&Base16Color::Base0E));
+        assert!(defaulted.contains(&Base16Color::Base0B));
+        assert_eq!(defaulted.len(), 12);
+    }
+}
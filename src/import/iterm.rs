@@ -0,0 +1,191 @@
+//! iTerm2 color presets.
+
+use std::path::Path;
+
+use ratatui::style::Color;
+
+use super::{file_name, invalid_color, missing, Terminal, ANSI_SLOTS};
+use crate::{load, Base16Color, Base16Palette, Base16PaletteError};
+
+/// Reads an iTerm2 color preset, the XML property list stored in
+/// `.itermcolors` files, into a palette with the mapping of [`from_ansi`].
+///
+/// `Ansi 0 Color` to `Ansi 15 Color` give the ANSI colors, the `Background
+/// Color` and `Foreground Color` base00 and base05 and the `Selection
+/// Color` base02. Components are read as sRGB whatever the `Color Space`
+/// of a color, which only shifts the colors of presets made in other color
+/// spaces slightly. The palette is named after the file name without
+/// extension.
+///
+/// [`from_ansi`]: super::from_ansi
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it isn't an XML property
+/// list, [`Base16PaletteError::InvalidColor`] for colors lacking a
+/// component and [`Base16PaletteError::MissingField`] if it has neither a
+/// background nor an `Ansi 0 Color`, or neither a foreground nor an `Ansi 7
+/// Color`, all wrapped in [`Base16PaletteError::InFile`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, import, DRACULA};
+/// # let dir = std::env::temp_dir().join(format!("ratatui-base16-doc-{}", std::process::id()));
+/// # std::fs::create_dir_all(&dir)?;
+/// let file = dir.join("Dracula.itermcolors");
+/// std::fs::write(&file, export::to_itermcolors(&DRACULA))?;
+/// let palette = import::from_itermcolors(&file)?;
+/// assert_eq!(palette.base0e, DRACULA.base0e);
+/// assert_eq!(palette.name, "Dracula");
+/// # std::fs::remove_dir_all(&dir)?;
+/// # Ok::<(), ratatui_base16::Base16PaletteError>(())
+/// ```
+pub fn from_itermcolors(file: impl AsRef<Path>) -> Result<Base16Palette, Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of an `.itermcolors` property list; `file` names it in errors.
+fn from_text(file: &Path, text: &str) -> Result<Base16Palette, Base16PaletteError> {
+    let Some(start) = text.find("<plist") else {
+        return Err(Base16PaletteError::InFile {
+            path: file.to_path_buf(),
+            key: None,
+            line: None,
+            column: None,
+            source: Box::new(Base16PaletteError::ExtractionFailed(
+                "not an XML property list".into(),
+            )),
+        });
+    };
+
+    let mut terminal = Terminal::default();
+    for (key, dict) in dictionaries(&text[start..]) {
+        let slot = match key {
+            "Background Color" => &mut terminal.background,
+            "Foreground Color" => &mut terminal.foreground,
+            "Selection Color" => &mut terminal.selection,
+            _ => match ansi_index(key).and_then(|index| terminal.ansi.get_mut(index)) {
+                Some(slot) => slot,
+                None => continue,
+            },
+        };
+        let color = components(dict).ok_or_else(|| {
+            let value = dict.split_whitespace().collect::<Vec<_>>().join(" ");
+            invalid_color(file, text, key, field_of(key), &value)
+        })?;
+        *slot = Some(color);
+    }
+    let (palette, _) = terminal
+        .into_palette(file_name(file), String::new())
+        .map_err(|slot| match slot {
+            Base16Color::Base00 => missing(file, "Background Color", "background"),
+            _ => missing(file, "Foreground Color", "foreground"),
+        })?;
+    Ok(palette)
+}
+
+/// Returns the keys of the top-level dictionary whose values are
+/// dictionaries, with the text inside those dictionaries.
+fn dictionaries(text: &str) -> Vec<(&str, &str)> {
+    let mut entries = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("<key>") {
+        rest = &rest[start + "<key>".len()..];
+        let Some(end) = rest.find("</key>") else {
+            break;
+        };
+        let key = rest[..end].trim();
+        rest = rest[end + "</key>".len()..].trim_start();
+        if let Some(inner) = rest.strip_prefix("<dict>") {
+            let Some(end) = inner.find("</dict>") else {
+                break;
+            };
+            entries.push((key, &inner[..end]));
+            rest = &inner[end + "</dict>".len()..];
+        }
+    }
+    entries
+}
+
+/// Reads the red, green and blue components of a color dictionary, which
+/// range from 0 to 1.
+fn components(dict: &str) -> Option<Color> {
+    let component = |name: &str| -> Option<u8> {
+        let key = format!("<key>{name} Component</key>");
+        let rest = dict[dict.find(&key)? + key.len()..].trim_start();
+        let rest = rest
+            .strip_prefix("<real>")
+            .or_else(|| rest.strip_prefix("<integer>"))?;
+        let value: f64 = rest[..rest.find('<')?].trim().parse().ok()?;
+        Some((value.clamp(0.0, 1.0) * 255.0).round() as u8)
+    };
+    Some(Color::Rgb(
+        component("Red")?,
+        component("Green")?,
+        component("Blue")?,
+    ))
+}
+
+/// Returns the slot a color of the preset is read into, for errors.
+fn field_of(key: &str) -> Base16Color {
+    match key {
+        "Background Color" => Base16Color::Base00,
+        "Foreground Color" => Base16Color::Base05,
+        "Selection Color" => Base16Color::Base02,
+        _ => ansi_index(key)
+            .and_then(|index| ANSI_SLOTS.get(index).copied())
+            .unwrap_or(Base16Color::Base05),
+    }
+}
+
+/// Returns the index of an `Ansi <index> Color` key.
+fn ansi_index(key: &str) -> Option<usize> {
+    key.strip_prefix("Ansi ")?
+        .strip_suffix(" Color")?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn component_fractions_are_read() {
+        let file = Path::new("Solarized Dark.itermcolors");
+        let palette = from_text(
+            file,
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<plist version=\"1.0\">\n<dict>\n",
+                "\t<key>Ansi 1 Color</key>\n\t<dict>\n",
+                "\t\t<key>Blue Component</key>\n\t\t<real>0.18</real>\n",
+                "\t\t<key>Green Component</key>\n\t\t<real>0.196</real>\n",
+                "\t\t<key>Red Component</key>\n\t\t<real>0.863</real>\n\t</dict>\n",
+                "\t<key>Background Color</key>\n\t<dict>\n",
+                "\t\t<key>Blue Component</key>\n\t\t<real>0.212</real>\n",
+                "\t\t<key>Green Component</key>\n\t\t<real>0.169</real>\n",
+                "\t\t<key>Red Component</key>\n\t\t<integer>0</integer>\n\t</dict>\n",
+                "\t<key>Foreground Color</key>\n\t<dict>\n",
+                "\t\t<key>Blue Component</key>\n\t\t<real>0.588</real>\n",
+                "\t\t<key>Green Component</key>\n\t\t<real>0.58</real>\n",
+                "\t\t<key>Red Component</key>\n\t\t<real>0.514</real>\n\t</dict>\n",
+                "</dict>\n</plist>\n",
+            ),
+        )
+        .unwrap();
+        assert_eq!(palette.name, "Solarized Dark");
+        assert_eq!(palette.base00, Color::Rgb(0x00, 0x2b, 0x36));
+        assert_eq!(palette.base05, Color::Rgb(0x83, 0x94, 0x96));
+        assert_eq!(palette.base08, Color::Rgb(0xdc, 0x32, 0x2e));
+
+        assert!(matches!(
+            from_text(file, "{\"Ansi 1 Color\": \"#dc322f\"}")
+                .unwrap_err()
+                .root(),
+            Base16PaletteError::ExtractionFailed(_)
+        ));
+    }
+}
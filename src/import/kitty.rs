@@ -0,0 +1,160 @@
+//! kitty theme configuration.
+
+use std::path::Path;
+
+use super::{file_name, invalid_color_at, missing, parse_color, Terminal, ANSI_SLOTS};
+use crate::{load, Base16Color, Base16Palette, Base16PaletteError};
+
+/// Reads the color directives of a kitty configuration or theme file into
+/// a palette, with the mapping of [`from_ansi`].
+///
+/// `color0` to `color15` give the ANSI colors, `background` and
+/// `foreground` base00 and base05 and `selection_background` base02,
+/// unless it repeats the foreground as inverted selections do. `color16`
+/// to `color21`, which base16 themes and [`export::to_kitty_conf`] set to
+/// the remaining slots, give base09, base0F, base01, base02, base04 and
+/// base06. Other directives, including `include`, are ignored. The palette
+/// takes the name and author from the `## name:` and `## author:` comments
+/// of kitty themes, and is otherwise named after the file name without
+/// extension.
+///
+/// [`from_ansi`]: super::from_ansi
+/// [`export::to_kitty_conf`]: crate::export::to_kitty_conf
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::InvalidColor`] for unreadable colors and
+/// [`Base16PaletteError::MissingField`] if it has neither a background nor
+/// a `color0`, or neither a foreground nor a `color7`, all wrapped in
+/// [`Base16PaletteError::InFile`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, import, DRACULA};
+/// # let dir = std::env::temp_dir().join(format!("ratatui-base16-doc-{}", std::process::id()));
+/// # std::fs::create_dir_all(&dir)?;
+/// let file = dir.join("dracula-kitty.conf");
+/// std::fs::write(&file, export::to_kitty_conf(&DRACULA))?;
+/// let palette = import::from_kitty_conf(&file)?;
+/// assert_eq!(palette.to_array(), DRACULA.to_array());
+/// # std::fs::remove_dir_all(&dir)?;
+/// # Ok::<(), ratatui_base16::Base16PaletteError>(())
+/// ```
+pub fn from_kitty_conf(file: impl AsRef<Path>) -> Result<Base16Palette, Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of a kitty configuration; `file` names it in errors.
+fn from_text(file: &Path, text: &str) -> Result<Base16Palette, Base16PaletteError> {
+    let mut terminal = Terminal::default();
+    let (mut name, mut author) = (None, String::new());
+    let mut selection = None;
+
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix("##") {
+            match comment.trim().split_once(':') {
+                Some((key, value)) if key.trim() == "name" => name = Some(value.trim().to_string()),
+                Some((key, value)) if key.trim() == "author" => author = value.trim().to_string(),
+                _ => {}
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once(char::is_whitespace) else {
+            continue;
+        };
+        let (slot, target) = match key {
+            "background" => (Base16Color::Base00, &mut terminal.background),
+            "foreground" => (Base16Color::Base05, &mut terminal.foreground),
+            "selection_background" => (Base16Color::Base02, &mut selection),
+            _ => {
+                let Some(index) = key
+                    .strip_prefix("color")
+                    .and_then(|index| index.parse::<usize>().ok())
+                else {
+                    continue;
+                };
+                match index {
+                    0..=15 => (ANSI_SLOTS[index], &mut terminal.ansi[index]),
+                    16..=21 => {
+                        let slots = [
+                            Base16Color::Base09,
+                            Base16Color::Base0F,
+                            Base16Color::Base01,
+                            Base16Color::Base02,
+                            Base16Color::Base04,
+                            Base16Color::Base06,
+                        ];
+                        (slots[index - 16], &mut terminal.extended[index - 16])
+                    }
+                    _ => continue,
+                }
+            }
+        };
+        let value = value.trim();
+        let color = parse_color(value, None).ok_or_else(|| {
+            let position = start + text[start..].find(key).unwrap_or_default();
+            invalid_color_at(file, text, Some(position), key, slot, value)
+        })?;
+        *target = Some(color);
+    }
+    terminal.selection = selection.filter(|selection| Some(*selection) != terminal.foreground);
+
+    let name = name
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| file_name(file));
+    let (palette, _) = terminal
+        .into_palette(name, author)
+        .map_err(|slot| match slot {
+            Base16Color::Base00 => missing(file, "background", "background"),
+            _ => missing(file, "foreground", "foreground"),
+        })?;
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn theme_metadata_and_colors_are_read() {
+        let file = Path::new("theme.conf");
+        let palette = from_text(
+            file,
+            concat!(
+                "# vim:ft=kitty\n\n## name: Tokyo Night\n## author: Folke Lemaitre\n\n",
+                "background #1a1b26\nforeground  #c0caf5\nselection_background #283457\n",
+                "# color1 #000000\ncolor1 #f7768e\ncolor10\t#9ece6a\ncursor #c0caf5\n",
+            ),
+        )
+        .unwrap();
+        assert_eq!(palette.name, "Tokyo Night");
+        assert_eq!(palette.author, "Folke Lemaitre");
+        assert_eq!(palette.base02, Color::Rgb(0x28, 0x34, 0x57));
+        assert_eq!(palette.base05, Color::Rgb(0xc0, 0xca, 0xf5));
+        assert_eq!(palette.base08, Color::Rgb(0xf7, 0x76, 0x8e));
+        assert_eq!(palette.base0b, Color::Rgb(0x9e, 0xce, 0x6a));
+
+        let text = "background #1a1b26\n\ncolor7 #c0caf5\ncolor2 green-ish\n";
+        let Base16PaletteError::InFile { line, source, .. } = from_text(file, text).unwrap_err()
+        else {
+            panic!("expected the file to be named");
+        };
+        assert_eq!(line, Some(4));
+        assert!(matches!(
+            *source,
+            Base16PaletteError::InvalidColor {
+                field: "base0b",
+                ..
+            }
+        ));
+    }
+}
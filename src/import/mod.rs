@@ -0,0 +1,344 @@
+//! Importers reading the themes of other tools into palettes.
+//!
+//! Most themes describe more colors than a base16 scheme, or different
+//! ones, so each importer documents how it maps the colors of its format
+//! onto the sixteen slots:
+//!
+//! ```rust,no_run
+//! use ratatui_base16::import;
+//!
+//! let palette = import::from_vscode_theme("themes/monokai-color-theme.json")?;
+//! println!("{palette}");
+//! # Ok::<(), ratatui_base16::Base16PaletteError>(())
+//! ```
+//!
+//! Errors are wrapped in [`Base16PaletteError::InFile`] with the path of the
+//! theme and, where it can be determined, the position of the offending
+//! key.
+//!
+//! [`Base16PaletteError::InFile`]: crate::Base16PaletteError::InFile
+
+use std::path::Path;
+
+use ratatui::style::Color;
+use serde_json::{Map, Value};
+
+use crate::{
+    color,
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+mod alacritty;
+mod gogh;
+mod helix;
+mod iterm;
+mod kitty;
+mod pywal;
+mod terminal_sexy;
+mod vscode;
+mod windows_terminal;
+
+pub use alacritty::from_alacritty;
+pub use gogh::from_gogh_yaml;
+pub use helix::from_helix_toml;
+pub use iterm::from_itermcolors;
+pub use kitty::from_kitty_conf;
+pub use pywal::{from_pywal, from_pywal_auto};
+pub use terminal_sexy::from_terminal_sexy;
+pub use vscode::from_vscode_theme;
+pub use windows_terminal::from_windows_terminal;
+
+/// Parses the text of `file` as `format` into its top-level table.
+fn parse(
+    file: &Path,
+    text: &str,
+    format: Format,
+) -> Result<Map<String, Value>, Base16PaletteError> {
+    load::table(text, format).map_err(|failure| failure.in_file(file, text))
+}
+
+/// Blanks out the comments and trailing commas of JSONC, keeping every
+/// other character in place so that errors point at the original text.
+///
+/// Comments are blanked first, so a trailing comma followed by a comment
+/// before the closing bracket is still found.
+fn strip_comments(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut index = 0;
+    let mut in_string = false;
+    while index < bytes.len() {
+        match (bytes[index], bytes.get(index + 1)) {
+            (b'\\', _) if in_string => index += 1,
+            (b'"', _) => in_string = !in_string,
+            (b'/', Some(b'/')) if !in_string => {
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    out[index] = b' ';
+                    index += 1;
+                }
+                continue;
+            }
+            (b'/', Some(b'*')) if !in_string => {
+                let end = text[index + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| index + end + 4);
+                for byte in &mut out[index..end] {
+                    if *byte != b'\n' {
+                        *byte = b' ';
+                    }
+                }
+                index = end;
+                continue;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    // With the comments blanked, the next significant byte after a comma
+    // tells whether it is a trailing one.
+    let mut in_string = false;
+    let mut index = 0;
+    while index < out.len() {
+        match out[index] {
+            b'\\' if in_string => index += 1,
+            b'"' => in_string = !in_string,
+            b',' if !in_string => {
+                let next = out[index + 1..]
+                    .iter()
+                    .find(|byte| !byte.is_ascii_whitespace());
+                if matches!(next, Some(b'}' | b']')) {
+                    out[index] = b' ';
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    // Only ASCII bytes outside of strings were replaced, so the text is
+    // still valid UTF-8.
+    String::from_utf8(out).unwrap_or_default()
+}
+
+/// Fills in the slots a theme leaves unset, returning the palette and the
+/// slots that were filled in.
+///
+/// The shades are blended between the background (base00) and the
+/// foreground (base05): base01, base02 and base03 at 8%, 17% and 40% of
+/// the way, base04 three quarters of the way from base03, and base07 and
+/// base06 beyond the foreground, away from the background. Accents default
+/// to the foreground, except base09 halfway between base08 and base0A and
+/// base0F, which is base08 darkened towards the background.
+///
+/// Fails with the first of base00 and base05 that is unset.
+fn complete(
+    slots: [Option<Color>; 16],
+    name: String,
+    author: String,
+) -> Result<(Base16Palette, Vec<Base16Color>), Base16Color> {
+    use Base16Color::*;
+
+    let background = slots[0].ok_or(Base00)?;
+    let foreground = slots[5].ok_or(Base05)?;
+    let mut colors = [Color::Reset; 16];
+    let mut defaulted = Vec::new();
+    let dark = color::relative_luminance(background) <= color::relative_luminance(foreground);
+    let extreme = if dark {
+        Color::Rgb(0xff, 0xff, 0xff)
+    } else {
+        Color::Rgb(0, 0, 0)
+    };
+    let order = [
+        Base00, Base05, Base03, Base01, Base02, Base04, Base07, Base06, Base08, Base0A, Base0B,
+        Base0C, Base0D, Base0E, Base09, Base0F,
+    ];
+    for slot in order {
+        let color = match slots[slot as usize] {
+            Some(color) => color,
+            None => {
+                defaulted.push(slot);
+                let get = |slot: Base16Color| colors[slot as usize];
+                match slot {
+                    Base01 => color::blend(background, foreground, 0.08),
+                    Base02 => color::blend(background, foreground, 0.17),
+                    Base03 => color::blend(background, foreground, 0.4),
+                    Base04 => color::blend(get(Base03), foreground, 0.75),
+                    Base07 => color::blend(foreground, extreme, 0.6),
+                    Base06 => color::blend(foreground, get(Base07), 0.5),
+                    Base09 => color::blend(get(Base08), get(Base0A), 0.5),
+                    Base0F => color::blend(get(Base08), background, 0.3),
+                    _ => foreground,
+                }
+            }
+        };
+        colors[slot as usize] = color;
+    }
+    defaulted.sort();
+    Ok((Base16Palette::from_array(colors, name, author), defaulted))
+}
+
+/// Builds a palette from the colors of a terminal theme, inverting the
+/// mapping of [`Base16Palette::to_ansi`].
+///
+/// base00 and base05 are the background and foreground, the accents are
+/// the normal ANSI colors, base03 is bright black and base07 bright white.
+/// The slots that terminal themes don't have are blended from the others:
+/// base01 and base02 between the background and the foreground, base04
+/// between base03 and the foreground, base06 between the foreground and
+/// base07, base09 between red and yellow and base0F from a darkened red.
+/// The palette is unnamed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{import, DRACULA};
+/// let palette = import::from_ansi(DRACULA.to_ansi(), DRACULA.base00, DRACULA.base05);
+/// assert_eq!(palette.base0d, DRACULA.base0d);
+/// assert_eq!(palette.base07, DRACULA.base07);
+/// ```
+pub fn from_ansi(ansi: [Color; 16], background: Color, foreground: Color) -> Base16Palette {
+    let terminal = Terminal {
+        ansi: ansi.map(Some),
+        background: Some(background),
+        foreground: Some(foreground),
+        selection: None,
+        extended: [None; 6],
+    };
+    // Only a missing background or foreground can fail.
+    let (palette, _) = terminal
+        .into_palette(String::new(), String::new())
+        .unwrap_or_else(|_| (Base16Palette::from(ansi), Vec::new()));
+    palette
+}
+
+/// The slots the ANSI colors are read into, matching
+/// [`Base16Palette::to_ansi`].
+const ANSI_SLOTS: [Base16Color; 16] = [
+    Base16Color::Base00,
+    Base16Color::Base08,
+    Base16Color::Base0B,
+    Base16Color::Base0A,
+    Base16Color::Base0D,
+    Base16Color::Base0E,
+    Base16Color::Base0C,
+    Base16Color::Base05,
+    Base16Color::Base03,
+    Base16Color::Base08,
+    Base16Color::Base0B,
+    Base16Color::Base0A,
+    Base16Color::Base0D,
+    Base16Color::Base0E,
+    Base16Color::Base0C,
+    Base16Color::Base07,
+];
+
+/// The colors of a terminal theme, any of which may be missing.
+#[derive(Debug, Default)]
+struct Terminal {
+    ansi: [Option<Color>; 16],
+    background: Option<Color>,
+    foreground: Option<Color>,
+    selection: Option<Color>,
+    /// Colors 16 to 21, which base16 themes set to base09, base0F, base01,
+    /// base02, base04 and base06.
+    extended: [Option<Color>; 6],
+}
+
+impl Terminal {
+    /// Maps the colors onto the slots like [`from_ansi`], using the normal
+    /// and bright variant of a color for each other and black and white for
+    /// the background and foreground if needed.
+    ///
+    /// Fails like [`complete`].
+    fn into_palette(
+        self,
+        name: String,
+        author: String,
+    ) -> Result<(Base16Palette, Vec<Base16Color>), Base16Color> {
+        let ansi = |index: usize| self.ansi[index].or(self.ansi[index + 8]);
+        let mut slots = [None; 16];
+        slots[0x00] = self.background.or(self.ansi[0]);
+        slots[0x02] = self.selection;
+        slots[0x03] = self.ansi[8];
+        slots[0x05] = self.foreground.or(self.ansi[7]);
+        slots[0x07] = self.ansi[15];
+        slots[0x08] = ansi(1);
+        slots[0x0a] = ansi(3);
+        slots[0x0b] = ansi(2);
+        slots[0x0c] = ansi(6);
+        slots[0x0d] = ansi(4);
+        slots[0x0e] = ansi(5);
+        let extended = [0x09, 0x0f, 0x01, 0x02, 0x04, 0x06];
+        for (slot, color) in extended.into_iter().zip(self.extended) {
+            slots[slot] = color.or(slots[slot]);
+        }
+        complete(slots, name, author)
+    }
+}
+
+/// Returns a [`Base16PaletteError::MissingField`] for `field`, in `file`.
+fn missing(file: &Path, key: &str, field: &'static str) -> Base16PaletteError {
+    Base16PaletteError::InFile {
+        path: file.to_path_buf(),
+        key: Some(key.to_string()),
+        line: None,
+        column: None,
+        source: Box::new(Base16PaletteError::MissingField(field)),
+    }
+}
+
+/// Parses a color of a theme, blending colors with an alpha channel over
+/// `background` since palettes can't express translucency.
+fn parse_color(value: &str, background: Option<Color>) -> Option<Color> {
+    let (color, alpha) = color::parse_alpha(value.trim())?;
+    Some(match background {
+        Some(background) if alpha < 0xff => {
+            color::blend(background, color, f64::from(alpha) / 255.0)
+        }
+        _ => color,
+    })
+}
+
+/// Returns an [`Base16PaletteError::InvalidColor`] for the value of `key`
+/// that was read into `slot`, located at the first occurrence of `key` in
+/// `text`.
+fn invalid_color(
+    file: &Path,
+    text: &str,
+    key: &str,
+    slot: Base16Color,
+    value: &str,
+) -> Base16PaletteError {
+    invalid_color_at(file, text, text.find(key), key, slot, value)
+}
+
+/// Returns an [`Base16PaletteError::InvalidColor`] like [`invalid_color`],
+/// located at `offset` in `text`.
+fn invalid_color_at(
+    file: &Path,
+    text: &str,
+    offset: Option<usize>,
+    key: &str,
+    slot: Base16Color,
+    value: &str,
+) -> Base16PaletteError {
+    let position = offset.map(|offset| load::position_of(text, offset));
+    Base16PaletteError::InFile {
+        path: file.to_path_buf(),
+        key: Some(key.to_string()),
+        line: position.map(|(line, _)| line),
+        column: position.and_then(|(_, column)| column),
+        source: Box::new(Base16PaletteError::InvalidColor {
+            field: slot.field_name(),
+            value: value.to_string(),
+            source: format!("`{key}` is not a color").into(),
+        }),
+    }
+}
+
+/// Returns the file stem of `file`, for themes that don't name themselves.
+fn file_name(file: &Path) -> String {
+    file.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
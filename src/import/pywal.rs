@@ -0,0 +1,128 @@
+//! Color schemes generated by pywal.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use super::{invalid_color, missing, parse, parse_color, Terminal, ANSI_SLOTS};
+use crate::{
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+/// Reads the `colors.json` written by pywal into a palette, with the
+/// mapping of [`from_ansi`].
+///
+/// The `special` background and foreground give base00 and base05 and
+/// `color0` to `color15` the ANSI colors. The palette is named "pywal", so
+/// wallpaper-driven colors can be told apart from the builtin palettes.
+///
+/// [`from_ansi`]: super::from_ansi
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it isn't valid JSON,
+/// [`Base16PaletteError::InvalidColor`] for unreadable colors and
+/// [`Base16PaletteError::MissingField`] if it has neither a background nor
+/// a `color0`, or neither a foreground nor a `color7`, all wrapped in
+/// [`Base16PaletteError::InFile`].
+pub fn from_pywal(file: impl AsRef<Path>) -> Result<Base16Palette, Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of a pywal `colors.json`; `file` names it in errors.
+fn from_text(file: &Path, text: &str) -> Result<Base16Palette, Base16PaletteError> {
+    let scheme = parse(file, text, Format::Json)?;
+    let empty = Map::new();
+    let table = |name| {
+        scheme
+            .get(name)
+            .and_then(Value::as_object)
+            .unwrap_or(&empty)
+    };
+    let (special, colors) = (table("special"), table("colors"));
+
+    let color = |table: &Map<String, Value>, key: &str, slot| {
+        let Some(value) = table.get(key).and_then(Value::as_str) else {
+            return Ok(None);
+        };
+        parse_color(value, None)
+            .map(Some)
+            .ok_or_else(|| invalid_color(file, text, key, slot, value))
+    };
+    let mut terminal = Terminal {
+        background: color(special, "background", Base16Color::Base00)?,
+        foreground: color(special, "foreground", Base16Color::Base05)?,
+        ..Terminal::default()
+    };
+    for (index, slot) in ANSI_SLOTS.into_iter().enumerate() {
+        terminal.ansi[index] = color(colors, &format!("color{index}"), slot)?;
+    }
+    let (palette, _) = terminal
+        .into_palette("pywal".to_string(), String::new())
+        .map_err(|slot| match slot {
+            Base16Color::Base00 => missing(file, "special.background", "background"),
+            _ => missing(file, "special.foreground", "foreground"),
+        })?;
+    Ok(palette)
+}
+
+/// Reads the colors pywal generated last, from `~/.cache/wal/colors.json`.
+///
+/// See [`from_pywal`].
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if pywal hasn't run yet or
+/// the home directory is unknown, and otherwise fails like [`from_pywal`].
+pub fn from_pywal_auto() -> Result<Base16Palette, Base16PaletteError> {
+    let file = cache_file().ok_or_else(|| {
+        Base16PaletteError::FileNotFound(PathBuf::from("~/.cache/wal/colors.json"))
+    })?;
+    from_pywal(file)
+}
+
+/// Returns the path pywal writes its colors to, in the cache directory in
+/// the home directory on every platform.
+fn cache_file() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())?;
+    Some(
+        Path::new(&home)
+            .join(".cache")
+            .join("wal")
+            .join("colors.json"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn special_and_indexed_colors_are_read() {
+        let colors: Vec<_> = (0..16)
+            .map(|index| format!("\"color{index}\": \"#{index:02x}{index:02x}{index:02x}\""))
+            .collect();
+        let palette = from_text(
+            Path::new("colors.json"),
+            &format!(
+                "{{\"wallpaper\": \"/tmp/sunset.jpg\", \"alpha\": \"100\", \
+                 \"special\": {{\"background\": \"#171b21\", \"foreground\": \"#c5c6c8\", \"cursor\": \"#c5c6c8\"}}, \
+                 \"colors\": {{{}}}}}",
+                colors.join(", ")
+            ),
+        )
+        .unwrap();
+        assert_eq!(palette.name, "pywal");
+        assert_eq!(palette.base00, Color::Rgb(0x17, 0x1b, 0x21));
+        assert_eq!(palette.base08, Color::Rgb(1, 1, 1));
+        assert_eq!(palette.base03, Color::Rgb(8, 8, 8));
+        assert_eq!(palette.base07, Color::Rgb(15, 15, 15));
+    }
+}
@@ -0,0 +1,99 @@
+//! Schemes exported by terminal.sexy.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{file_name, invalid_color, missing, parse, parse_color, Terminal, ANSI_SLOTS};
+use crate::{
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+/// Reads a scheme exported from terminal.sexy as JSON into a palette, with
+/// the mapping of [`from_ansi`].
+///
+/// The `color` array gives the sixteen ANSI colors and `background` and
+/// `foreground` base00 and base05. The palette takes the `name` and
+/// `author` of the scheme, falling back to the file name without
+/// extension.
+///
+/// [`from_ansi`]: super::from_ansi
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it isn't valid JSON,
+/// [`Base16PaletteError::InvalidColor`] for unreadable colors and
+/// [`Base16PaletteError::MissingField`] if it has neither a background nor
+/// a first color, or neither a foreground nor an eighth color, all wrapped
+/// in [`Base16PaletteError::InFile`].
+pub fn from_terminal_sexy(file: impl AsRef<Path>) -> Result<Base16Palette, Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of a terminal.sexy export; `file` names it in errors.
+fn from_text(file: &Path, text: &str) -> Result<Base16Palette, Base16PaletteError> {
+    let scheme = parse(file, text, Format::Json)?;
+    let string = |key: &str| scheme.get(key).and_then(Value::as_str);
+
+    let color = |key: &str, value: Option<&str>, slot| {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        parse_color(value, None)
+            .map(Some)
+            .ok_or_else(|| invalid_color(file, text, key, slot, value))
+    };
+    let mut terminal = Terminal {
+        background: color("background", string("background"), Base16Color::Base00)?,
+        foreground: color("foreground", string("foreground"), Base16Color::Base05)?,
+        ..Terminal::default()
+    };
+    let colors = scheme.get("color").and_then(Value::as_array);
+    for (index, slot) in ANSI_SLOTS.into_iter().enumerate() {
+        let value = colors
+            .and_then(|colors| colors.get(index))
+            .and_then(Value::as_str);
+        terminal.ansi[index] = color("color", value, slot)?;
+    }
+    let name = string("name")
+        .filter(|name| !name.is_empty())
+        .map_or_else(|| file_name(file), str::to_string);
+    let author = string("author").unwrap_or_default().to_string();
+    let (palette, _) = terminal
+        .into_palette(name, author)
+        .map_err(|slot| match slot {
+            Base16Color::Base00 => missing(file, "background", "background"),
+            _ => missing(file, "foreground", "foreground"),
+        })?;
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn color_array_is_read() {
+        let colors: Vec<_> = (0..16)
+            .map(|index| format!("\"#{index:02x}{index:02x}{index:02x}\""))
+            .collect();
+        let palette = from_text(
+            Path::new("scheme.json"),
+            &format!(
+                "{{\"name\": \"\", \"author\": \"Someone\", \"color\": [{}], \"foreground\": \"#c5c8c6\", \"background\": \"#1d1f21\"}}",
+                colors.join(", ")
+            ),
+        )
+        .unwrap();
+        assert_eq!(palette.name, "scheme");
+        assert_eq!(palette.author, "Someone");
+        assert_eq!(palette.base05, Color::Rgb(0xc5, 0xc8, 0xc6));
+        assert_eq!(palette.base0d, Color::Rgb(4, 4, 4));
+        assert_eq!(palette.base03, Color::Rgb(8, 8, 8));
+    }
+}
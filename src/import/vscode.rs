@@ -0,0 +1,251 @@
+//! Visual Studio Code color themes.
+
+use std::path::Path;
+
+use ratatui::style::Color;
+use serde_json::{Map, Value};
+
+use super::{complete, file_name, invalid_color, missing, parse, parse_color, strip_comments};
+use crate::{
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+/// Workbench colors holding each slot, most specific first. Slots of the
+/// syntax colors fall back to the ANSI colors of the integrated terminal.
+const WORKBENCH: [(Base16Color, &[&str]); 16] = [
+    (Base16Color::Base00, &["editor.background"]),
+    (
+        Base16Color::Base01,
+        &[
+            "editorGroupHeader.tabsBackground",
+            "sideBar.background",
+            "statusBar.background",
+        ],
+    ),
+    (
+        Base16Color::Base02,
+        &[
+            "editor.selectionBackground",
+            "list.activeSelectionBackground",
+        ],
+    ),
+    (
+        Base16Color::Base03,
+        &["editorLineNumber.foreground", "terminal.ansiBrightBlack"],
+    ),
+    (
+        Base16Color::Base04,
+        &["statusBar.foreground", "editorLineNumber.activeForeground"],
+    ),
+    (Base16Color::Base05, &["editor.foreground", "foreground"]),
+    (Base16Color::Base06, &["terminal.ansiWhite"]),
+    (Base16Color::Base07, &["terminal.ansiBrightWhite"]),
+    (Base16Color::Base08, &["terminal.ansiRed"]),
+    (Base16Color::Base09, &[]),
+    (Base16Color::Base0A, &["terminal.ansiYellow"]),
+    (Base16Color::Base0B, &["terminal.ansiGreen"]),
+    (Base16Color::Base0C, &["terminal.ansiCyan"]),
+    (Base16Color::Base0D, &["terminal.ansiBlue"]),
+    (Base16Color::Base0E, &["terminal.ansiMagenta"]),
+    (Base16Color::Base0F, &[]),
+];
+
+/// TextMate scopes whose token color holds each slot, most specific first,
+/// following the base16 TextMate template.
+const SCOPES: [(Base16Color, &[&str]); 9] = [
+    (Base16Color::Base03, &["comment"]),
+    (Base16Color::Base08, &["variable", "entity.name.tag"]),
+    (
+        Base16Color::Base09,
+        &["constant.numeric", "constant.language", "constant"],
+    ),
+    (
+        Base16Color::Base0A,
+        &["entity.name.type", "entity.name.class", "support.type"],
+    ),
+    (Base16Color::Base0B, &["string"]),
+    (
+        Base16Color::Base0C,
+        &["string.regexp", "constant.character.escape"],
+    ),
+    (
+        Base16Color::Base0D,
+        &["entity.name.function", "support.function"],
+    ),
+    (Base16Color::Base0E, &["keyword", "storage"]),
+    (
+        Base16Color::Base0F,
+        &["invalid.deprecated", "punctuation.section.embedded"],
+    ),
+];
+
+/// Reads a Visual Studio Code color theme, the JSON file of a theme
+/// extension, into a palette.
+///
+/// The background shades come from the workbench colors: base00 is
+/// `editor.background`, base01 the tab bar or side bar, base02 the editor
+/// selection, base04 the status bar text and base05 `editor.foreground`.
+/// Comments (base03) and the accents come from the `tokenColors` of the
+/// scopes the base16 TextMate template assigns to each slot, such as
+/// `string` for base0B and `keyword` for base0E, and otherwise from the
+/// ANSI colors of the integrated terminal. Slots that are still unset are
+/// blended from the others, e.g. base09 halfway between base08 and base0A,
+/// and translucent colors are blended over the background.
+///
+/// Comments and trailing commas, which VS Code allows in themes, are
+/// accepted. The palette is named after the `name` of the theme, or the
+/// file name without extension.
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it isn't valid JSON,
+/// [`Base16PaletteError::InvalidColor`] for unreadable colors and
+/// [`Base16PaletteError::MissingField`] if it lacks `editor.background` or
+/// `editor.foreground`, all wrapped in
+/// [`Base16PaletteError::InFile`].
+pub fn from_vscode_theme(file: impl AsRef<Path>) -> Result<Base16Palette, Base16PaletteError> {
+    let file = file.as_ref();
+    from_text(file, &load::read(file)?)
+}
+
+/// Reads the text of a Visual Studio Code color theme; `file` names it in errors.
+fn from_text(file: &Path, text: &str) -> Result<Base16Palette, Base16PaletteError> {
+    let theme = parse(file, &strip_comments(text), Format::Json)?;
+
+    let empty = Map::new();
+    let colors = theme
+        .get("colors")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let mut slots: [Option<Color>; 16] = [None; 16];
+    // The background comes first, so translucent colors can be blended over
+    // it.
+    for (slot, keys) in WORKBENCH {
+        let found = keys
+            .iter()
+            .find_map(|key| Some((*key, colors.get(*key)?.as_str()?)));
+        if let Some((key, value)) = found {
+            let color = parse_color(value, slots[0])
+                .ok_or_else(|| invalid_color(file, text, key, slot, value))?;
+            slots[slot as usize] = Some(color);
+        }
+    }
+    let rules = token_rules(&theme);
+    for (slot, scopes) in SCOPES {
+        let value = scopes.iter().find_map(|scope| token_color(&rules, scope));
+        if let Some(color) = value.and_then(|value| parse_color(value, slots[0])) {
+            slots[slot as usize] = Some(color);
+        }
+    }
+
+    let name = theme
+        .get("name")
+        .and_then(Value::as_str)
+        .map_or_else(|| file_name(file), str::to_string);
+    let author = theme
+        .get("author")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let (palette, _) = complete(slots, name, author).map_err(|slot| match slot {
+        Base16Color::Base00 => missing(file, "colors.editor.background", "editor.background"),
+        _ => missing(file, "colors.editor.foreground", "editor.foreground"),
+    })?;
+    Ok(palette)
+}
+
+/// Returns the scopes and foregrounds of the `tokenColors` rules.
+fn token_rules(theme: &Map<String, Value>) -> Vec<(&str, &str)> {
+    let Some(Value::Array(rules)) = theme.get("tokenColors") else {
+        return Vec::new();
+    };
+    let mut scopes = Vec::new();
+    for rule in rules {
+        let Some(foreground) = rule.pointer("/settings/foreground").and_then(Value::as_str) else {
+            continue;
+        };
+        match rule.get("scope") {
+            Some(Value::String(scope)) => {
+                scopes.extend(scope.split(',').map(|scope| (scope.trim(), foreground)));
+            }
+            Some(Value::Array(list)) => {
+                scopes.extend(
+                    list.iter()
+                        .filter_map(Value::as_str)
+                        .map(|scope| (scope.trim(), foreground)),
+                );
+            }
+            _ => {}
+        }
+    }
+    scopes
+}
+
+/// Returns the foreground of tokens in `scope`, from the rule with the
+/// longest selector that is `scope` or one of its parents.
+fn token_color<'a>(rules: &[(&str, &'a str)], scope: &str) -> Option<&'a str> {
+    rules
+        .iter()
+        .filter(|(selector, _)| {
+            scope == *selector
+                || scope
+                    .strip_prefix(selector)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+        .max_by_key(|(selector, _)| selector.len())
+        .map(|(_, foreground)| *foreground)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workbench_and_token_colors_are_mapped() {
+        let file = Path::new("night-owl.json");
+        let palette = from_text(
+            file,
+            r##"{
+    // Exported from the extension
+    "name": "Night Owl",
+    "colors": {
+        "editor.background": "#011627",
+        "editor.foreground": "#d6deeb",
+        "editor.selectionBackground": "#ffffff40", /* translucent */
+        "terminal.ansiRed": "#ef5350", // last
+    },
+    "tokenColors": [
+        { "scope": "comment", "settings": { "foreground": "#637777" } },
+        { "scope": ["string", "string.quoted"], "settings": { "foreground": "#ecc48d" } },
+        { "scope": "keyword, storage.type", "settings": { "foreground": "#c792ea" } },
+        { "scope": "keyword.operator", "settings": { "foreground": "#7fdbca" } }, /* last */
+    ],
+}"##,
+        )
+        .unwrap();
+        assert_eq!(palette.name, "Night Owl");
+        assert_eq!(palette.base00, Color::Rgb(0x01, 0x16, 0x27));
+        assert_eq!(palette.base02, Color::Rgb(0x89, 0x8b, 0x8d));
+        assert_eq!(palette.base03, Color::Rgb(0x63, 0x77, 0x77));
+        assert_eq!(palette.base08, Color::Rgb(0xef, 0x53, 0x50));
+        assert_eq!(palette.base0b, Color::Rgb(0xec, 0xc4, 0x8d));
+        assert_eq!(palette.base0e, Color::Rgb(0xc7, 0x92, 0xea));
+        assert_eq!(palette.base0d, palette.base05);
+
+        let error =
+            from_text(file, r#"{ "colors": { "editor.background": "nope" } }"#).unwrap_err();
+        assert!(matches!(
+            error,
+            Base16PaletteError::InFile { line: Some(1), .. }
+        ));
+        assert!(matches!(
+            error.root(),
+            Base16PaletteError::InvalidColor {
+                field: "base00",
+                ..
+            }
+        ));
+    }
+}
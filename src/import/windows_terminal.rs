@@ -0,0 +1,181 @@
+//! Windows Terminal color schemes.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{invalid_color_at, missing, parse, parse_color, strip_comments, Terminal, ANSI_SLOTS};
+use crate::{
+    load::{self, Format},
+    Base16Color, Base16Palette, Base16PaletteError,
+};
+
+/// The names Windows Terminal gives the ANSI colors, in order.
+const KEYS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "purple",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightPurple",
+    "brightCyan",
+    "brightWhite",
+];
+
+/// Reads the color scheme named `scheme_name` from the `schemes` list of a
+/// Windows Terminal `settings.json` into a palette, with the mapping of
+/// [`from_ansi`].
+///
+/// Scheme names are matched exactly, then ignoring case. The `background`,
+/// `foreground` and `selectionBackground` of the scheme give base00, base05
+/// and base02. Comments and trailing commas, which Windows Terminal
+/// accepts, are allowed. The palette is named after the scheme.
+///
+/// [`from_ansi`]: super::from_ansi
+///
+/// # Errors
+///
+/// Returns [`Base16PaletteError::FileNotFound`] if the file doesn't exist,
+/// [`Base16PaletteError::ExtractionFailed`] if it isn't valid JSON,
+/// [`Base16PaletteError::PaletteNotFound`] listing the schemes of the file
+/// if none is named `scheme_name`, [`Base16PaletteError::InvalidColor`] for
+/// unreadable colors and [`Base16PaletteError::MissingField`] if the scheme
+/// has neither a background nor a black, or neither a foreground nor a
+/// white, all wrapped in [`Base16PaletteError::InFile`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{export, import, DRACULA};
+/// let scheme = export::to_windows_terminal_json(&DRACULA);
+/// # let dir = std::env::temp_dir().join(format!("ratatui-base16-doc-{}", std::process::id()));
+/// # std::fs::create_dir_all(&dir)?;
+/// let file = dir.join("windows-terminal-settings.json");
+/// std::fs::write(&file, format!("{{ \"schemes\": [{scheme}] }}"))?;
+/// let palette = import::from_windows_terminal(&file, "dracula")?;
+/// assert_eq!(palette.name, "Dracula");
+/// assert_eq!(palette.base0c, DRACULA.base0c);
+/// # std::fs::remove_dir_all(&dir)?;
+/// # Ok::<(), ratatui_base16::Base16PaletteError>(())
+/// ```
+pub fn from_windows_terminal(
+    settings_json: impl AsRef<Path>,
+    scheme_name: &str,
+) -> Result<Base16Palette, Base16PaletteError> {
+    let file = settings_json.as_ref();
+    from_text(file, &load::read(file)?, scheme_name)
+}
+
+/// Reads the text of a Windows Terminal `settings.json`; `file` names it in errors.
+fn from_text(
+    file: &Path,
+    text: &str,
+    scheme_name: &str,
+) -> Result<Base16Palette, Base16PaletteError> {
+    let settings = parse(file, &strip_comments(text), Format::Json)?;
+    let schemes: Vec<_> = settings
+        .get("schemes")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|scheme| Some((scheme.get("name")?.as_str()?, scheme.as_object()?)))
+        .collect();
+    let found = schemes
+        .iter()
+        .find(|(name, _)| *name == scheme_name)
+        .or_else(|| {
+            schemes
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(scheme_name))
+        });
+    let Some((name, scheme)) = found else {
+        return Err(Base16PaletteError::InFile {
+            path: file.to_path_buf(),
+            key: Some("schemes".to_string()),
+            line: None,
+            column: None,
+            source: Box::new(Base16PaletteError::PaletteNotFound {
+                query: scheme_name.to_string(),
+                candidates: schemes.iter().map(|(name, _)| name.to_string()).collect(),
+            }),
+        });
+    };
+
+    // Errors point into the scheme rather than at the first scheme using
+    // the same key.
+    let start = text.find(&format!("\"{name}\"")).unwrap_or_default();
+    let color = |key: &str, slot| {
+        let Some(value) = scheme.get(key).and_then(Value::as_str) else {
+            return Ok(None);
+        };
+        parse_color(value, None).map(Some).ok_or_else(|| {
+            let offset = text[start..]
+                .find(&format!("\"{key}\""))
+                .map(|offset| start + offset + 1);
+            invalid_color_at(file, text, offset, key, slot, value)
+        })
+    };
+    let mut terminal = Terminal {
+        background: color("background", Base16Color::Base00)?,
+        foreground: color("foreground", Base16Color::Base05)?,
+        selection: color("selectionBackground", Base16Color::Base02)?,
+        ..Terminal::default()
+    };
+    for (index, key) in KEYS.into_iter().enumerate() {
+        terminal.ansi[index] = color(key, ANSI_SLOTS[index])?;
+    }
+    let (palette, _) = terminal
+        .into_palette(name.to_string(), String::new())
+        .map_err(|slot| match slot {
+            Base16Color::Base00 => missing(file, "background", "background"),
+            _ => missing(file, "foreground", "foreground"),
+        })?;
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn named_scheme_is_picked() {
+        let file = Path::new("settings.json");
+        let text = r##"{
+    "profiles": { "defaults": { "colorScheme": "One Half Dark" } },
+    // Schemes shipped with Windows Terminal
+    "schemes": [
+        { "name": "Campbell", "background": "#0C0C0C", "foreground": "#CCCCCC", "red": "#C50F1F" },
+        {
+            "name": "One Half Dark",
+            "background": "#282C34",
+            "foreground": "#DCDFE4",
+            "red": "#E06C75",
+            "brightBlack": "#5A6374",
+            "selectionBackground": "#FFFFFF",
+        },
+    ],
+}"##;
+
+        let palette = from_text(file, text, "One Half Dark").unwrap();
+        assert_eq!(palette.name, "One Half Dark");
+        assert_eq!(palette.base00, Color::Rgb(0x28, 0x2c, 0x34));
+        assert_eq!(palette.base03, Color::Rgb(0x5a, 0x63, 0x74));
+        assert_eq!(palette.base08, Color::Rgb(0xe0, 0x6c, 0x75));
+
+        let error = from_text(file, text, "Solarized").unwrap_err();
+        let Base16PaletteError::PaletteNotFound { candidates, .. } = error.root() else {
+            panic!("expected the scheme to be missing");
+        };
+        assert_eq!(candidates, &["Campbell", "One Half Dark"]);
+    }
+}
@@ -1,32 +1,312 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use figment::{
-    providers::{Format, Toml, Yaml},
-    Figment,
-};
+extern crate alloc;
+
+// Lets the expansion of `base16!` refer to this crate by name from within it.
+#[cfg(feature = "macros")]
+extern crate self as ratatui_base16;
+
+use alloc::borrow::Cow;
+#[cfg(feature = "serde")]
+use alloc::boxed::Box;
+use alloc::string::String;
 use ratatui::style::Color;
-use serde::de;
-use serde::de::Deserializer;
-use serde::{Deserialize, Serialize};
-use serde_with::serde_as;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
 use std::path::PathBuf;
-use std::str::FromStr;
 use thiserror::Error;
 
+#[cfg(feature = "std")]
+mod adjust;
+#[cfg(feature = "std")]
+mod alpha;
+mod ansi;
+#[cfg(feature = "detect-os")]
+mod appearance;
+#[cfg(feature = "std")]
+mod apply;
+mod builder;
+#[cfg(feature = "fs")]
+mod cache;
+mod color;
+mod convert;
+#[cfg(feature = "serde")]
+mod css;
+#[cfg(feature = "serde")]
+mod de;
+mod display;
+#[cfg(feature = "std")]
+pub mod export;
+mod family;
+#[cfg(feature = "figment")]
+mod figment;
+#[cfg(feature = "std")]
+pub mod generate;
+#[cfg(feature = "std")]
+mod gradient;
+#[cfg(feature = "fs")]
+pub mod import;
+#[cfg(feature = "serde")]
+mod load;
+mod mutation;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "schedule")]
+mod schedule;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serialize;
+mod slot;
+mod styles;
+mod stylize;
+#[cfg(feature = "syntect")]
+mod syntect;
+#[cfg(all(test, feature = "fs"))]
+mod temp_dir;
+#[cfg(feature = "detect-terminal")]
+mod terminal;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "std")]
+mod tree_sitter;
+#[cfg(feature = "std")]
+mod validate;
+mod variant;
+#[cfg(feature = "std")]
+pub mod widgets;
+
+/// Embeds a scheme file as a `const` [`Base16Palette`] at compile time.
+///
+/// The path is resolved relative to the `CARGO_MANIFEST_DIR` of the calling
+/// crate and a missing or malformed file fails the build. Colors are read
+/// like the runtime loaders read them, limited to hex strings, `"reset"`,
+/// integers and channel arrays and tables; color names, ANSI indexes and CSS
+/// functions fail the build too. This macro is only available with the
+/// `macros` feature enabled.
+///
+/// ```rust,ignore
+/// use ratatui_base16::{base16, Base16Palette};
+///
+/// const GRUVBOX: Base16Palette = base16!("themes/gruvbox.yaml");
+/// ```
+#[cfg(feature = "macros")]
+pub use ratatui_base16_macros::base16;
+
+/// Colors the runtime loaders read but `base16!` rejects fail the build.
+/// `.config/named-colors.yaml` is `.config/notations.yaml` with base08
+/// spelled `"coral"`:
+///
+/// ```compile_fail
+/// const NAMED: ratatui_base16::Base16Palette =
+///     ratatui_base16::base16!(".config/named-colors.yaml");
+/// ```
+#[cfg(all(doctest, feature = "macros"))]
+struct RejectedNotations;
+
+#[cfg(feature = "detect-os")]
+pub use appearance::SystemAppearance;
+#[cfg(feature = "std")]
+pub use apply::TerminalThemeGuard;
+pub use builder::Base16PaletteBuilder;
+#[cfg(feature = "fs")]
+pub use cache::PaletteCache;
+#[cfg(feature = "std")]
+pub use color::{blend, mix};
+pub use family::{SchemeFamily, SCHEME_FAMILIES};
+#[cfg(feature = "serde")]
+pub use load::LoadOptions;
+#[cfg(feature = "std")]
+pub use mutation::Invariants;
+#[cfg(feature = "std")]
+pub use registry::PaletteRegistry;
+#[cfg(feature = "schedule")]
+pub use schedule::ThemeSchedule;
+pub use slot::Base16Color;
+pub use styles::{DiffStyles, LogStyles, MarkupStyles};
+pub use stylize::Base16Stylize;
+#[cfg(feature = "detect-terminal")]
+pub use terminal::TerminalColors;
+#[cfg(feature = "std")]
+pub use tree_sitter::TREE_SITTER_CAPTURES;
+#[cfg(feature = "std")]
+pub use validate::{Finding, FindingKind, Severity};
+pub use variant::Variant;
+
 /// The `Base16PaletteError` enum represents errors that can occur while working
 /// with the Base16 color palette configuration.
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum Base16PaletteError {
     /// This error occurs when the extraction of data from a file or
-    /// configuration fails.
+    /// configuration fails for a reason not covered by a more specific
+    /// variant, such as malformed YAML or TOML syntax.
+    ///
+    /// This variant encapsulates the error of the YAML or TOML parser, or of
+    /// the Figment configuration library when loading through
+    /// `Base16Palette::from_figment` with the `figment` feature enabled.
+    #[cfg(feature = "serde")]
+    #[error("unable to extract data from file: {0}")]
+    ExtractionFailed(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// This error occurs when the palette file to load does not exist.
+    #[cfg(feature = "serde")]
+    #[error("file not found: {}", .0.display())]
+    FileNotFound(PathBuf),
+
+    /// This error occurs when a palette slot holds a value that cannot be
+    /// parsed as a color.
+    #[cfg(feature = "serde")]
+    #[error("invalid color {value:?} for `{field}`")]
+    InvalidColor {
+        /// The slot whose value failed to parse, e.g. `base0a`.
+        field: &'static str,
+        /// The offending value as written in the file.
+        value: String,
+        /// The underlying deserialization error.
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// This error occurs when strict loading finds a key that is not part of
+    /// the base16 spec.
+    #[cfg(feature = "serde")]
+    #[error("unknown field `{0}`")]
+    UnknownField(String),
+
+    /// This error occurs when a palette file has an extension that does not
+    /// match any supported format.
+    #[cfg(feature = "serde")]
+    #[error("unsupported palette format: {0:?}")]
+    UnsupportedFormat(String),
+
+    /// This error wraps a failure to load a palette file with the path of the
+    /// file and, where they can be determined, the offending key and its
+    /// position in the file.
     ///
-    /// This variant encapsulates a `figment::Error`, indicating that it
-    /// originated from the Figment configuration library, which might be
-    /// used to handle configuration data in various formats like JSON,
-    /// TOML, YAML, etc.
-    #[error("unable to extract data from file")]
-    ExtractionFailed(#[from] figment::Error),
+    /// Its message has the familiar `path:line:column: message` shape, so it
+    /// can be shown to end users as is.
+    #[cfg(feature = "serde")]
+    #[error("{}: {source}", location(path, *line, *column))]
+    InFile {
+        /// The file that failed to load.
+        path: PathBuf,
+        /// The dotted key the error refers to, if any.
+        key: Option<String>,
+        /// The 1-based line of the problem, if known.
+        line: Option<usize>,
+        /// The 1-based column of the problem, if known.
+        column: Option<usize>,
+        /// The underlying error.
+        #[source]
+        source: Box<Base16PaletteError>,
+    },
+
+    /// This error occurs when a palette cannot be serialized into one of the
+    /// supported file formats.
+    #[cfg(feature = "serde")]
+    #[error("unable to serialize palette: {0}")]
+    SerializationFailed(String),
+
+    /// This error occurs when reading or writing a file fails.
+    #[cfg(feature = "std")]
+    #[error("i/o error")]
+    Io(#[from] std::io::Error),
+
+    /// This error occurs when a palette is missing one of its sixteen colors.
+    #[error("missing field `{0}`")]
+    MissingField(&'static str),
+
+    /// This error occurs when a guarded mutation would break one of the
+    /// palette's [`Invariants`].
+    #[error("{slot} cannot be changed: {reason}")]
+    InvariantViolated {
+        /// The slot that was being changed.
+        slot: Base16Color,
+        /// A description of the broken invariant.
+        reason: String,
+    },
+
+    /// This error occurs when no palette in a [`PaletteRegistry`] matches a
+    /// looked up name, or several match it equally well.
+    #[cfg(feature = "std")]
+    #[error("no palette named {query:?}{}", suggestions(candidates))]
+    PaletteNotFound {
+        /// The name that was looked up.
+        query: String,
+        /// The names of similar palettes, best match first.
+        candidates: Vec<String>,
+    },
+
+    /// This error occurs when a string does not name one of the sixteen
+    /// palette slots `base00` to `base0F`.
+    #[error("invalid base16 color slot: {0:?}")]
+    InvalidSlot(String),
+}
+
+impl Base16PaletteError {
+    /// Returns the underlying error, looking through any
+    /// [`Base16PaletteError::InFile`] context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::Base16PaletteError;
+    /// let error = Base16PaletteError::MissingField("base08");
+    /// assert!(matches!(error.root(), Base16PaletteError::MissingField(_)));
+    ///
+    /// # #[cfg(feature = "serde")]
+    /// # {
+    /// let error = Base16PaletteError::InFile {
+    ///     path: "dracula.yaml".into(),
+    ///     key: Some("base08".into()),
+    ///     line: None,
+    ///     column: None,
+    ///     source: Box::new(error),
+    /// };
+    /// assert!(matches!(error.root(), Base16PaletteError::MissingField(_)));
+    /// # }
+    /// ```
+    pub fn root(&self) -> &Self {
+        match self {
+            #[cfg(feature = "serde")]
+            Self::InFile { source, .. } => source.root(),
+            error => error,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn suggestions(candidates: &[String]) -> String {
+    match candidates {
+        [] => String::new(),
+        [candidate] => format!(", did you mean {candidate:?}?"),
+        candidates => format!(", did you mean one of {}?", candidates.join(", ")),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn location(path: &std::path::Path, line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!("{}:{line}:{column}", path.display()),
+        (Some(line), None) => format!("{}:{line}", path.display()),
+        _ => path.display().to_string(),
+    }
+}
+
+#[cfg(feature = "figment")]
+impl From<::figment::Error> for Base16PaletteError {
+    fn from(error: ::figment::Error) -> Self {
+        Self::ExtractionFailed(Box::new(error))
+    }
+}
+
+impl From<core::convert::Infallible> for Base16PaletteError {
+    fn from(error: core::convert::Infallible) -> Self {
+        match error {}
+    }
 }
 
 /// A `Base16Palette` defines a color palette based on the Base16 styling
@@ -48,122 +328,99 @@ pub enum Base16PaletteError {
 /// In order to create a dark theme, colours base00 to base07 should span from
 /// dark to light. For a light theme, these colours should span from light to
 /// dark.
-#[serde_as]
-#[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize), serde(from = "de::RawPalette"))]
 pub struct Base16Palette {
-    /// Name
-    #[serde(skip, alias = "scheme")]
-    pub name: &'static str,
+    /// Name, read from the `scheme` key of scheme files
+    pub name: Cow<'static, str>,
 
     /// Author
-    #[serde(skip)]
-    pub author: &'static str,
+    pub author: Cow<'static, str>,
 
-    /// Default Background
-    #[serde(skip)]
-    pub slug: &'static str,
+    /// Slug, typically the file name or URL the scheme originates from
+    pub slug: Cow<'static, str>,
 
     /// Default Background
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base00: Color,
 
     /// Lighter Background (Used for status bars, line number and folding marks)
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base01: Color,
 
     /// Selection Background (Settings where you need to highlight text, such as
     /// find results)
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base02: Color,
 
     /// Comments, Invisibles, Line Highlighting
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base03: Color,
 
     /// Dark Foreground (Used for status bars)
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base04: Color,
 
     /// Default Foreground, Caret, Delimiters, Operators
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base05: Color,
 
     /// Light Foreground (Not often used, could be used for hover states or
     /// dividers)
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base06: Color,
 
     /// Light Background (Probably at most for cursor line background color)
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base07: Color,
 
     /// Variables, XML Tags, Markup Link Text, Markup Lists, Diff Deleted
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base08: Color,
 
     /// Integers, Boolean, Constants, XML Attributes, Markup Link Url
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base09: Color,
 
     /// Classes, Markup Bold, Search Text Background
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base0a: Color,
 
     /// Strings, Inherited Class, Markup Code, Diff Inserted
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base0b: Color,
 
     /// Support, Regular Expressions, Escape Characters, Markup Quotes
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base0c: Color,
 
     /// Functions, Methods, Attribute IDs, Headings
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base0d: Color,
 
     /// Keywords, Storage, Selector, Markup Bold, Diff Changed
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base0e: Color,
 
     /// Deprecated, Opening/Closing Embedded Language Tags, e.g. `<?php ?>
-    #[serde(deserialize_with = "deserialize_from_str")]
     pub base0f: Color,
+
+    /// Alpha channel of each slot in spec order, from base00 to base0F, where
+    /// `0xff` is opaque
+    ///
+    /// Read from `#rrggbbaa` colors in scheme files. Terminals can't render
+    /// translucent colors, so use [`Base16Palette::blend_over`] to composite
+    /// them onto a background first.
+    pub alpha: [u8; 16],
 }
 
+/// Returns [`DEFAULT_DARK`], so a palette created by accident still renders
+/// as a coherent dark scheme.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{Base16Palette, DEFAULT_DARK};
+/// assert_eq!(Base16Palette::default(), DEFAULT_DARK);
+/// ```
 impl Default for Base16Palette {
     fn default() -> Self {
-        Self {
-            name: "Default",
-            author: "Dheepak Krishnamurthy",
-            slug: "ratatui-base16",
-            base00: Color::Indexed(0),
-            base01: Color::Indexed(1),
-            base02: Color::Indexed(2),
-            base03: Color::Indexed(3),
-            base04: Color::Indexed(4),
-            base05: Color::Indexed(5),
-            base06: Color::Indexed(6),
-            base07: Color::Indexed(7),
-            base08: Color::Indexed(8),
-            base09: Color::Indexed(9),
-            base0a: Color::Indexed(10),
-            base0b: Color::Indexed(11),
-            base0c: Color::Indexed(12),
-            base0d: Color::Indexed(13),
-            base0e: Color::Indexed(14),
-            base0f: Color::Indexed(15),
-        }
+        DEFAULT_DARK
     }
 }
 
+#[cfg(feature = "fs")]
 impl Base16Palette {
     /// Loads a `Base16Palette` instance from a YAML file.
     ///
-    /// Given a file path, this function uses Figment's `Yaml` provider to read
-    /// and parse the YAML content into a `Base16Palette` instance. This
-    /// allows for loading the color palette configuration from a
-    /// YAML-formatted file.
+    /// Given a file path, this function reads and parses the YAML content
+    /// into a `Base16Palette` instance. This allows for loading the color
+    /// palette configuration from a YAML-formatted file.
     ///
     /// # Arguments
     ///
@@ -184,18 +441,14 @@ impl Base16Palette {
     /// let palette_result = Base16Palette::from_yaml("path_to_file.yaml");
     /// ```
     pub fn from_yaml(file: impl Into<PathBuf>) -> Result<Self, Base16PaletteError> {
-        Figment::new()
-            .merge(Yaml::file(file.into()))
-            .extract::<Base16Palette>()
-            .map_err(Base16PaletteError::ExtractionFailed)
+        load::yaml(&file.into(), LoadOptions::default())
     }
 
     /// Loads a `Base16Palette` instance from a TOML file.
     ///
-    /// Given a file path, this function uses Figment's `Toml` provider to read
-    /// and parse the TOML content into a `Base16Palette` instance. This
-    /// allows for loading the color palette configuration from a
-    /// TOML-formatted file.
+    /// Given a file path, this function reads and parses the TOML content
+    /// into a `Base16Palette` instance. This allows for loading the color
+    /// palette configuration from a TOML-formatted file.
     ///
     /// # Arguments
     ///
@@ -216,74 +469,116 @@ impl Base16Palette {
     /// let palette_result = Base16Palette::from_toml("path_to_file.toml");
     /// ```
     pub fn from_toml(file: impl Into<PathBuf>) -> Result<Self, Base16PaletteError> {
-        Figment::new()
-            .merge(Toml::file(file.into()))
-            .extract::<Base16Palette>()
-            .map_err(Base16PaletteError::ExtractionFailed)
-    }
-}
-
-fn deserialize_from_str<'de, D>(deserializer: D) -> Result<Color, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let s = String::deserialize(deserializer)?;
-    if s.starts_with('#') {
-        Color::from_str(&s).map_err(de::Error::custom)
-    } else {
-        Color::from_str(&format!("#{s}")).map_err(de::Error::custom)
+        load::toml(&file.into(), LoadOptions::default())
     }
 }
 
+/// Declares a `const` [`Base16Palette`] from `0xRRGGBB` colors, without any
+/// runtime parsing.
+///
+/// The macro takes the name of the constant, optionally preceded by doc
+/// comments, attributes and a visibility, followed by the scheme name, the
+/// optional `author` and `slug`, and the sixteen slots in order. An omitted
+/// author or slug is left empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_base16::palette;
+///
+/// palette! {
+///     /// The theme embedded in my application.
+///     pub MY_THEME,
+///     scheme: "My Theme",
+///     author: "Me",
+///     base00: 0x181818,
+///     base01: 0x282828,
+///     base02: 0x383838,
+///     base03: 0x585858,
+///     base04: 0xb8b8b8,
+///     base05: 0xd8d8d8,
+///     base06: 0xe8e8e8,
+///     base07: 0xf8f8f8,
+///     base08: 0xab4642,
+///     base09: 0xdc9656,
+///     base0a: 0xf7ca88,
+///     base0b: 0xa1b56c,
+///     base0c: 0x86c1b9,
+///     base0d: 0x7cafc2,
+///     base0e: 0xba8baf,
+///     base0f: 0xa16946,
+/// }
+///
+/// assert_eq!(MY_THEME.name, "My Theme");
+/// assert_eq!(MY_THEME.slug, "");
+/// assert_eq!(MY_THEME.base0d, ratatui::style::Color::Rgb(0x7c, 0xaf, 0xc2));
+/// ```
+#[macro_export]
 macro_rules! palette {
     (
-        $name:ident,
-        scheme : $scheme:literal,
-        author : $author:literal,
-        slug : $slug:literal,
-        base00 : $base00:literal,
-        base01 : $base01:literal,
-        base02 : $base02:literal,
-        base03 : $base03:literal,
-        base04 : $base04:literal,
-        base05 : $base05:literal,
-        base06 : $base06:literal,
-        base07 : $base07:literal,
-        base08 : $base08:literal,
-        base09 : $base09:literal,
-        base0a : $base0a:literal,
-        base0b : $base0b:literal,
-        base0c : $base0c:literal,
-        base0d : $base0d:literal,
-        base0e : $base0e:literal,
-        base0f : $base0f:literal,
+        $(#[$meta:meta])*
+        $vis:vis $name:ident,
+        scheme : $scheme:expr,
+        $(author : $author:expr,)?
+        $(slug : $slug:expr,)?
+        base00 : $base00:expr,
+        base01 : $base01:expr,
+        base02 : $base02:expr,
+        base03 : $base03:expr,
+        base04 : $base04:expr,
+        base05 : $base05:expr,
+        base06 : $base06:expr,
+        base07 : $base07:expr,
+        base08 : $base08:expr,
+        base09 : $base09:expr,
+        base0a : $base0a:expr,
+        base0b : $base0b:expr,
+        base0c : $base0c:expr,
+        base0d : $base0d:expr,
+        base0e : $base0e:expr,
+        base0f : $base0f:expr,
     ) => {
-        pub const $name: $crate::Base16Palette = $crate::Base16Palette {
-            name: $scheme,
-            author: $author,
-            slug: $slug,
-            base00: ratatui::style::Color::from_u32($base00),
-            base01: ratatui::style::Color::from_u32($base01),
-            base02: ratatui::style::Color::from_u32($base02),
-            base03: ratatui::style::Color::from_u32($base03),
-            base04: ratatui::style::Color::from_u32($base04),
-            base05: ratatui::style::Color::from_u32($base05),
-            base06: ratatui::style::Color::from_u32($base06),
-            base07: ratatui::style::Color::from_u32($base07),
-            base08: ratatui::style::Color::from_u32($base08),
-            base09: ratatui::style::Color::from_u32($base09),
-            base0a: ratatui::style::Color::from_u32($base0a),
-            base0b: ratatui::style::Color::from_u32($base0b),
-            base0c: ratatui::style::Color::from_u32($base0c),
-            base0d: ratatui::style::Color::from_u32($base0d),
-            base0e: ratatui::style::Color::from_u32($base0e),
-            base0f: ratatui::style::Color::from_u32($base0f),
+        $(#[$meta])*
+        $vis const $name: $crate::Base16Palette = $crate::Base16Palette {
+            name: $crate::__private::Cow::Borrowed($scheme),
+            author: $crate::__private::Cow::Borrowed($crate::palette!(@or $($author)?)),
+            slug: $crate::__private::Cow::Borrowed($crate::palette!(@or $($slug)?)),
+            base00: $crate::__private::Color::from_u32($base00),
+            base01: $crate::__private::Color::from_u32($base01),
+            base02: $crate::__private::Color::from_u32($base02),
+            base03: $crate::__private::Color::from_u32($base03),
+            base04: $crate::__private::Color::from_u32($base04),
+            base05: $crate::__private::Color::from_u32($base05),
+            base06: $crate::__private::Color::from_u32($base06),
+            base07: $crate::__private::Color::from_u32($base07),
+            base08: $crate::__private::Color::from_u32($base08),
+            base09: $crate::__private::Color::from_u32($base09),
+            base0a: $crate::__private::Color::from_u32($base0a),
+            base0b: $crate::__private::Color::from_u32($base0b),
+            base0c: $crate::__private::Color::from_u32($base0c),
+            base0d: $crate::__private::Color::from_u32($base0d),
+            base0e: $crate::__private::Color::from_u32($base0e),
+            base0f: $crate::__private::Color::from_u32($base0f),
+            alpha: [0xff; 16],
         };
     };
+    (@or) => {
+        ""
+    };
+    (@or $value:expr) => {
+        $value
+    };
+}
+
+/// Items used by the expansion of [`palette!`], not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::borrow::Cow;
+    pub use ratatui::style::Color;
 }
 
 palette! {
-    CUPCAKE,
+    pub CUPCAKE,
     scheme: "Cupcake",
     author: "Chris Kempson (http://chriskempson.com)",
     slug: "https://github.com/chriskempson/base16-default-schemes/blob/master/cupcake.yaml",
@@ -306,7 +601,7 @@ palette! {
 }
 
 palette! {
-    DEFAULT_DARK,
+    pub DEFAULT_DARK,
     scheme: "Default Dark",
     author: "Chris Kempson (http://chriskempson.com)",
     slug: "https://github.com/chriskempson/base16-default-schemes/blob/master/default-dark.yaml",
@@ -329,7 +624,7 @@ palette! {
 }
 
 palette! {
-    DEFAULT_LIGHT,
+    pub DEFAULT_LIGHT,
     scheme: "Default Light",
     author: "Chris Kempson (http://chriskempson.com)",
     slug: "https://github.com/chriskempson/base16-default-schemes/blob/master/default-light.yaml",
@@ -352,7 +647,7 @@ palette! {
 }
 
 palette! {
-    EIGHTIES,
+    pub EIGHTIES,
     scheme: "Eighties",
     author: "Chris Kempson (http://chriskempson.com)",
     slug: "https://github.com/chriskempson/base16-default-schemes/blob/master/eighties.yaml",
@@ -375,7 +670,7 @@ palette! {
 }
 
 palette! {
-    MOCHA,
+    pub MOCHA,
     scheme: "Mocha",
     author: "Chris Kempson (http://chriskempson.com)",
     slug: "https://github.com/chriskempson/base16-default-schemes/blob/master/mocha.yaml",
@@ -398,7 +693,7 @@ palette! {
 }
 
 palette! {
-    OCEAN,
+    pub OCEAN,
     scheme: "Ocean",
     author: "Chris Kempson (http://chriskempson.com)",
     slug: "https://github.com/chriskempson/base16-default-schemes/blob/master/ocean.yaml",
@@ -421,7 +716,7 @@ palette! {
 }
 
 palette! {
-    DRACULA,
+    pub DRACULA,
     scheme: "Dracula",
     author: "Mike Barkmin (http://github.com/mikebarkmin) based on Dracula Theme (http://github.com/dracula)",
     slug: "https://github.com/dracula/base16-dracula-scheme/blob/master/dracula.yaml",
@@ -444,7 +739,7 @@ palette! {
 }
 
 palette! {
-    GITHUB_LIGHT,
+    pub GITHUB_LIGHT,
     scheme: "Github",
     author: "Defman21",
     slug: "https://github.com/Defman21/base16-github-scheme/blob/master/github.yaml",
@@ -467,7 +762,7 @@ palette! {
 }
 
 palette! {
-    ROSE_PINE_DAWN,
+    pub ROSE_PINE_DAWN,
     scheme: "Rosé Pine Dawn",
     author: "Emilia Dunfelt <edun@dunfelt.se>",
     slug: "https://github.com/edunfelt/base16-rose-pine-scheme/blob/main/rose-pine-dawn.yaml",
@@ -490,7 +785,7 @@ palette! {
 }
 
 palette! {
-    ROSE_PINE_MOON,
+    pub ROSE_PINE_MOON,
     scheme: "Rosé Pine Moon",
     author: "Emilia Dunfelt <edun@dunfelt.se>",
     slug: "https://github.com/edunfelt/base16-rose-pine-scheme/blob/main/rose-pine-moon.yaml",
@@ -513,7 +808,7 @@ palette! {
 }
 
 palette! {
-    ROSE_PINE,
+    pub ROSE_PINE,
     scheme: "Rosé Pine",
     author: "Emilia Dunfelt <edun@dunfelt.se>",
     slug: "https://github.com/edunfelt/base16-rose-pine-scheme/blob/main/rose-pine.yaml",
@@ -535,18 +830,833 @@ palette! {
     base0f: 0x00524f67,
 }
 
-#[cfg(test)]
+#[cfg(feature = "schemes-gruvbox")]
+palette! {
+    pub GRUVBOX_DARK,
+    scheme: "Gruvbox dark, medium",
+    author: "Dawid Kurek (dawikur@gmail.com), morhetz (https://github.com/morhetz/gruvbox)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/gruvbox-dark-medium.yaml",
+    base00: 0x00282828,
+    base01: 0x003c3836,
+    base02: 0x00504945,
+    base03: 0x00665c54,
+    base04: 0x00bdae93,
+    base05: 0x00d5c4a1,
+    base06: 0x00ebdbb2,
+    base07: 0x00fbf1c7,
+    base08: 0x00fb4934,
+    base09: 0x00fe8019,
+    base0a: 0x00fabd2f,
+    base0b: 0x00b8bb26,
+    base0c: 0x008ec07c,
+    base0d: 0x0083a598,
+    base0e: 0x00d3869b,
+    base0f: 0x00d65d0e,
+}
+
+#[cfg(feature = "schemes-gruvbox")]
+palette! {
+    pub GRUVBOX_DARK_HARD,
+    scheme: "Gruvbox dark, hard",
+    author: "Dawid Kurek (dawikur@gmail.com), morhetz (https://github.com/morhetz/gruvbox)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/gruvbox-dark-hard.yaml",
+    base00: 0x001d2021,
+    base01: 0x003c3836,
+    base02: 0x00504945,
+    base03: 0x00665c54,
+    base04: 0x00bdae93,
+    base05: 0x00d5c4a1,
+    base06: 0x00ebdbb2,
+    base07: 0x00fbf1c7,
+    base08: 0x00fb4934,
+    base09: 0x00fe8019,
+    base0a: 0x00fabd2f,
+    base0b: 0x00b8bb26,
+    base0c: 0x008ec07c,
+    base0d: 0x0083a598,
+    base0e: 0x00d3869b,
+    base0f: 0x00d65d0e,
+}
+
+#[cfg(feature = "schemes-gruvbox")]
+palette! {
+    pub GRUVBOX_DARK_SOFT,
+    scheme: "Gruvbox dark, soft",
+    author: "Dawid Kurek (dawikur@gmail.com), morhetz (https://github.com/morhetz/gruvbox)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/gruvbox-dark-soft.yaml",
+    base00: 0x0032302f,
+    base01: 0x003c3836,
+    base02: 0x00504945,
+    base03: 0x00665c54,
+    base04: 0x00bdae93,
+    base05: 0x00d5c4a1,
+    base06: 0x00ebdbb2,
+    base07: 0x00fbf1c7,
+    base08: 0x00fb4934,
+    base09: 0x00fe8019,
+    base0a: 0x00fabd2f,
+    base0b: 0x00b8bb26,
+    base0c: 0x008ec07c,
+    base0d: 0x0083a598,
+    base0e: 0x00d3869b,
+    base0f: 0x00d65d0e,
+}
+
+#[cfg(feature = "schemes-gruvbox")]
+palette! {
+    pub GRUVBOX_LIGHT,
+    scheme: "Gruvbox light, medium",
+    author: "Dawid Kurek (dawikur@gmail.com), morhetz (https://github.com/morhetz/gruvbox)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/gruvbox-light-medium.yaml",
+    base00: 0x00fbf1c7,
+    base01: 0x00ebdbb2,
+    base02: 0x00d5c4a1,
+    base03: 0x00bdae93,
+    base04: 0x00665c54,
+    base05: 0x00504945,
+    base06: 0x003c3836,
+    base07: 0x00282828,
+    base08: 0x009d0006,
+    base09: 0x00af3a03,
+    base0a: 0x00b57614,
+    base0b: 0x0079740e,
+    base0c: 0x00427b58,
+    base0d: 0x00076678,
+    base0e: 0x008f3f71,
+    base0f: 0x00d65d0e,
+}
+
+#[cfg(feature = "schemes-gruvbox")]
+palette! {
+    pub GRUVBOX_LIGHT_HARD,
+    scheme: "Gruvbox light, hard",
+    author: "Dawid Kurek (dawikur@gmail.com), morhetz (https://github.com/morhetz/gruvbox)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/gruvbox-light-hard.yaml",
+    base00: 0x00f9f5d7,
+    base01: 0x00ebdbb2,
+    base02: 0x00d5c4a1,
+    base03: 0x00bdae93,
+    base04: 0x00665c54,
+    base05: 0x00504945,
+    base06: 0x003c3836,
+    base07: 0x00282828,
+    base08: 0x009d0006,
+    base09: 0x00af3a03,
+    base0a: 0x00b57614,
+    base0b: 0x0079740e,
+    base0c: 0x00427b58,
+    base0d: 0x00076678,
+    base0e: 0x008f3f71,
+    base0f: 0x00d65d0e,
+}
+
+#[cfg(feature = "schemes-gruvbox")]
+palette! {
+    pub GRUVBOX_LIGHT_SOFT,
+    scheme: "Gruvbox light, soft",
+    author: "Dawid Kurek (dawikur@gmail.com), morhetz (https://github.com/morhetz/gruvbox)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/gruvbox-light-soft.yaml",
+    base00: 0x00f2e5bc,
+    base01: 0x00ebdbb2,
+    base02: 0x00d5c4a1,
+    base03: 0x00bdae93,
+    base04: 0x00665c54,
+    base05: 0x00504945,
+    base06: 0x003c3836,
+    base07: 0x00282828,
+    base08: 0x009d0006,
+    base09: 0x00af3a03,
+    base0a: 0x00b57614,
+    base0b: 0x0079740e,
+    base0c: 0x00427b58,
+    base0d: 0x00076678,
+    base0e: 0x008f3f71,
+    base0f: 0x00d65d0e,
+}
+
+#[cfg(feature = "schemes-nord")]
+palette! {
+    pub NORD,
+    scheme: "Nord",
+    author: "arcticicestudio",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/nord.yaml",
+    base00: 0x002e3440,
+    base01: 0x003b4252,
+    base02: 0x00434c5e,
+    base03: 0x004c566a,
+    base04: 0x00d8dee9,
+    base05: 0x00e5e9f0,
+    base06: 0x00eceff4,
+    base07: 0x008fbcbb,
+    base08: 0x00bf616a,
+    base09: 0x00d08770,
+    base0a: 0x00ebcb8b,
+    base0b: 0x00a3be8c,
+    base0c: 0x0088c0d0,
+    base0d: 0x0081a1c1,
+    base0e: 0x00b48ead,
+    base0f: 0x005e81ac,
+}
+
+#[cfg(feature = "schemes-solarized")]
+palette! {
+    pub SOLARIZED_DARK,
+    scheme: "Solarized Dark",
+    author: "Ethan Schoonover (modified by aramisgithub)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/solarized-dark.yaml",
+    base00: 0x00002b36,
+    base01: 0x00073642,
+    base02: 0x00586e75,
+    base03: 0x00657b83,
+    base04: 0x00839496,
+    base05: 0x0093a1a1,
+    base06: 0x00eee8d5,
+    base07: 0x00fdf6e3,
+    base08: 0x00dc322f,
+    base09: 0x00cb4b16,
+    base0a: 0x00b58900,
+    base0b: 0x00859900,
+    base0c: 0x002aa198,
+    base0d: 0x00268bd2,
+    base0e: 0x006c71c4,
+    base0f: 0x00d33682,
+}
+
+#[cfg(feature = "schemes-solarized")]
+palette! {
+    pub SOLARIZED_LIGHT,
+    scheme: "Solarized Light",
+    author: "Ethan Schoonover (modified by aramisgithub)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/solarized-light.yaml",
+    base00: 0x00fdf6e3,
+    base01: 0x00eee8d5,
+    base02: 0x0093a1a1,
+    base03: 0x00839496,
+    base04: 0x00657b83,
+    base05: 0x00586e75,
+    base06: 0x00073642,
+    base07: 0x00002b36,
+    base08: 0x00dc322f,
+    base09: 0x00cb4b16,
+    base0a: 0x00b58900,
+    base0b: 0x00859900,
+    base0c: 0x002aa198,
+    base0d: 0x00268bd2,
+    base0e: 0x006c71c4,
+    base0f: 0x00d33682,
+}
+
+#[cfg(feature = "schemes-catppuccin")]
+palette! {
+    pub CATPPUCCIN_MOCHA,
+    scheme: "Catppuccin Mocha",
+    author: "https://github.com/catppuccin/catppuccin",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/catppuccin-mocha.yaml",
+    base00: 0x001e1e2e,
+    base01: 0x00181825,
+    base02: 0x00313244,
+    base03: 0x0045475a,
+    base04: 0x00585b70,
+    base05: 0x00cdd6f4,
+    base06: 0x00f5e0dc,
+    base07: 0x00b4befe,
+    base08: 0x00f38ba8,
+    base09: 0x00fab387,
+    base0a: 0x00f9e2af,
+    base0b: 0x00a6e3a1,
+    base0c: 0x0094e2d5,
+    base0d: 0x0089b4fa,
+    base0e: 0x00cba6f7,
+    base0f: 0x00f2cdcd,
+}
+
+#[cfg(feature = "schemes-catppuccin")]
+palette! {
+    pub CATPPUCCIN_MACCHIATO,
+    scheme: "Catppuccin Macchiato",
+    author: "https://github.com/catppuccin/catppuccin",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/catppuccin-macchiato.yaml",
+    base00: 0x0024273a,
+    base01: 0x001e2030,
+    base02: 0x00363a4f,
+    base03: 0x00494d64,
+    base04: 0x005b6078,
+    base05: 0x00cad3f5,
+    base06: 0x00f4dbd6,
+    base07: 0x00b7bdf8,
+    base08: 0x00ed8796,
+    base09: 0x00f5a97f,
+    base0a: 0x00eed49f,
+    base0b: 0x00a6da95,
+    base0c: 0x008bd5ca,
+    base0d: 0x008aadf4,
+    base0e: 0x00c6a0f6,
+    base0f: 0x00f0c6c6,
+}
+
+#[cfg(feature = "schemes-catppuccin")]
+palette! {
+    pub CATPPUCCIN_FRAPPE,
+    scheme: "Catppuccin Frappe",
+    author: "https://github.com/catppuccin/catppuccin",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/catppuccin-frappe.yaml",
+    base00: 0x00303446,
+    base01: 0x00292c3c,
+    base02: 0x00414559,
+    base03: 0x0051576d,
+    base04: 0x00626880,
+    base05: 0x00c6d0f5,
+    base06: 0x00f2d5cf,
+    base07: 0x00babbf1,
+    base08: 0x00e78284,
+    base09: 0x00ef9f76,
+    base0a: 0x00e5c890,
+    base0b: 0x00a6d189,
+    base0c: 0x0081c8be,
+    base0d: 0x008caaee,
+    base0e: 0x00ca9ee6,
+    base0f: 0x00eebebe,
+}
+
+#[cfg(feature = "schemes-catppuccin")]
+palette! {
+    pub CATPPUCCIN_LATTE,
+    scheme: "Catppuccin Latte",
+    author: "https://github.com/catppuccin/catppuccin",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/catppuccin-latte.yaml",
+    base00: 0x00eff1f5,
+    base01: 0x00e6e9ef,
+    base02: 0x00ccd0da,
+    base03: 0x00bcc0cc,
+    base04: 0x00acb0be,
+    base05: 0x004c4f69,
+    base06: 0x00dc8a78,
+    base07: 0x007287fd,
+    base08: 0x00d20f39,
+    base09: 0x00fe640b,
+    base0a: 0x00df8e1d,
+    base0b: 0x0040a02b,
+    base0c: 0x00179299,
+    base0d: 0x001e66f5,
+    base0e: 0x008839ef,
+    base0f: 0x00dd7878,
+}
+
+#[cfg(feature = "schemes-tokyo-night")]
+palette! {
+    pub TOKYO_NIGHT,
+    scheme: "Tokyo Night Dark",
+    author: "Michaël Ball",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/tokyo-night-dark.yaml",
+    base00: 0x001a1b26,
+    base01: 0x0016161e,
+    base02: 0x002f3549,
+    base03: 0x00444b6a,
+    base04: 0x00787c99,
+    base05: 0x00a9b1d6,
+    base06: 0x00cbccd1,
+    base07: 0x00d5d6db,
+    base08: 0x00c0caf5,
+    base09: 0x00a9b1d6,
+    base0a: 0x000db9d7,
+    base0b: 0x009ece6a,
+    base0c: 0x00b4f9f8,
+    base0d: 0x002ac3de,
+    base0e: 0x00bb9af7,
+    base0f: 0x00f7768e,
+}
+
+#[cfg(feature = "schemes-tokyo-night")]
+palette! {
+    pub TOKYO_NIGHT_STORM,
+    scheme: "Tokyo Night Storm",
+    author: "Michaël Ball",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/tokyo-night-storm.yaml",
+    base00: 0x0024283b,
+    base01: 0x0016161e,
+    base02: 0x00343a52,
+    base03: 0x00444b6a,
+    base04: 0x00787c99,
+    base05: 0x00a9b1d6,
+    base06: 0x00cbccd1,
+    base07: 0x00d5d6db,
+    base08: 0x00c0caf5,
+    base09: 0x00a9b1d6,
+    base0a: 0x000db9d7,
+    base0b: 0x009ece6a,
+    base0c: 0x00b4f9f8,
+    base0d: 0x002ac3de,
+    base0e: 0x00bb9af7,
+    base0f: 0x00f7768e,
+}
+
+#[cfg(feature = "schemes-tokyo-night")]
+palette! {
+    pub TOKYO_NIGHT_LIGHT,
+    scheme: "Tokyo Night Light",
+    author: "Michaël Ball",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/tokyo-night-light.yaml",
+    base00: 0x00d5d6db,
+    base01: 0x00cbccd1,
+    base02: 0x00dfe0e5,
+    base03: 0x009699a3,
+    base04: 0x004c505e,
+    base05: 0x00343b59,
+    base06: 0x001a1b26,
+    base07: 0x001a1b26,
+    base08: 0x00343b58,
+    base09: 0x00965027,
+    base0a: 0x00166775,
+    base0b: 0x00485e30,
+    base0c: 0x003e6968,
+    base0d: 0x0034548a,
+    base0e: 0x005a4a78,
+    base0f: 0x008c4351,
+}
+
+#[cfg(feature = "schemes-kanagawa")]
+palette! {
+    pub KANAGAWA,
+    scheme: "Kanagawa",
+    author: "Tommaso Laurenzi (https://github.com/rebelot)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/kanagawa.yaml",
+    base00: 0x001f1f28,
+    base01: 0x0016161d,
+    base02: 0x00223249,
+    base03: 0x0054546d,
+    base04: 0x00727169,
+    base05: 0x00dcd7ba,
+    base06: 0x00c8c093,
+    base07: 0x00717c7c,
+    base08: 0x00c34043,
+    base09: 0x00ffa066,
+    base0a: 0x00c0a36e,
+    base0b: 0x0076946a,
+    base0c: 0x006a9589,
+    base0d: 0x007e9cd8,
+    base0e: 0x00957fb8,
+    base0f: 0x00d27e99,
+}
+
+#[cfg(feature = "schemes-everforest")]
+palette! {
+    pub EVERFOREST_DARK,
+    scheme: "Everforest",
+    author: "Sainnhe Park (https://github.com/sainnhe)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/everforest.yaml",
+    base00: 0x002f383e,
+    base01: 0x00374247,
+    base02: 0x004a555b,
+    base03: 0x00859289,
+    base04: 0x009da9a0,
+    base05: 0x00d3c6aa,
+    base06: 0x00e4e1cd,
+    base07: 0x00fdf6e3,
+    base08: 0x007fbbb3,
+    base09: 0x00d699b6,
+    base0a: 0x00dbbc7f,
+    base0b: 0x0083c092,
+    base0c: 0x00e69875,
+    base0d: 0x00a7c080,
+    base0e: 0x00e67e80,
+    base0f: 0x00eaedc8,
+}
+
+#[cfg(feature = "schemes-everforest")]
+palette! {
+    pub EVERFOREST_LIGHT,
+    scheme: "Everforest Light",
+    author: "Sainnhe Park (https://github.com/sainnhe)",
+    slug: "https://github.com/sainnhe/everforest/blob/master/palette.md",
+    base00: 0x00fdf6e3,
+    base01: 0x00f4f0d9,
+    base02: 0x00e6e2cc,
+    base03: 0x00939f91,
+    base04: 0x00829181,
+    base05: 0x005c6a72,
+    base06: 0x004a555b,
+    base07: 0x002f383e,
+    base08: 0x003a94c5,
+    base09: 0x00df69ba,
+    base0a: 0x00dfa000,
+    base0b: 0x0035a77c,
+    base0c: 0x00f57d26,
+    base0d: 0x008da101,
+    base0e: 0x00f85552,
+    base0f: 0x00a6b0a0,
+}
+
+#[cfg(feature = "schemes-one")]
+palette! {
+    pub ONE_DARK,
+    scheme: "OneDark",
+    author: "Lalit Magant (http://github.com/tilal6991)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/onedark.yaml",
+    base00: 0x00282c34,
+    base01: 0x00353b45,
+    base02: 0x003e4451,
+    base03: 0x00545862,
+    base04: 0x00565c64,
+    base05: 0x00abb2bf,
+    base06: 0x00b6bdca,
+    base07: 0x00c8ccd4,
+    base08: 0x00e06c75,
+    base09: 0x00d19a66,
+    base0a: 0x00e5c07b,
+    base0b: 0x0098c379,
+    base0c: 0x0056b6c2,
+    base0d: 0x0061afef,
+    base0e: 0x00c678dd,
+    base0f: 0x00be5046,
+}
+
+#[cfg(feature = "schemes-one")]
+palette! {
+    pub ONE_LIGHT,
+    scheme: "One Light",
+    author: "Daniel Pfeifer (http://github.com/purpleKarrot)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/one-light.yaml",
+    base00: 0x00fafafa,
+    base01: 0x00f0f0f1,
+    base02: 0x00e5e5e6,
+    base03: 0x00a0a1a7,
+    base04: 0x00696c77,
+    base05: 0x00383a42,
+    base06: 0x00202227,
+    base07: 0x00090a0b,
+    base08: 0x00ca1243,
+    base09: 0x00d75f00,
+    base0a: 0x00c18401,
+    base0b: 0x0050a14f,
+    base0c: 0x000184bc,
+    base0d: 0x004078f2,
+    base0e: 0x00a626a4,
+    base0f: 0x00986801,
+}
+
+#[cfg(feature = "schemes-monokai")]
+palette! {
+    pub MONOKAI,
+    scheme: "Monokai",
+    author: "Wimer Hazenberg (http://www.monokai.nl)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/monokai.yaml",
+    base00: 0x00272822,
+    base01: 0x00383830,
+    base02: 0x0049483e,
+    base03: 0x0075715e,
+    base04: 0x00a59f85,
+    base05: 0x00f8f8f2,
+    base06: 0x00f5f4f1,
+    base07: 0x00f9f8f5,
+    base08: 0x00f92672,
+    base09: 0x00fd971f,
+    base0a: 0x00f4bf75,
+    base0b: 0x00a6e22e,
+    base0c: 0x00a1efe4,
+    base0d: 0x0066d9ef,
+    base0e: 0x00ae81ff,
+    base0f: 0x00cc6633,
+}
+
+#[cfg(feature = "schemes-monokai")]
+palette! {
+    pub MONOKAI_PRO,
+    scheme: "Monokai Pro",
+    author: "Wimer Hazenberg (https://monokai.pro)",
+    slug: "https://monokai.pro",
+    base00: 0x002d2a2e,
+    base01: 0x00403e41,
+    base02: 0x005b595c,
+    base03: 0x00727072,
+    base04: 0x00c1c0c0,
+    base05: 0x00fcfcfa,
+    base06: 0x00fcfcfa,
+    base07: 0x00ffffff,
+    base08: 0x00ff6188,
+    base09: 0x00fc9867,
+    base0a: 0x00ffd866,
+    base0b: 0x00a9dc76,
+    base0c: 0x0078dce8,
+    base0d: 0x0078dce8,
+    base0e: 0x00ab9df2,
+    base0f: 0x00fc9867,
+}
+
+#[cfg(feature = "schemes-ayu")]
+palette! {
+    pub AYU_DARK,
+    scheme: "Ayu Dark",
+    author: "Tinted Theming (https://github.com/tinted-theming)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/ayu-dark.yaml",
+    base00: 0x000b0e14,
+    base01: 0x00131721,
+    base02: 0x00202229,
+    base03: 0x003e4b59,
+    base04: 0x00bfbdb6,
+    base05: 0x00e6e1cf,
+    base06: 0x00e6e1cf,
+    base07: 0x00f3f4f5,
+    base08: 0x00f07178,
+    base09: 0x00ff8f40,
+    base0a: 0x00ffb454,
+    base0b: 0x00b8cc52,
+    base0c: 0x0095e6cb,
+    base0d: 0x0059c2ff,
+    base0e: 0x00d2a6ff,
+    base0f: 0x00e6b673,
+}
+
+#[cfg(feature = "schemes-ayu")]
+palette! {
+    pub AYU_MIRAGE,
+    scheme: "Ayu Mirage",
+    author: "Tinted Theming (https://github.com/tinted-theming)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/ayu-mirage.yaml",
+    base00: 0x00171b24,
+    base01: 0x001f2430,
+    base02: 0x00242936,
+    base03: 0x00707a8c,
+    base04: 0x008a9199,
+    base05: 0x00cccac2,
+    base06: 0x00d9d7ce,
+    base07: 0x00f3f4f5,
+    base08: 0x00f28779,
+    base09: 0x00ffa659,
+    base0a: 0x00ffd173,
+    base0b: 0x00d5ff80,
+    base0c: 0x0095e6cb,
+    base0d: 0x005ccfe6,
+    base0e: 0x00d4bfff,
+    base0f: 0x00f29e74,
+}
+
+#[cfg(feature = "schemes-ayu")]
+palette! {
+    pub AYU_LIGHT,
+    scheme: "Ayu Light",
+    author: "Tinted Theming (https://github.com/tinted-theming)",
+    slug: "https://github.com/tinted-theming/schemes/blob/spec-0.11/base16/ayu-light.yaml",
+    base00: 0x00f8f9fa,
+    base01: 0x00edeff1,
+    base02: 0x00d2d4d8,
+    base03: 0x00adaeb1,
+    base04: 0x008a8986,
+    base05: 0x005c6166,
+    base06: 0x004e5257,
+    base07: 0x00404447,
+    base08: 0x00f07171,
+    base09: 0x00fa8d3e,
+    base0a: 0x00f2ae49,
+    base0b: 0x006cbf43,
+    base0c: 0x004cbf99,
+    base0d: 0x00399ee6,
+    base0e: 0x00a37acc,
+    base0f: 0x00e6ba7e,
+}
+
+/// All palettes bundled with this crate.
+///
+/// Besides the base16 default schemes, Dracula, GitHub and Rosé Pine, the
+/// bundled families are each behind a `schemes-*` feature, such as
+/// `schemes-gruvbox`, so that only the enabled families are compiled in.
+/// All of them are enabled by default through `schemes-default`.
+pub static BUILTIN_PALETTES: &[Base16Palette] = &[
+    #[cfg(feature = "schemes-ayu")]
+    AYU_DARK,
+    #[cfg(feature = "schemes-ayu")]
+    AYU_LIGHT,
+    #[cfg(feature = "schemes-ayu")]
+    AYU_MIRAGE,
+    #[cfg(feature = "schemes-catppuccin")]
+    CATPPUCCIN_FRAPPE,
+    #[cfg(feature = "schemes-catppuccin")]
+    CATPPUCCIN_LATTE,
+    #[cfg(feature = "schemes-catppuccin")]
+    CATPPUCCIN_MACCHIATO,
+    #[cfg(feature = "schemes-catppuccin")]
+    CATPPUCCIN_MOCHA,
+    CUPCAKE,
+    DEFAULT_DARK,
+    DEFAULT_LIGHT,
+    DRACULA,
+    EIGHTIES,
+    #[cfg(feature = "schemes-everforest")]
+    EVERFOREST_DARK,
+    #[cfg(feature = "schemes-everforest")]
+    EVERFOREST_LIGHT,
+    GITHUB_LIGHT,
+    #[cfg(feature = "schemes-gruvbox")]
+    GRUVBOX_DARK,
+    #[cfg(feature = "schemes-gruvbox")]
+    GRUVBOX_DARK_HARD,
+    #[cfg(feature = "schemes-gruvbox")]
+    GRUVBOX_DARK_SOFT,
+    #[cfg(feature = "schemes-gruvbox")]
+    GRUVBOX_LIGHT,
+    #[cfg(feature = "schemes-gruvbox")]
+    GRUVBOX_LIGHT_HARD,
+    #[cfg(feature = "schemes-gruvbox")]
+    GRUVBOX_LIGHT_SOFT,
+    #[cfg(feature = "schemes-kanagawa")]
+    KANAGAWA,
+    MOCHA,
+    #[cfg(feature = "schemes-monokai")]
+    MONOKAI,
+    #[cfg(feature = "schemes-monokai")]
+    MONOKAI_PRO,
+    #[cfg(feature = "schemes-nord")]
+    NORD,
+    OCEAN,
+    #[cfg(feature = "schemes-one")]
+    ONE_DARK,
+    #[cfg(feature = "schemes-one")]
+    ONE_LIGHT,
+    ROSE_PINE,
+    ROSE_PINE_DAWN,
+    ROSE_PINE_MOON,
+    #[cfg(feature = "schemes-solarized")]
+    SOLARIZED_DARK,
+    #[cfg(feature = "schemes-solarized")]
+    SOLARIZED_LIGHT,
+    #[cfg(feature = "schemes-tokyo-night")]
+    TOKYO_NIGHT,
+    #[cfg(feature = "schemes-tokyo-night")]
+    TOKYO_NIGHT_LIGHT,
+    #[cfg(feature = "schemes-tokyo-night")]
+    TOKYO_NIGHT_STORM,
+];
+
+/// The builtin palettes by their scheme names and the names of their scheme
+/// files, normalized like [`PaletteRegistry::lookup`] does and sorted for
+/// [`Base16Palette::builtin`].
+#[cfg(feature = "std")]
+pub(crate) static BUILTIN_INDEX: &[(&str, &Base16Palette)] = &[
+    #[cfg(feature = "schemes-ayu")]
+    ("ayudark", &AYU_DARK),
+    #[cfg(feature = "schemes-ayu")]
+    ("ayulight", &AYU_LIGHT),
+    #[cfg(feature = "schemes-ayu")]
+    ("ayumirage", &AYU_MIRAGE),
+    #[cfg(feature = "schemes-catppuccin")]
+    ("catppuccinfrappe", &CATPPUCCIN_FRAPPE),
+    #[cfg(feature = "schemes-catppuccin")]
+    ("catppuccinlatte", &CATPPUCCIN_LATTE),
+    #[cfg(feature = "schemes-catppuccin")]
+    ("catppuccinmacchiato", &CATPPUCCIN_MACCHIATO),
+    #[cfg(feature = "schemes-catppuccin")]
+    ("catppuccinmocha", &CATPPUCCIN_MOCHA),
+    ("cupcake", &CUPCAKE),
+    ("defaultdark", &DEFAULT_DARK),
+    ("defaultlight", &DEFAULT_LIGHT),
+    ("dracula", &DRACULA),
+    ("eighties", &EIGHTIES),
+    #[cfg(feature = "schemes-everforest")]
+    ("everforest", &EVERFOREST_DARK),
+    #[cfg(feature = "schemes-everforest")]
+    ("everforestlight", &EVERFOREST_LIGHT),
+    ("github", &GITHUB_LIGHT),
+    #[cfg(feature = "schemes-gruvbox")]
+    ("gruvboxdarkhard", &GRUVBOX_DARK_HARD),
+    #[cfg(feature = "schemes-gruvbox")]
+    ("gruvboxdarkmedium", &GRUVBOX_DARK),
+    #[cfg(feature = "schemes-gruvbox")]
+    ("gruvboxdarksoft", &GRUVBOX_DARK_SOFT),
+    #[cfg(feature = "schemes-gruvbox")]
+    ("gruvboxlighthard", &GRUVBOX_LIGHT_HARD),
+    #[cfg(feature = "schemes-gruvbox")]
+    ("gruvboxlightmedium", &GRUVBOX_LIGHT),
+    #[cfg(feature = "schemes-gruvbox")]
+    ("gruvboxlightsoft", &GRUVBOX_LIGHT_SOFT),
+    #[cfg(feature = "schemes-kanagawa")]
+    ("kanagawa", &KANAGAWA),
+    ("mocha", &MOCHA),
+    #[cfg(feature = "schemes-monokai")]
+    ("monokai", &MONOKAI),
+    #[cfg(feature = "schemes-monokai")]
+    ("monokaipro", &MONOKAI_PRO),
+    #[cfg(feature = "schemes-nord")]
+    ("nord", &NORD),
+    ("ocean", &OCEAN),
+    #[cfg(feature = "schemes-one")]
+    ("onedark", &ONE_DARK),
+    #[cfg(feature = "schemes-one")]
+    ("onelight", &ONE_LIGHT),
+    ("rosepine", &ROSE_PINE),
+    ("rosepinedawn", &ROSE_PINE_DAWN),
+    ("rosepinemoon", &ROSE_PINE_MOON),
+    #[cfg(feature = "schemes-solarized")]
+    ("solarizeddark", &SOLARIZED_DARK),
+    #[cfg(feature = "schemes-solarized")]
+    ("solarizedlight", &SOLARIZED_LIGHT),
+    #[cfg(feature = "schemes-tokyo-night")]
+    ("tokyonightdark", &TOKYO_NIGHT),
+    #[cfg(feature = "schemes-tokyo-night")]
+    ("tokyonightlight", &TOKYO_NIGHT_LIGHT),
+    #[cfg(feature = "schemes-tokyo-night")]
+    ("tokyonightstorm", &TOKYO_NIGHT_STORM),
+];
+
+#[cfg(all(test, feature = "serde"))]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "fs")]
     #[test]
     fn read_from_yaml() {
         let mut file = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         file.push("./.config/dracula.yaml");
-        let _ = Base16Palette::from_yaml(file).unwrap();
+        let palette = Base16Palette::from_yaml(file).unwrap();
+        assert_eq!(palette.name, "Dracula");
+        assert_eq!(palette.author, DRACULA.author);
 
         let mut file = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         file.push("./.config/github.yaml");
         let _ = Base16Palette::from_yaml(file).unwrap();
     }
+
+    #[cfg(all(feature = "macros", feature = "fs"))]
+    #[test]
+    fn embed_scheme_at_compile_time() {
+        const EMBEDDED: Base16Palette = crate::base16!(".config/dracula.yaml");
+        let mut file = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        file.push(".config/dracula.yaml");
+        assert_eq!(EMBEDDED, Base16Palette::from_yaml(file).unwrap());
+
+        const NOTATIONS: Base16Palette = crate::base16!(".config/notations.yaml");
+        let mut file = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        file.push(".config/notations.yaml");
+        assert_eq!(NOTATIONS, Base16Palette::from_yaml(file).unwrap());
+        assert_eq!(NOTATIONS.base0c, Color::Rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn read_both_slot_name_spellings() {
+        let uppercase = DRACULA.to_yaml().unwrap();
+        assert!(uppercase.contains("base0A:"));
+        let lowercase = uppercase.replace("base0A:", "base0a:");
+        for yaml in [uppercase, lowercase] {
+            let palette: Base16Palette = serde_yaml::from_str(&yaml).unwrap();
+            assert_eq!(palette.base0a, DRACULA.base0a);
+        }
+    }
+
+    #[test]
+    fn read_integer_and_channel_colors() {
+        let toml = DRACULA
+            .to_toml()
+            .unwrap()
+            .replace("\"#282936\"", "0x282936")
+            .replace("\"#3a3c4e\"", "3816526");
+        let palette: Base16Palette = toml::from_str(&toml).unwrap();
+        assert_eq!(palette, DRACULA);
+
+        let toml = toml
+            .replace("0x282936", "[40, 41, 54]")
+            .replace("\"#4d4f68\"", "{ r = 0x4d, g = 0x4f, b = 0x68 }");
+        let palette: Base16Palette = toml::from_str(&toml).unwrap();
+        assert_eq!(palette, DRACULA);
+
+        let toml = toml.replace("[40, 41, 54]", "0x1000000");
+        assert!(toml::from_str::<Base16Palette>(&toml).is_err());
+    }
 }
@@ -0,0 +1,722 @@
+//! Loading palettes from scheme files and strings and classifying the
+//! failures.
+
+use std::borrow::Cow;
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::{de::Slot, Base16Color, Base16Palette, Base16PaletteError};
+
+impl Base16Palette {
+    /// Parses a `Base16Palette` from the contents of a YAML scheme file.
+    ///
+    /// Unlike [`Base16Palette::from_yaml`] this doesn't touch the file
+    /// system, so it also works on targets without one, such as
+    /// `wasm32-unknown-unknown`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::InvalidColor`] or
+    /// [`Base16PaletteError::MissingField`] for bad or missing slots and
+    /// [`Base16PaletteError::ExtractionFailed`] if `yaml` can't be parsed.
+    ///
+    /// Unquoted colors such as `base00: 282828` are read as the hex digits
+    /// they are written with, although YAML itself parses them as decimal
+    /// numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::Base16Palette;
+    /// let yaml = include_str!("../.config/dracula.yaml");
+    /// let palette = Base16Palette::from_yaml_str(yaml).unwrap();
+    /// assert_eq!(palette.name, "Dracula");
+    /// ```
+    pub fn from_yaml_str(yaml: &str) -> Result<Self, Base16PaletteError> {
+        parse(yaml, Format::Yaml, LoadOptions::default()).map_err(|failure| *failure.error)
+    }
+
+    /// Parses a `Base16Palette` from the contents of a TOML scheme file.
+    ///
+    /// See [`Base16Palette::from_yaml_str`] for details.
+    pub fn from_toml_str(toml: &str) -> Result<Self, Base16PaletteError> {
+        parse(toml, Format::Toml, LoadOptions::default()).map_err(|failure| *failure.error)
+    }
+
+    /// Parses a `Base16Palette` from the contents of a JSON scheme file, as
+    /// written by [`Base16Palette::to_json`].
+    ///
+    /// See [`Base16Palette::from_yaml_str`] for details.
+    pub fn from_json_str(json: &str) -> Result<Self, Base16PaletteError> {
+        parse(json, Format::Json, LoadOptions::default()).map_err(|failure| *failure.error)
+    }
+
+    /// Parses a `Base16Palette` from the UTF-8 encoded contents of a YAML
+    /// scheme file, such as the body of a fetch response.
+    ///
+    /// See [`Base16Palette::from_yaml_str`] for details.
+    pub fn from_yaml_bytes(yaml: &[u8]) -> Result<Self, Base16PaletteError> {
+        Self::from_yaml_str(utf8(yaml)?)
+    }
+
+    /// Parses a `Base16Palette` from the UTF-8 encoded contents of a TOML
+    /// scheme file.
+    ///
+    /// See [`Base16Palette::from_yaml_str`] for details.
+    pub fn from_toml_bytes(toml: &[u8]) -> Result<Self, Base16PaletteError> {
+        Self::from_toml_str(utf8(toml)?)
+    }
+}
+
+#[cfg(feature = "fs")]
+impl Base16Palette {
+    /// Loads a `Base16Palette` from a YAML, TOML or JSON file, picking the
+    /// format from the file extension.
+    ///
+    /// Files ending in `.yaml` or `.yml` are read with
+    /// [`Base16Palette::from_yaml`], files ending in `.toml` with
+    /// [`Base16Palette::from_toml`] and files ending in `.json` as JSON, see
+    /// [`Base16Palette::from_json_str`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::UnsupportedFormat`] for any other
+    /// extension, and otherwise the same errors as the format-specific
+    /// loaders.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::Base16Palette;
+    /// let palette = Base16Palette::from_file(".config/dracula.yaml").unwrap();
+    /// assert_eq!(palette.name, "Dracula");
+    /// ```
+    pub fn from_file(file: impl AsRef<Path>) -> Result<Self, Base16PaletteError> {
+        Self::from_file_with(file, LoadOptions::default())
+    }
+
+    /// Loads a `Base16Palette` from a YAML file with the given
+    /// [`LoadOptions`].
+    ///
+    /// # Errors
+    ///
+    /// With [`LoadOptions::strict`] set, returns
+    /// [`Base16PaletteError::UnknownField`] or
+    /// [`Base16PaletteError::MissingField`] (wrapped in
+    /// [`Base16PaletteError::InFile`]) for files that don't follow the spec,
+    /// in addition to the errors returned by [`Base16Palette::from_yaml`].
+    pub fn from_yaml_with(
+        file: impl AsRef<Path>,
+        options: LoadOptions,
+    ) -> Result<Self, Base16PaletteError> {
+        load(file.as_ref(), Format::Yaml, options)
+    }
+
+    /// Loads a `Base16Palette` from a TOML file with the given
+    /// [`LoadOptions`].
+    ///
+    /// See [`Base16Palette::from_yaml_with`] for details.
+    pub fn from_toml_with(
+        file: impl AsRef<Path>,
+        options: LoadOptions,
+    ) -> Result<Self, Base16PaletteError> {
+        load(file.as_ref(), Format::Toml, options)
+    }
+
+    /// Loads a `Base16Palette` from a YAML, TOML or JSON file with the given
+    /// [`LoadOptions`], picking the format from the file extension.
+    ///
+    /// See [`Base16Palette::from_file`] and [`Base16Palette::from_yaml_with`]
+    /// for details.
+    pub fn from_file_with(
+        file: impl AsRef<Path>,
+        options: LoadOptions,
+    ) -> Result<Self, Base16PaletteError> {
+        let file = file.as_ref();
+        let extension = file
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+        match Format::from_extension(extension) {
+            Some(format) => load(file, format, options),
+            None => Err(Base16PaletteError::UnsupportedFormat(extension.to_string())),
+        }
+    }
+
+    /// Loads a `Base16Palette` from a YAML file, taking any slot that is
+    /// missing or cannot be parsed from `fallback`.
+    ///
+    /// Returns the palette together with the slots that were filled in from
+    /// the fallback, in slot order, so callers can warn about them. The
+    /// scheme name, author and slug are always read from the file.
+    ///
+    /// # Errors
+    ///
+    /// Only errors that leave no palette to work with are reported: a missing
+    /// file, a file that isn't valid YAML or unreadable metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Palette, DEFAULT_DARK};
+    /// let (palette, defaulted) =
+    ///     Base16Palette::from_yaml_lenient(".config/dracula.yaml", &DEFAULT_DARK).unwrap();
+    /// assert_eq!(palette.name, "Dracula");
+    /// assert!(defaulted.is_empty());
+    /// ```
+    pub fn from_yaml_lenient(
+        file: impl AsRef<Path>,
+        fallback: &Base16Palette,
+    ) -> Result<(Self, Vec<Base16Color>), Base16PaletteError> {
+        load_lenient(file.as_ref(), Format::Yaml, fallback)
+    }
+
+    /// Loads a `Base16Palette` from a TOML file, taking any slot that is
+    /// missing or cannot be parsed from `fallback`.
+    ///
+    /// See [`Base16Palette::from_yaml_lenient`] for details.
+    pub fn from_toml_lenient(
+        file: impl AsRef<Path>,
+        fallback: &Base16Palette,
+    ) -> Result<(Self, Vec<Base16Color>), Base16PaletteError> {
+        load_lenient(file.as_ref(), Format::Toml, fallback)
+    }
+}
+
+/// Options controlling how palette files are loaded.
+///
+/// The default options are permissive: unknown keys are ignored and the
+/// scheme metadata may be left out. Use [`Base16Palette::from_yaml_with`],
+/// [`Base16Palette::from_toml_with`] or [`Base16Palette::from_file_with`] to
+/// load a file with other options.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{Base16Palette, LoadOptions};
+/// # #[cfg(feature = "fs")]
+/// # {
+/// let palette =
+///     Base16Palette::from_yaml_with(".config/dracula.yaml", LoadOptions::strict()).unwrap();
+/// assert_eq!(palette.name, "Dracula");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct LoadOptions {
+    /// Validates the file exactly against the base16 spec.
+    ///
+    /// Strict loading rejects keys other than the sixteen slots and the
+    /// `scheme`, `author`, `slug`, `variant`, `description` and `system`
+    /// metadata, and requires a non-empty `scheme` and `author`.
+    pub strict: bool,
+}
+
+impl LoadOptions {
+    /// Returns the options with `strict` loading turned on.
+    pub const fn strict() -> Self {
+        Self { strict: true }
+    }
+}
+
+/// The keys accepted at the top level of a scheme file by strict loading.
+const KNOWN_KEYS: &[&str] = &[
+    "scheme",
+    "name",
+    "author",
+    "slug",
+    "variant",
+    "description",
+    "system",
+];
+
+/// The formats scheme files are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl Format {
+    #[cfg(feature = "fs")]
+    fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Returns whether `file` has the extension of a scheme format that
+/// [`Base16Palette::from_file`] can read.
+#[cfg(feature = "fs")]
+pub(crate) fn is_scheme_file(file: &Path) -> bool {
+    file.extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(Format::from_extension)
+        .is_some()
+}
+
+/// An error together with the key and position in the source text it
+/// refers to, which become [`Base16PaletteError::InFile`] context when
+/// loading files.
+#[cfg_attr(not(feature = "fs"), allow(dead_code))]
+pub(crate) struct Failure {
+    error: Box<Base16PaletteError>,
+    key: Option<String>,
+    position: Option<(usize, Option<usize>)>,
+}
+
+impl Failure {
+    pub(crate) fn at(key: &str, error: Base16PaletteError) -> Self {
+        Self {
+            error: Box::new(error),
+            key: Some(key.to_string()),
+            position: None,
+        }
+    }
+
+    #[cfg(feature = "fs")]
+    pub(crate) fn in_file(self, file: &Path, text: &str) -> Base16PaletteError {
+        let position = self.position.or_else(|| {
+            let name = self.key.as_deref()?.rsplit('.').next()?;
+            find_key(text, name)
+        });
+        Base16PaletteError::InFile {
+            path: file.to_path_buf(),
+            key: self.key,
+            line: position.map(|(line, _)| line),
+            column: position.and_then(|(_, column)| column),
+            source: self.error,
+        }
+    }
+}
+
+impl From<Base16PaletteError> for Failure {
+    fn from(error: Base16PaletteError) -> Self {
+        Self {
+            error: Box::new(error),
+            key: None,
+            position: None,
+        }
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<&str, Base16PaletteError> {
+    std::str::from_utf8(bytes).map_err(|error| Base16PaletteError::ExtractionFailed(error.into()))
+}
+
+/// Reads a YAML scheme file.
+#[cfg(feature = "fs")]
+pub(crate) fn yaml(file: &Path, options: LoadOptions) -> Result<Base16Palette, Base16PaletteError> {
+    load(file, Format::Yaml, options)
+}
+
+/// Reads a TOML scheme file.
+#[cfg(feature = "fs")]
+pub(crate) fn toml(file: &Path, options: LoadOptions) -> Result<Base16Palette, Base16PaletteError> {
+    load(file, Format::Toml, options)
+}
+
+#[cfg(feature = "fs")]
+fn load(
+    file: &Path,
+    format: Format,
+    options: LoadOptions,
+) -> Result<Base16Palette, Base16PaletteError> {
+    let text = read(file)?;
+    parse(&text, format, options).map_err(|failure| failure.in_file(file, &text))
+}
+
+#[cfg(feature = "fs")]
+fn load_lenient(
+    file: &Path,
+    format: Format,
+    fallback: &Base16Palette,
+) -> Result<(Base16Palette, Vec<Base16Color>), Base16PaletteError> {
+    let text = read(file)?;
+    table(&text, format)
+        .and_then(|table| lenient(&table, fallback))
+        .map_err(|failure| failure.in_file(file, &text))
+}
+
+#[cfg(feature = "fs")]
+pub(crate) fn read(file: &Path) -> Result<String, Base16PaletteError> {
+    if !file.is_file() {
+        return Err(Base16PaletteError::FileNotFound(file.to_path_buf()));
+    }
+    Ok(std::fs::read_to_string(file)?)
+}
+
+fn parse(text: &str, format: Format, options: LoadOptions) -> Result<Base16Palette, Failure> {
+    let table = table(text, format)?;
+    if options.strict {
+        check_strict(&table)?;
+    }
+    let mut palette = metadata(&table)?;
+    for slot in Base16Color::ALL {
+        let Some(value) = find_slot(&table, slot) else {
+            let field = slot.field_name();
+            return Err(Failure::at(field, Base16PaletteError::MissingField(field)));
+        };
+        let Slot(color, alpha) = read_slot(slot, value)?;
+        *palette.color_mut(slot) = color;
+        palette.alpha[slot as usize] = alpha;
+    }
+    Ok(palette)
+}
+
+#[cfg(feature = "fs")]
+fn lenient(
+    table: &Map<String, Value>,
+    fallback: &Base16Palette,
+) -> Result<(Base16Palette, Vec<Base16Color>), Failure> {
+    let mut palette = Base16Palette {
+        alpha: fallback.alpha,
+        ..metadata(table)?
+    };
+    let mut defaulted = Vec::new();
+    for slot in Base16Color::ALL {
+        let color = find_slot(table, slot).and_then(|value| read_slot(slot, value).ok());
+        match color {
+            Some(Slot(color, alpha)) => {
+                *palette.color_mut(slot) = color;
+                palette.alpha[slot as usize] = alpha;
+            }
+            None => {
+                *palette.color_mut(slot) = fallback.color(slot);
+                defaulted.push(slot);
+            }
+        }
+    }
+    Ok((palette, defaulted))
+}
+
+/// Parses the text of a scheme file into its top-level table.
+pub(crate) fn table(text: &str, format: Format) -> Result<Map<String, Value>, Failure> {
+    let syntax = |error: Box<dyn std::error::Error + Send + Sync>, position| Failure {
+        error: Box::new(Base16PaletteError::ExtractionFailed(error)),
+        key: None,
+        position,
+    };
+    let value = match format {
+        Format::Yaml => {
+            let value = serde_yaml::from_str::<serde_yaml::Value>(text).map_err(|error| {
+                let position = error
+                    .location()
+                    .map(|location| (location.line(), Some(location.column())));
+                syntax(error.into(), position)
+            })?;
+            let mut value =
+                serde_json::to_value(value).map_err(|error| syntax(error.into(), None))?;
+            if let Value::Object(table) = &mut value {
+                ratatui_base16_yaml::hex_integers(text, table, |key| {
+                    key.parse::<Base16Color>().is_ok()
+                });
+            }
+            value
+        }
+        Format::Toml => {
+            let value = toml::from_str::<toml::Table>(text).map_err(|error| {
+                let position = error.span().map(|span| position_of(text, span.start));
+                syntax(error.into(), position)
+            })?;
+            serde_json::to_value(value).map_err(|error| syntax(error.into(), None))?
+        }
+        Format::Json => serde_json::from_str::<Value>(text).map_err(|error| {
+            let position = (error.line() > 0).then(|| (error.line(), Some(error.column())));
+            syntax(error.into(), position)
+        })?,
+    };
+    match value {
+        Value::Object(table) => Ok(table),
+        Value::Null => Ok(Map::new()),
+        value => Err(syntax(
+            format!("expected a table of colors, found {}", describe(&value)).into(),
+            None,
+        )),
+    }
+}
+
+/// Rejects unknown keys and missing metadata.
+fn check_strict(table: &Map<String, Value>) -> Result<(), Failure> {
+    let unknown = table
+        .keys()
+        .find(|key| !KNOWN_KEYS.contains(&key.as_str()) && key.parse::<Base16Color>().is_err());
+    if let Some(key) = unknown {
+        return Err(Failure::at(
+            key,
+            Base16PaletteError::UnknownField(key.clone()),
+        ));
+    }
+    for field in ["scheme", "author"] {
+        let present = table
+            .get(field)
+            .or_else(|| (field == "scheme").then(|| table.get("name")).flatten())
+            .and_then(Value::as_str)
+            .is_some_and(|value| !value.is_empty());
+        if !present {
+            return Err(Failure::at(field, Base16PaletteError::MissingField(field)));
+        }
+    }
+    Ok(())
+}
+
+/// Reads the scheme name, author and slug into an otherwise default
+/// palette.
+fn metadata(table: &Map<String, Value>) -> Result<Base16Palette, Failure> {
+    let text = |keys: &[&'static str]| -> Result<Cow<'static, str>, Failure> {
+        match keys
+            .iter()
+            .find_map(|key| table.get(*key).map(|value| (*key, value)))
+        {
+            None => Ok(Cow::Borrowed("")),
+            Some((_, Value::String(value))) => Ok(Cow::Owned(value.clone())),
+            Some((key, value)) => Err(Failure::at(
+                key,
+                Base16PaletteError::ExtractionFailed(
+                    format!("`{key}` must be a string, found {value}").into(),
+                ),
+            )),
+        }
+    };
+    Ok(Base16Palette {
+        name: text(&["scheme", "name"])?,
+        author: text(&["author"])?,
+        slug: text(&["slug"])?,
+        ..Base16Palette::default()
+    })
+}
+
+/// Finds the value of `slot`, spelled either as `base0a` or `base0A`.
+fn find_slot(table: &Map<String, Value>, slot: Base16Color) -> Option<&Value> {
+    [slot.field_name(), slot.name()]
+        .into_iter()
+        .find_map(|key| table.get(key))
+}
+
+fn read_slot(slot: Base16Color, value: &Value) -> Result<Slot, Failure> {
+    Slot::deserialize(value).map_err(|error| {
+        let field = slot.field_name();
+        Failure::at(
+            field,
+            Base16PaletteError::InvalidColor {
+                field,
+                value: describe(value),
+                source: Box::new(error),
+            },
+        )
+    })
+}
+
+/// Finds the line and column at which a top-level `key` is defined, for
+/// both `key: value` and `key = value` syntaxes.
+fn find_key(text: &str, key: &str) -> Option<(usize, Option<usize>)> {
+    text.lines().enumerate().find_map(|(index, line)| {
+        let trimmed = line.trim_start();
+        let unquoted = trimmed.trim_start_matches(['"', '\'']);
+        let name = unquoted.get(..key.len())?;
+        let rest = unquoted[key.len()..].trim_start_matches(['"', '\'']);
+        let defines = name.eq_ignore_ascii_case(key)
+            && (rest.trim_start().starts_with(':') || rest.trim_start().starts_with('='));
+        defines.then(|| (index + 1, Some(line.len() - trimmed.len() + 1)))
+    })
+}
+
+/// Converts a byte offset into `text` into a 1-based line and column.
+pub(crate) fn position_of(text: &str, offset: usize) -> (usize, Option<usize>) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    (line, Some(column))
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use std::fs;
+
+    use ratatui::style::Color;
+
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn palettes_parse_from_strings_and_bytes() {
+        let yaml = crate::DRACULA.to_yaml().unwrap();
+        assert_eq!(Base16Palette::from_yaml_str(&yaml).unwrap(), crate::DRACULA);
+        let toml = crate::DRACULA.to_toml().unwrap();
+        assert_eq!(
+            Base16Palette::from_toml_bytes(toml.as_bytes()).unwrap(),
+            crate::DRACULA
+        );
+        let json = crate::DRACULA.to_json().unwrap();
+        assert_eq!(Base16Palette::from_json_str(&json).unwrap(), crate::DRACULA);
+
+        let unquoted = yaml
+            .replace("'#282936'", "282936 # comment")
+            .replace("'#3a3c4e'", "073642");
+        let palette = Base16Palette::from_yaml_str(&unquoted).unwrap();
+        assert_eq!(palette.base00, Color::Rgb(0x28, 0x29, 0x36));
+        assert_eq!(palette.base01, Color::Rgb(0x07, 0x36, 0x42));
+        let described = format!("description: |\n  base00: was 111111\n{unquoted}");
+        let palette = Base16Palette::from_yaml_str(&described).unwrap();
+        assert_eq!(palette.base00, Color::Rgb(0x28, 0x29, 0x36));
+
+        let error = Base16Palette::from_yaml_str(&yaml.replace("#ea51b2", "nope")).unwrap_err();
+        assert!(matches!(
+            error,
+            Base16PaletteError::InvalidColor {
+                field: "base08",
+                ..
+            }
+        ));
+        assert!(matches!(
+            Base16Palette::from_yaml_bytes(b"scheme: \xff"),
+            Err(Base16PaletteError::ExtractionFailed(_))
+        ));
+    }
+
+    #[test]
+    fn missing_files_are_reported() {
+        let error = Base16Palette::from_yaml("does/not/exist.yaml").unwrap_err();
+        assert_eq!(error.to_string(), "file not found: does/not/exist.yaml");
+        assert!(
+            matches!(error, Base16PaletteError::FileNotFound(path) if path.ends_with("exist.yaml"))
+        );
+        assert!(matches!(
+            Base16Palette::from_file("scheme.ini"),
+            Err(Base16PaletteError::UnsupportedFormat(extension)) if extension == "ini"
+        ));
+    }
+
+    #[test]
+    fn bad_colors_and_missing_slots_are_reported() {
+        let dir = TempDir::new("load");
+
+        let yaml = crate::DRACULA.to_yaml().unwrap();
+        let typo = dir.join("typo.yaml");
+        fs::write(&typo, yaml.replace("#ea51b2", "#ea51zz")).unwrap();
+        match Base16Palette::from_yaml(&typo).unwrap_err().root() {
+            Base16PaletteError::InvalidColor { field, value, .. } => {
+                assert_eq!((*field, value.as_str()), ("base08", "#ea51zz"));
+            }
+            error => panic!("unexpected error: {error}"),
+        }
+
+        let missing = dir.join("missing.yaml");
+        let without_base0d = yaml
+            .lines()
+            .filter(|line| !line.starts_with("base0D"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&missing, without_base0d).unwrap();
+        assert!(matches!(
+            Base16Palette::from_file(&missing).unwrap_err().root(),
+            Base16PaletteError::MissingField("base0d")
+        ));
+    }
+
+    #[test]
+    fn lenient_loading_fills_broken_slots() {
+        let dir = TempDir::new("lenient");
+
+        let file = dir.join("broken.yaml");
+        let yaml = crate::DRACULA
+            .to_yaml()
+            .unwrap()
+            .replace("#ea51b2", "nope")
+            .replace("base0A:", "base0a:");
+        let yaml = yaml
+            .lines()
+            .filter(|line| !line.starts_with("base0F"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&file, yaml).unwrap();
+
+        let (palette, defaulted) =
+            Base16Palette::from_yaml_lenient(&file, &crate::DEFAULT_DARK).unwrap();
+        assert_eq!(defaulted, [Base16Color::Base08, Base16Color::Base0F]);
+        assert_eq!(palette.name, "Dracula");
+        assert_eq!(palette.base00, crate::DRACULA.base00);
+        assert_eq!(palette.base08, crate::DEFAULT_DARK.base08);
+    }
+
+    #[test]
+    fn strict_loading_rejects_unknown_keys_and_missing_metadata() {
+        let dir = TempDir::new("strict");
+        let strict = LoadOptions::strict();
+
+        let yaml = crate::DRACULA.to_yaml().unwrap();
+        let file = dir.join("exact.yaml");
+        fs::write(&file, &yaml).unwrap();
+        assert!(Base16Palette::from_yaml_with(&file, strict).is_ok());
+
+        let file = dir.join("extra.yaml");
+        fs::write(&file, format!("{yaml}base10: '#000000'\n")).unwrap();
+        assert!(Base16Palette::from_yaml(&file).is_ok());
+        let error = Base16Palette::from_yaml_with(&file, strict).unwrap_err();
+        assert!(matches!(error.root(), Base16PaletteError::UnknownField(key) if key == "base10"));
+        assert!(matches!(
+            error,
+            Base16PaletteError::InFile { line: Some(21), .. }
+        ));
+
+        let file = dir.join("anonymous.yaml");
+        fs::write(&file, yaml.replace("author:", "authors:")).unwrap();
+        let error = Base16Palette::from_file_with(&file, strict).unwrap_err();
+        assert!(matches!(error.root(), Base16PaletteError::UnknownField(key) if key == "authors"));
+        let without_author = yaml
+            .lines()
+            .filter(|line| !line.starts_with("author"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&file, without_author).unwrap();
+        let error = Base16Palette::from_file_with(&file, strict).unwrap_err();
+        assert!(matches!(
+            error.root(),
+            Base16PaletteError::MissingField("author")
+        ));
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_line() {
+        let dir = TempDir::new("location");
+
+        let file = dir.join("typo.toml");
+        fs::write(
+            &file,
+            crate::DRACULA.to_toml().unwrap().replace("#ea51b2", "nope"),
+        )
+        .unwrap();
+        let error = Base16Palette::from_toml(&file).unwrap_err();
+        let Base16PaletteError::InFile { key, line, .. } = &error else {
+            panic!("unexpected error: {error}");
+        };
+        assert_eq!(key.as_deref(), Some("base08"));
+        assert_eq!(*line, Some(13));
+        assert!(error
+            .to_string()
+            .starts_with(&format!("{}:13:1: ", file.display())));
+
+        let file = dir.join("syntax.yaml");
+        fs::write(&file, "scheme: Broken\nbase00: [\n").unwrap();
+        let error = Base16Palette::from_yaml(&file).unwrap_err();
+        assert!(matches!(
+            error,
+            Base16PaletteError::InFile { line: Some(_), .. }
+        ));
+    }
+}
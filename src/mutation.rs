@@ -0,0 +1,215 @@
+//! Immutable setters and guarded mutation of palettes.
+
+use ratatui::style::Color;
+
+#[cfg(feature = "std")]
+use crate::Base16PaletteError;
+use crate::{Base16Color, Base16Palette};
+
+macro_rules! with_setters {
+    ($($with:ident => $field:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Returns a copy of the palette with `", stringify!($field), "` replaced.")]
+            #[must_use = "method returns a new palette and does not modify the original"]
+            pub fn $with(mut self, color: Color) -> Self {
+                self.$field = color;
+                self
+            }
+        )*
+    };
+}
+
+impl Base16Palette {
+    with_setters! {
+        with_base00 => base00,
+        with_base01 => base01,
+        with_base02 => base02,
+        with_base03 => base03,
+        with_base04 => base04,
+        with_base05 => base05,
+        with_base06 => base06,
+        with_base07 => base07,
+        with_base08 => base08,
+        with_base09 => base09,
+        with_base0a => base0a,
+        with_base0b => base0b,
+        with_base0c => base0c,
+        with_base0d => base0d,
+        with_base0e => base0e,
+        with_base0f => base0f,
+    }
+
+    /// Returns a copy of the palette with the color of `slot` replaced.
+    #[must_use = "method returns a new palette and does not modify the original"]
+    pub fn with_color(mut self, slot: Base16Color, color: Color) -> Self {
+        *self.color_mut(slot) = color;
+        self
+    }
+
+    /// Returns a copy of the palette whose default background (base00) is
+    /// `Color::Reset`, so the terminal's own background shows through.
+    ///
+    /// All other slots, including the lighter backgrounds base01 and base02
+    /// used for status bars and selections, are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::DRACULA;
+    /// let palette = DRACULA.with_transparent_background();
+    /// assert_eq!(palette.base00, Color::Reset);
+    /// assert_eq!(palette.base01, DRACULA.base01);
+    /// ```
+    #[must_use = "method returns a new palette and does not modify the original"]
+    pub fn with_transparent_background(self) -> Self {
+        self.with_base00(Color::Reset)
+    }
+
+    /// Replaces the color of `slot` like [`set`](Self::set), but only if the
+    /// resulting palette still satisfies `invariants`.
+    ///
+    /// This is useful for runtime customization, such as letting users pick
+    /// an accent color, without ending up with unreadable text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::InvariantViolated`] and leaves the
+    /// palette unchanged if the new color breaks one of the invariants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::{Base16Color, Invariants, DEFAULT_DARK};
+    /// let mut palette = DEFAULT_DARK;
+    /// let invariants = Invariants::default();
+    /// assert!(palette
+    ///     .set_checked(Base16Color::Base0D, Color::Rgb(0x40, 0xa0, 0xff), &invariants)
+    ///     .is_ok());
+    /// // Nearly black text on a nearly black background is rejected.
+    /// assert!(palette
+    ///     .set_checked(Base16Color::Base08, Color::Rgb(0x20, 0x20, 0x20), &invariants)
+    ///     .is_err());
+    /// assert_eq!(palette.base08, DEFAULT_DARK.base08);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_checked(
+        &mut self,
+        slot: Base16Color,
+        color: Color,
+        invariants: &Invariants,
+    ) -> Result<Color, Base16PaletteError> {
+        let candidate = self.clone().with_color(slot, color);
+        invariants.check(&candidate, slot)?;
+        self.set(slot, color)
+    }
+}
+
+/// Guard rails checked by [`Base16Palette::set_checked`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Invariants {
+    /// The minimum WCAG contrast ratio of the default foreground (base05) and
+    /// the accents (base08 to base0F) against the background (base00).
+    pub min_contrast: Option<f64>,
+    /// Whether the shades base00 to base07 must be ordered by luminance, from
+    /// dark to light for dark schemes and light to dark for light schemes.
+    pub monotonic_shades: bool,
+}
+
+#[cfg(feature = "std")]
+impl Default for Invariants {
+    /// Requires a contrast of at least 3:1 and ordered shades.
+    fn default() -> Self {
+        Self {
+            min_contrast: Some(3.0),
+            monotonic_shades: true,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Invariants {
+    /// Checks the invariants affected by a change to `changed`.
+    fn check(
+        &self,
+        palette: &Base16Palette,
+        changed: Base16Color,
+    ) -> Result<(), Base16PaletteError> {
+        let violation = |reason: String| Base16PaletteError::InvariantViolated {
+            slot: changed,
+            reason,
+        };
+
+        if let Some(min_contrast) = self.min_contrast {
+            let foregrounds = core::iter::once(Base16Color::Base05)
+                .chain(palette.accents().map(|(slot, _)| slot))
+                .filter(|slot| changed == Base16Color::Base00 || *slot == changed);
+            for slot in foregrounds {
+                if let Some(ratio) = palette.contrast(slot).filter(|r| *r < min_contrast) {
+                    return Err(violation(format!(
+                        "contrast of {slot} against base00 is {ratio:.2}, below {min_contrast:.2}"
+                    )));
+                }
+            }
+        }
+
+        if self.monotonic_shades && (changed as usize) < 8 {
+            if let Some((a, b)) = palette.unordered_shades() {
+                return Err(violation(format!(
+                    "shades {a} and {b} are not ordered by luminance"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn with_setters_leave_the_original_untouched() {
+        let palette = DEFAULT_DARK.with_base08(Color::Red);
+        assert_eq!(palette.base08, Color::Red);
+        assert_eq!(
+            DEFAULT_DARK
+                .with_color(Base16Color::Base08, Color::Red)
+                .base08,
+            Color::Red
+        );
+        assert_ne!(DEFAULT_DARK.base08, Color::Red);
+    }
+
+    #[test]
+    fn unordered_shades_are_rejected() {
+        let mut palette = DEFAULT_DARK;
+        let result = palette.set_checked(
+            Base16Color::Base02,
+            Color::Rgb(0xf0, 0xf0, 0xf0),
+            &Invariants::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(Base16PaletteError::InvariantViolated {
+                slot: Base16Color::Base02,
+                ..
+            })
+        ));
+        let unchecked = Invariants {
+            min_contrast: None,
+            monotonic_shades: false,
+        };
+        assert!(palette
+            .set_checked(
+                Base16Color::Base02,
+                Color::Rgb(0xf0, 0xf0, 0xf0),
+                &unchecked
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,430 @@
+//! A collection of palettes looked up by name.
+
+use std::collections::BTreeMap;
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "fs")]
+use crate::load;
+use crate::{Base16Palette, Base16PaletteError, Variant, BUILTIN_INDEX, BUILTIN_PALETTES};
+
+/// A set of palettes keyed by their scheme name, such as the schemes found
+/// in a user's theme directory.
+///
+/// Palettes are kept sorted by name, which makes the registry a natural
+/// source for theme browsers and pickers.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{PaletteRegistry, DRACULA};
+/// let registry = PaletteRegistry::builtin();
+/// assert_eq!(registry.get("Dracula"), Some(&DRACULA));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaletteRegistry {
+    palettes: BTreeMap<String, Base16Palette>,
+}
+
+impl PaletteRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry holding all [`BUILTIN_PALETTES`].
+    pub fn builtin() -> Self {
+        BUILTIN_PALETTES.iter().cloned().collect()
+    }
+
+    /// Adds `palette` under its scheme name, returning the palette it
+    /// replaces, if any.
+    pub fn insert(&mut self, palette: Base16Palette) -> Option<Base16Palette> {
+        self.palettes.insert(palette.name.to_string(), palette)
+    }
+
+    /// Removes the palette named `name`.
+    pub fn remove(&mut self, name: &str) -> Option<Base16Palette> {
+        self.palettes.remove(name)
+    }
+
+    /// Returns the palette named exactly `name`.
+    pub fn get(&self, name: &str) -> Option<&Base16Palette> {
+        self.palettes.get(name)
+    }
+
+    /// Looks up a palette by a name typed by a user.
+    ///
+    /// Names are compared ignoring case, accents, spaces and punctuation, so
+    /// `"rosepine dawn"`, `"rose-pine-dawn"` and `"Rosé Pine Dawn"` all find
+    /// the same palette. Failing that, a name that is the only one starting
+    /// with, containing or spelled close to `query` is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::PaletteNotFound`] with the ranked
+    /// candidates from [`PaletteRegistry::search`] if no palette or more
+    /// than one palette matches equally well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{PaletteRegistry, ROSE_PINE_DAWN};
+    /// let registry = PaletteRegistry::builtin();
+    /// assert_eq!(registry.lookup("rose-pine-dawn").unwrap(), &ROSE_PINE_DAWN);
+    /// assert!(registry.lookup("rose").is_err());
+    /// ```
+    pub fn lookup(&self, query: &str) -> Result<&Base16Palette, Base16PaletteError> {
+        if let Some(palette) = self.get(query) {
+            return Ok(palette);
+        }
+        let ranked = self.rank(query);
+        match ranked.as_slice() {
+            [(_, palette)] => return Ok(palette),
+            [(best, palette), (next, _), ..] if best < next => return Ok(palette),
+            _ => {}
+        }
+        Err(Base16PaletteError::PaletteNotFound {
+            query: query.to_string(),
+            candidates: ranked
+                .into_iter()
+                .map(|(_, palette)| palette.name.to_string())
+                .collect(),
+        })
+    }
+
+    /// Returns the palettes whose names resemble `query`, best match first.
+    ///
+    /// Exact matches after normalization rank before names starting with
+    /// `query`, which rank before names containing it, followed by names
+    /// close to it in spelling.
+    pub fn search(&self, query: &str) -> Vec<&Base16Palette> {
+        self.rank(query)
+            .into_iter()
+            .map(|(_, palette)| palette)
+            .collect()
+    }
+
+    /// Returns the scheme names in the registry, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.palettes.keys().map(String::as_str)
+    }
+
+    /// Returns the palettes in the registry, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = &Base16Palette> {
+        self.palettes.values()
+    }
+
+    /// Returns the dark or the light palettes in the registry, sorted by
+    /// name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{PaletteRegistry, Variant};
+    /// let registry = PaletteRegistry::builtin();
+    /// let light: Vec<_> = registry.by_variant(Variant::Light).map(|p| &*p.name).collect();
+    /// assert!(light.contains(&"Rosé Pine Dawn"));
+    /// assert!(!light.contains(&"Dracula"));
+    /// ```
+    pub fn by_variant(&self, variant: Variant) -> impl Iterator<Item = &Base16Palette> {
+        self.iter()
+            .filter(move |palette| palette.variant() == variant)
+    }
+
+    /// Returns the number of palettes in the registry.
+    pub fn len(&self) -> usize {
+        self.palettes.len()
+    }
+
+    /// Returns `true` if the registry holds no palettes.
+    pub fn is_empty(&self) -> bool {
+        self.palettes.is_empty()
+    }
+
+    fn rank(&self, query: &str) -> Vec<(usize, &Base16Palette)> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut ranked: Vec<_> = self
+            .iter()
+            .filter_map(|palette| Some((score(&query, &normalize(&palette.name))?, palette)))
+            .collect();
+        ranked.sort_by_key(|(score, _)| *score);
+        ranked
+    }
+}
+
+#[cfg(feature = "fs")]
+impl PaletteRegistry {
+    /// Loads every `.yaml`, `.yml`, `.toml` and `.json` scheme file directly
+    /// inside `dir` into a new registry.
+    ///
+    /// Files that fail to load are skipped and returned alongside the
+    /// registry, so callers can report them. Schemes without a name are
+    /// registered under their file stem. When several files define the same
+    /// name, the one whose path sorts last wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::FileNotFound`] if `dir` doesn't exist
+    /// and [`Base16PaletteError::Io`] if it can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::PaletteRegistry;
+    /// let (registry, skipped) = PaletteRegistry::load_dir(".config").unwrap();
+    /// assert!(registry.get("Dracula").is_some());
+    /// assert!(skipped.is_empty());
+    /// ```
+    pub fn load_dir(
+        dir: impl AsRef<Path>,
+    ) -> Result<(Self, Vec<Base16PaletteError>), Base16PaletteError> {
+        Self::load(dir.as_ref(), false)
+    }
+
+    /// Loads every scheme file inside `dir` and its subdirectories into a
+    /// new registry.
+    ///
+    /// Symbolic links to directories are not followed, so a link back to a
+    /// parent doesn't load the same schemes again. See
+    /// [`PaletteRegistry::load_dir`] for details.
+    pub fn load_dir_recursive(
+        dir: impl AsRef<Path>,
+    ) -> Result<(Self, Vec<Base16PaletteError>), Base16PaletteError> {
+        Self::load(dir.as_ref(), true)
+    }
+
+    fn load(
+        dir: &Path,
+        recursive: bool,
+    ) -> Result<(Self, Vec<Base16PaletteError>), Base16PaletteError> {
+        if !dir.is_dir() {
+            return Err(Base16PaletteError::FileNotFound(dir.to_path_buf()));
+        }
+        let mut files = Vec::new();
+        scan(dir, recursive, &mut files)?;
+        files.sort();
+
+        let mut registry = Self::new();
+        let mut skipped = Vec::new();
+        for file in files {
+            match Base16Palette::from_file(&file) {
+                Ok(mut palette) => {
+                    if palette.name.is_empty() {
+                        let stem = file.file_stem().unwrap_or_default();
+                        palette.name = stem.to_string_lossy().into_owned().into();
+                    }
+                    registry.insert(palette);
+                }
+                Err(error) => skipped.push(error),
+            }
+        }
+        Ok((registry, skipped))
+    }
+}
+
+/// Collects the scheme files in `dir`, descending into subdirectories if
+/// `recursive` is set.
+#[cfg(feature = "fs")]
+fn scan(dir: &Path, recursive: bool, files: &mut Vec<PathBuf>) -> Result<(), Base16PaletteError> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        // Doesn't follow symbolic links, which could loop back to `dir`.
+        if entry.file_type()?.is_dir() {
+            if recursive {
+                scan(&path, recursive, files)?;
+            }
+        } else if load::is_scheme_file(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+impl Base16Palette {
+    /// Returns the builtin palette with the scheme name or scheme file name
+    /// `name`, compared ignoring case, accents, spaces and punctuation like
+    /// [`PaletteRegistry::lookup`].
+    ///
+    /// Unlike the registry, no prefixes or misspellings are accepted, and
+    /// palettes are found with a binary search of a static index without
+    /// building a registry first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Palette, DRACULA, ROSE_PINE_DAWN};
+    /// assert_eq!(Base16Palette::builtin("rose-pine-dawn"), Some(&ROSE_PINE_DAWN));
+    /// assert_eq!(Base16Palette::builtin("DRACULA"), Some(&DRACULA));
+    /// assert_eq!(Base16Palette::builtin("drac"), None);
+    /// ```
+    pub fn builtin(name: &str) -> Option<&'static Base16Palette> {
+        let key = normalize(name);
+        BUILTIN_INDEX
+            .binary_search_by(|(entry, _)| (*entry).cmp(key.as_str()))
+            .ok()
+            .map(|index| BUILTIN_INDEX[index].1)
+    }
+}
+
+/// Lowercases `name`, strips accents from Latin letters and drops
+/// everything but letters and digits.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .flat_map(char::to_lowercase)
+        .map(strip_accent)
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// Maps an accented lowercase Latin letter to the letter without accent.
+pub(crate) fn strip_accent(c: char) -> char {
+    match c {
+        'à'..='å' => 'a',
+        'ç' => 'c',
+        'è'..='ë' => 'e',
+        'ì'..='ï' => 'i',
+        'ñ' => 'n',
+        'ò'..='ö' | 'ø' => 'o',
+        'ù'..='ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        c => c,
+    }
+}
+
+/// Scores how well the normalized `name` matches `query`, lower being
+/// better, or `None` if it doesn't match at all.
+fn score(query: &str, name: &str) -> Option<usize> {
+    if name == query {
+        return Some(0);
+    }
+    if name.starts_with(query) {
+        return Some(1);
+    }
+    if name.contains(query) {
+        return Some(2);
+    }
+    let distance = distance(query, name);
+    (distance <= (query.chars().count() / 4).max(1)).then_some(3 + distance)
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substituted = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substituted.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl FromIterator<Base16Palette> for PaletteRegistry {
+    fn from_iter<I: IntoIterator<Item = Base16Palette>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+impl Extend<Base16Palette> for PaletteRegistry {
+    fn extend<I: IntoIterator<Item = Base16Palette>>(&mut self, iter: I) {
+        for palette in iter {
+            self.insert(palette);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "fs")]
+    use std::fs;
+
+    use super::*;
+    #[cfg(feature = "fs")]
+    use crate::temp_dir::TempDir;
+    use crate::{DRACULA, ROSE_PINE, ROSE_PINE_DAWN};
+
+    #[test]
+    fn names_are_matched_loosely() {
+        let registry = PaletteRegistry::builtin();
+        for query in [
+            "rosepine dawn",
+            "rose-pine-dawn",
+            "Rosé Pine Dawn",
+            "rose pine daw",
+        ] {
+            assert_eq!(registry.lookup(query).unwrap(), &ROSE_PINE_DAWN, "{query}");
+        }
+        assert_eq!(registry.lookup("rose pine").unwrap(), &ROSE_PINE);
+        assert_eq!(registry.lookup("dracla").unwrap(), &DRACULA);
+
+        let Err(Base16PaletteError::PaletteNotFound { candidates, .. }) = registry.lookup("rose")
+        else {
+            panic!("expected an ambiguous match");
+        };
+        assert_eq!(candidates.len(), 3);
+        assert!(registry.lookup("solarized").is_err());
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn directories_are_scanned_and_invalid_files_reported() {
+        let dir = TempDir::new("registry");
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("dracula.yaml"), DRACULA.to_yaml().unwrap()).unwrap();
+        fs::write(dir.join("broken.toml"), "scheme = \"Broken\"\n").unwrap();
+        fs::write(dir.join("notes.txt"), "not a scheme").unwrap();
+        let anonymous = ROSE_PINE.to_json().unwrap().replace("Rosé Pine", "");
+        fs::write(dir.join("nested").join("pine.json"), anonymous).unwrap();
+
+        let (registry, skipped) = PaletteRegistry::load_dir(&dir).unwrap();
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["Dracula"]);
+        assert_eq!(registry.get("Dracula"), Some(&DRACULA));
+        assert!(matches!(
+            skipped.as_slice(),
+            [Base16PaletteError::InFile { path, .. }] if path.ends_with("broken.toml")
+        ));
+
+        let (registry, _) = PaletteRegistry::load_dir_recursive(&dir).unwrap();
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["Dracula", "pine"]);
+        assert_eq!(registry.get("pine").unwrap().base00, ROSE_PINE.base00);
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&*dir, dir.join("nested").join("loop")).unwrap();
+            let (registry, skipped) = PaletteRegistry::load_dir_recursive(&dir).unwrap();
+            assert_eq!(registry.names().collect::<Vec<_>>(), ["Dracula", "pine"]);
+            assert_eq!(skipped.len(), 1);
+        }
+
+        assert!(matches!(
+            PaletteRegistry::load_dir(dir.join("missing")),
+            Err(Base16PaletteError::FileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn builtin_index_covers_every_palette() {
+        assert!(BUILTIN_INDEX.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for palette in BUILTIN_PALETTES {
+            assert_eq!(Base16Palette::builtin(&palette.name), Some(palette));
+            let file = palette.slug.rsplit('/').next().unwrap_or_default();
+            if let Some(stem) = file.strip_suffix(".yaml") {
+                assert_eq!(Base16Palette::builtin(stem), Some(palette), "{stem}");
+            }
+        }
+        assert!(BUILTIN_INDEX
+            .iter()
+            .all(|(key, palette)| normalize(key) == *key && BUILTIN_PALETTES.contains(palette)));
+    }
+}
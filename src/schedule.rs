@@ -0,0 +1,252 @@
+//! Switching between a light and a dark palette by the time of day.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone};
+
+use crate::{Base16Palette, Variant};
+
+/// Picks a light palette during the day and a dark palette at night.
+///
+/// By default the light palette is used from 07:00 to 19:00 local time. The
+/// switch times can be changed with [`ThemeSchedule::with_hours`] or, with
+/// the `sunrise` feature, follow the sun with
+/// [`ThemeSchedule::with_location`].
+///
+/// [`ThemeSchedule::next_switch`] tells when the palette changes next, so
+/// applications can set a timer instead of polling.
+///
+/// # Examples
+///
+/// ```rust
+/// # use chrono::{NaiveTime, TimeZone, Utc};
+/// # use ratatui_base16::{ThemeSchedule, DEFAULT_DARK, DEFAULT_LIGHT};
+/// let schedule = ThemeSchedule::new(DEFAULT_LIGHT, DEFAULT_DARK).with_hours(
+///     NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+///     NaiveTime::from_hms_opt(20, 30, 0).unwrap(),
+/// );
+/// let noon = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+/// assert_eq!(schedule.palette_at(&noon), &DEFAULT_LIGHT);
+/// assert_eq!(
+///     schedule.next_switch_at(&noon),
+///     Some(Utc.with_ymd_and_hms(2024, 6, 1, 20, 30, 0).unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeSchedule {
+    light: Base16Palette,
+    dark: Base16Palette,
+    light_at: NaiveTime,
+    dark_at: NaiveTime,
+    #[cfg(feature = "sunrise")]
+    location: Option<(f64, f64)>,
+}
+
+impl ThemeSchedule {
+    /// Creates a schedule using `light` from 07:00 to 19:00 local time and
+    /// `dark` otherwise.
+    pub fn new(light: Base16Palette, dark: Base16Palette) -> Self {
+        Self {
+            light,
+            dark,
+            light_at: NaiveTime::from_hms_opt(7, 0, 0).unwrap_or_default(),
+            dark_at: NaiveTime::from_hms_opt(19, 0, 0).unwrap_or_default(),
+            #[cfg(feature = "sunrise")]
+            location: None,
+        }
+    }
+
+    /// Returns the schedule switching to the light palette at `light_at` and
+    /// to the dark palette at `dark_at`, both in local time.
+    ///
+    /// `dark_at` may be earlier than `light_at` for a schedule whose light
+    /// period spans midnight.
+    #[must_use = "method returns a new schedule and does not modify the original"]
+    pub fn with_hours(mut self, light_at: NaiveTime, dark_at: NaiveTime) -> Self {
+        self.light_at = light_at;
+        self.dark_at = dark_at;
+        self
+    }
+
+    /// Returns the schedule switching to the light palette at sunrise and to
+    /// the dark palette at sunset at the given position, in degrees north
+    /// and east.
+    ///
+    /// During polar day and night the palette doesn't switch at all.
+    #[cfg(feature = "sunrise")]
+    #[must_use = "method returns a new schedule and does not modify the original"]
+    pub fn with_location(mut self, latitude: f64, longitude: f64) -> Self {
+        self.location = Some((latitude, longitude));
+        self
+    }
+
+    /// Returns the variant in use at `time`.
+    pub fn variant_at<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Variant {
+        let now = time.time();
+        match self.day(time.date_naive(), offset_minutes(time)) {
+            Ok((light_at, dark_at)) => {
+                let light = if light_at <= dark_at {
+                    light_at <= now && now < dark_at
+                } else {
+                    now >= light_at || now < dark_at
+                };
+                if light {
+                    Variant::Light
+                } else {
+                    Variant::Dark
+                }
+            }
+            Err(variant) => variant,
+        }
+    }
+
+    /// Returns the palette in use at `time`.
+    pub fn palette_at<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> &Base16Palette {
+        match self.variant_at(time) {
+            Variant::Light => &self.light,
+            Variant::Dark => &self.dark,
+        }
+    }
+
+    /// Returns the palette in use now, in the local time zone.
+    pub fn current(&self) -> &Base16Palette {
+        self.palette_at(&Local::now())
+    }
+
+    /// Returns the first moment after `time` at which the palette changes,
+    /// or `None` if it doesn't change within a year, as during polar night.
+    ///
+    /// The UTC offset of `time` is used throughout, so a switch that falls
+    /// after a daylight saving change may be off by the size of the change.
+    pub fn next_switch_at<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let offset = offset_minutes(time);
+        let local = time.naive_local();
+        let start = time.date_naive();
+        let switch = start.iter_days().take(367).find_map(|date| {
+            let (light_at, dark_at) = self.day(date, offset).ok()?;
+            let mut times = [date.and_time(light_at), date.and_time(dark_at)];
+            times.sort();
+            times.into_iter().find(|switch| *switch > local)
+        })?;
+        Some(time.clone() + (switch - local))
+    }
+
+    /// Returns how long the current palette stays in use, or `None` if it
+    /// doesn't change within a year.
+    pub fn next_switch(&self) -> Option<std::time::Duration> {
+        let now = Local::now();
+        let switch = self.next_switch_at(&now)?;
+        (switch - now).to_std().ok()
+    }
+
+    /// Returns the local times at which the light and the dark palette
+    /// take over on `date`, or the variant used all day.
+    #[cfg_attr(not(feature = "sunrise"), allow(unused_variables))]
+    fn day(&self, date: NaiveDate, offset: i64) -> Result<(NaiveTime, NaiveTime), Variant> {
+        #[cfg(feature = "sunrise")]
+        if let Some((latitude, longitude)) = self.location {
+            return sun(date, offset, latitude, longitude);
+        }
+        Ok((self.light_at, self.dark_at))
+    }
+}
+
+fn offset_minutes<Tz: TimeZone>(time: &DateTime<Tz>) -> i64 {
+    let utc = time.naive_utc();
+    (time.naive_local() - utc).num_minutes()
+}
+
+/// Computes the local times of sunrise and sunset on `date` with the NOAA
+/// solar position equations.
+#[cfg(feature = "sunrise")]
+fn sun(
+    date: NaiveDate,
+    offset: i64,
+    latitude: f64,
+    longitude: f64,
+) -> Result<(NaiveTime, NaiveTime), Variant> {
+    use chrono::{Datelike, Duration};
+    use std::f64::consts::TAU;
+
+    let gamma = TAU / 365.0 * f64::from(date.ordinal0());
+    let equation_of_time = 229.18
+        * (0.000075 + 0.001868 * gamma.cos()
+            - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos()
+            - 0.040849 * (2.0 * gamma).sin());
+    let declination = 0.006918 - 0.399912 * gamma.cos() + 0.070257 * gamma.sin()
+        - 0.006758 * (2.0 * gamma).cos()
+        + 0.000907 * (2.0 * gamma).sin()
+        - 0.002697 * (3.0 * gamma).cos()
+        + 0.00148 * (3.0 * gamma).sin();
+
+    let latitude = latitude.to_radians();
+    let cos_hour_angle = 90.833_f64.to_radians().cos() / (latitude.cos() * declination.cos())
+        - latitude.tan() * declination.tan();
+    if cos_hour_angle > 1.0 {
+        return Err(Variant::Dark);
+    }
+    if cos_hour_angle < -1.0 {
+        return Err(Variant::Light);
+    }
+    let hour_angle = cos_hour_angle.acos().to_degrees();
+    let local = |minutes: f64| {
+        let minutes = (minutes.round() as i64 + offset).rem_euclid(24 * 60);
+        NaiveTime::MIN + Duration::minutes(minutes)
+    };
+    Ok((
+        local(720.0 - 4.0 * (longitude + hour_angle) - equation_of_time),
+        local(720.0 - 4.0 * (longitude - hour_angle) - equation_of_time),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, Timelike};
+
+    use super::*;
+    use crate::{DEFAULT_DARK, DEFAULT_LIGHT};
+
+    #[test]
+    fn switches_at_the_configured_hours() {
+        let schedule = ThemeSchedule::new(DEFAULT_LIGHT, DEFAULT_DARK);
+        let zone = FixedOffset::east_opt(2 * 3600).unwrap();
+        let evening = zone.with_ymd_and_hms(2024, 3, 9, 21, 15, 0).unwrap();
+        assert_eq!(schedule.variant_at(&evening), Variant::Dark);
+        assert_eq!(
+            schedule.next_switch_at(&evening),
+            Some(zone.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap())
+        );
+
+        let night_owl = schedule.with_hours(
+            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(4, 0, 0).unwrap(),
+        );
+        assert_eq!(night_owl.palette_at(&evening), &DEFAULT_DARK);
+        let late = zone.with_ymd_and_hms(2024, 3, 10, 1, 0, 0).unwrap();
+        assert_eq!(night_owl.palette_at(&late), &DEFAULT_LIGHT);
+        assert_eq!(
+            night_owl.next_switch_at(&late).map(|switch| switch.hour()),
+            Some(4)
+        );
+    }
+
+    #[cfg(feature = "sunrise")]
+    #[test]
+    fn follows_the_sun() {
+        use chrono::Utc;
+
+        // London around the summer solstice: sunrise at about 03:43 UTC and
+        // sunset at about 20:21 UTC.
+        let london = ThemeSchedule::new(DEFAULT_LIGHT, DEFAULT_DARK).with_location(51.5, -0.13);
+        let morning = Utc.with_ymd_and_hms(2024, 6, 21, 3, 0, 0).unwrap();
+        assert_eq!(london.variant_at(&morning), Variant::Dark);
+        let sunrise = london.next_switch_at(&morning).unwrap();
+        assert_eq!((sunrise.hour(), sunrise.minute() / 10), (3, 4));
+        let sunset = london.next_switch_at(&sunrise).unwrap();
+        assert_eq!((sunset.hour(), sunset.minute() / 10), (20, 2));
+
+        let svalbard = london.with_location(78.2, 15.6);
+        assert_eq!(svalbard.variant_at(&morning), Variant::Light);
+        let winter = Utc.with_ymd_and_hms(2024, 12, 21, 12, 0, 0).unwrap();
+        assert_eq!(svalbard.variant_at(&winter), Variant::Dark);
+    }
+}
@@ -0,0 +1,129 @@
+//! JSON Schema of scheme files, for applications embedding palettes in their
+//! configuration.
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde_json::{json, Map};
+
+use crate::{Base16Color, Base16Palette};
+
+/// Describes the palette as it is serialized: the `scheme`, `author`, `slug`
+/// and `variant` keys and the sixteen slots as `"#rrggbb"` or `"#rrggbbaa"`
+/// strings.
+///
+/// Slots may be spelled `base0A` as well as `base0a`, like in the scheme
+/// files of the tinted-theming project. The other color notations the
+/// deserializer accepts, such as `[r, g, b]` arrays or color names, are left
+/// out so editors suggest the portable one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::Base16Palette;
+/// let schema = schemars::schema_for!(Base16Palette);
+/// assert_eq!(schema.get("title"), Some(&"Base16Palette".into()));
+/// assert!(schema.pointer("/properties/base0D/$ref").is_some());
+/// ```
+impl JsonSchema for Base16Palette {
+    fn schema_name() -> Cow<'static, str> {
+        "Base16Palette".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ratatui_base16::Base16Palette".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let color = generator.subschema_for::<HexColor>().to_value();
+        let mut properties = Map::new();
+        for (key, description) in [
+            ("scheme", "Name of the scheme"),
+            ("author", "Author of the scheme"),
+            ("slug", "File name or URL the scheme originates from"),
+        ] {
+            properties.insert(
+                key.into(),
+                json!({ "type": "string", "description": description }),
+            );
+        }
+        properties.insert(
+            "variant".into(),
+            json!({
+                "description": "Whether the scheme is dark or light, derived from base00 when read",
+                "enum": ["dark", "light"],
+            }),
+        );
+        let mut required = Vec::new();
+        let mut either = Vec::new();
+        for slot in Base16Color::ALL {
+            let (lower, upper) = (slot.field_name(), slot.name());
+            properties.insert(lower.into(), color.clone());
+            if lower == upper {
+                required.push(lower);
+            } else {
+                properties.insert(upper.into(), color.clone());
+                either.push(json!({ "anyOf": [{ "required": [lower] }, { "required": [upper] }] }));
+            }
+        }
+        json_schema!({
+            "title": "Base16Palette",
+            "description": "A base16 color scheme",
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "allOf": either,
+        })
+    }
+}
+
+/// A palette color in the notation palettes are serialized with.
+struct HexColor;
+
+impl JsonSchema for HexColor {
+    fn schema_name() -> Cow<'static, str> {
+        "HexColor".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A color as six hex digits, or eight with an alpha channel",
+            "type": "string",
+            "pattern": "^#?([0-9a-fA-F]{6}|[0-9a-fA-F]{8})$",
+            "examples": ["#282a36", "#28293680"],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::DRACULA;
+
+    #[test]
+    fn describes_serialized_palettes() {
+        let schema = schemars::schema_for!(Base16Palette);
+        let properties = schema.get("properties").and_then(Value::as_object).unwrap();
+        let serialized = serde_json::to_value(&DRACULA).unwrap();
+        for key in serialized.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key), "{key}");
+        }
+        assert_eq!(
+            schema
+                .pointer("/required")
+                .and_then(Value::as_array)
+                .map(Vec::len),
+            Some(10)
+        );
+        assert_eq!(
+            schema.pointer("/allOf/0/anyOf/1/required/0"),
+            Some(&"base0A".into())
+        );
+        assert_eq!(
+            schema.pointer("/$defs/HexColor/type"),
+            Some(&"string".into())
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Serialization of palettes in the base16 scheme layout.
+
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+
+use ratatui::style::Color;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::{color, Base16Palette, Base16PaletteError};
+
+/// Serializes the palette with the same keys that are read from scheme
+/// files: `scheme`, `author`, `slug`, `variant` and the sixteen slots,
+/// spelled `base00` to `base0F` like in the base16 spec, with colors
+/// formatted as `"#rrggbb"` strings, or `"#rrggbbaa"` for translucent ones.
+///
+/// Colors without a concrete RGB value are written by name (e.g. `"reset"`)
+/// or index (e.g. `"4"`), which the deserializer reads back unchanged.
+impl Serialize for Base16Palette {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Base16Palette", 20)?;
+        state.serialize_field("scheme", &self.name)?;
+        state.serialize_field("author", &self.author)?;
+        if self.slug.is_empty() {
+            state.skip_field("slug")?;
+        } else {
+            state.serialize_field("slug", &self.slug)?;
+        }
+        state.serialize_field("variant", self.variant().as_str())?;
+        for (slot, color) in self {
+            let alpha = self.alpha[slot as usize];
+            state.serialize_field(slot.name(), &serialize_color(color, alpha))?;
+        }
+        state.end()
+    }
+}
+
+impl Base16Palette {
+    /// Serializes the palette into a YAML scheme file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let yaml = DRACULA.to_yaml().unwrap();
+    /// assert!(yaml.starts_with("scheme: Dracula\n"));
+    /// assert!(yaml.contains("base00: '#282936'\n"));
+    /// ```
+    pub fn to_yaml(&self) -> Result<String, Base16PaletteError> {
+        serde_yaml::to_string(self)
+            .map_err(|e| Base16PaletteError::SerializationFailed(e.to_string()))
+    }
+
+    /// Serializes the palette into a TOML scheme file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let toml = DRACULA.to_toml().unwrap();
+    /// assert!(toml.starts_with("scheme = \"Dracula\"\n"));
+    /// assert!(toml.contains("base00 = \"#282936\"\n"));
+    /// ```
+    pub fn to_toml(&self) -> Result<String, Base16PaletteError> {
+        toml::to_string(self).map_err(|e| Base16PaletteError::SerializationFailed(e.to_string()))
+    }
+
+    /// Serializes the palette into a pretty-printed JSON scheme file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let json = DRACULA.to_json().unwrap();
+    /// assert!(json.contains("\"base00\": \"#282936\""));
+    /// ```
+    pub fn to_json(&self) -> Result<String, Base16PaletteError> {
+        serde_json::to_string_pretty(self)
+            .map(|json| json + "\n")
+            .map_err(|e| Base16PaletteError::SerializationFailed(e.to_string()))
+    }
+
+    /// Writes the palette to a YAML file, see [`to_yaml`](Self::to_yaml).
+    #[cfg(feature = "fs")]
+    pub fn write_yaml(&self, file: impl Into<PathBuf>) -> Result<(), Base16PaletteError> {
+        std::fs::write(file.into(), self.to_yaml()?)?;
+        Ok(())
+    }
+
+    /// Writes the palette to a TOML file, see [`to_toml`](Self::to_toml).
+    #[cfg(feature = "fs")]
+    pub fn write_toml(&self, file: impl Into<PathBuf>) -> Result<(), Base16PaletteError> {
+        std::fs::write(file.into(), self.to_toml()?)?;
+        Ok(())
+    }
+
+    /// Writes the palette to a JSON file, see [`to_json`](Self::to_json).
+    #[cfg(feature = "fs")]
+    pub fn write_json(&self, file: impl Into<PathBuf>) -> Result<(), Base16PaletteError> {
+        std::fs::write(file.into(), self.to_json()?)?;
+        Ok(())
+    }
+}
+
+fn serialize_color(color: Color, alpha: u8) -> String {
+    match color {
+        Color::Rgb(..) if alpha < 0xff => {
+            format!("{}{alpha:02x}", color::to_hex(color).unwrap_or_default())
+        }
+        Color::Rgb(..) => color::to_hex(color).unwrap_or_default(),
+        _ => color.to_string().to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    #[cfg(feature = "fs")]
+    use crate::temp_dir::TempDir;
+    use crate::{Base16Palette, DRACULA};
+
+    #[test]
+    fn serialized_palette_round_trips() {
+        let mut palette = DRACULA
+            .with_base01(Color::Reset)
+            .with_base02(Color::Indexed(4));
+        palette.alpha[0x0e] = 0x80;
+        let value = serde_json::to_value(&palette).unwrap();
+        assert_eq!(value["base0D"], "#62d6e8");
+        assert_eq!(value["base0E"], "#b45bcf80");
+        assert_eq!(value["scheme"], "Dracula");
+        assert_eq!(value["variant"], "dark");
+
+        let loaded: Base16Palette = serde_json::from_value(value).unwrap();
+        assert_eq!(loaded.to_array(), palette.to_array());
+        assert_eq!(loaded.alpha, palette.alpha);
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn written_files_load_again() {
+        let dir = TempDir::new("serialize");
+
+        let yaml = dir.join("dracula.yaml");
+        DRACULA.write_yaml(&yaml).unwrap();
+        let loaded = Base16Palette::from_yaml(&yaml).unwrap();
+        assert_eq!(loaded.to_array(), DRACULA.to_array());
+        assert_eq!((loaded.name, loaded.slug), (DRACULA.name, DRACULA.slug));
+
+        let toml = dir.join("dracula.toml");
+        DRACULA.write_toml(&toml).unwrap();
+        let loaded = Base16Palette::from_toml(&toml).unwrap();
+        assert_eq!(loaded.to_array(), DRACULA.to_array());
+    }
+}
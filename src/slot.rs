@@ -0,0 +1,416 @@
+//! Identifiers for the sixteen slots of a base16 palette.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Index;
+use core::str::FromStr;
+
+use ratatui::style::Color;
+
+use crate::{Base16Palette, Base16PaletteError};
+
+/// One of the sixteen color slots of a [`Base16Palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Base16Color {
+    /// Default Background
+    Base00,
+    /// Lighter Background
+    Base01,
+    /// Selection Background
+    Base02,
+    /// Comments, Invisibles, Line Highlighting
+    Base03,
+    /// Dark Foreground
+    Base04,
+    /// Default Foreground, Caret, Delimiters, Operators
+    Base05,
+    /// Light Foreground
+    Base06,
+    /// Light Background
+    Base07,
+    /// Variables, XML Tags, Markup Link Text, Markup Lists, Diff Deleted
+    Base08,
+    /// Integers, Boolean, Constants, XML Attributes, Markup Link Url
+    Base09,
+    /// Classes, Markup Bold, Search Text Background
+    Base0A,
+    /// Strings, Inherited Class, Markup Code, Diff Inserted
+    Base0B,
+    /// Support, Regular Expressions, Escape Characters, Markup Quotes
+    Base0C,
+    /// Functions, Methods, Attribute IDs, Headings
+    Base0D,
+    /// Keywords, Storage, Selector, Markup Italic, Diff Changed
+    Base0E,
+    /// Deprecated, Opening/Closing Embedded Language Tags
+    Base0F,
+}
+
+impl Base16Color {
+    /// All slots in spec order, from base00 to base0F.
+    pub const ALL: [Base16Color; 16] = [
+        Self::Base00,
+        Self::Base01,
+        Self::Base02,
+        Self::Base03,
+        Self::Base04,
+        Self::Base05,
+        Self::Base06,
+        Self::Base07,
+        Self::Base08,
+        Self::Base09,
+        Self::Base0A,
+        Self::Base0B,
+        Self::Base0C,
+        Self::Base0D,
+        Self::Base0E,
+        Self::Base0F,
+    ];
+
+    /// Returns a description of the role the slot plays, following the base16
+    /// styling guidelines.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Base00 => "Default Background",
+            Self::Base01 => {
+                "Lighter Background (Used for status bars, line number and folding marks)"
+            }
+            Self::Base02 => "Selection Background",
+            Self::Base03 => "Comments, Invisibles, Line Highlighting",
+            Self::Base04 => "Dark Foreground (Used for status bars)",
+            Self::Base05 => "Default Foreground, Caret, Delimiters, Operators",
+            Self::Base06 => "Light Foreground (Not often used)",
+            Self::Base07 => "Light Background (Not often used)",
+            Self::Base08 => "Variables, XML Tags, Markup Link Text, Markup Lists, Diff Deleted",
+            Self::Base09 => "Integers, Boolean, Constants, XML Attributes, Markup Link Url",
+            Self::Base0A => "Classes, Markup Bold, Search Text Background",
+            Self::Base0B => "Strings, Inherited Class, Markup Code, Diff Inserted",
+            Self::Base0C => "Support, Regular Expressions, Escape Characters, Markup Quotes",
+            Self::Base0D => "Functions, Methods, Attribute IDs, Headings",
+            Self::Base0E => "Keywords, Storage, Selector, Markup Italic, Diff Changed",
+            Self::Base0F => "Deprecated, Opening/Closing Embedded Language Tags",
+        }
+    }
+
+    /// Returns the name of the corresponding [`Base16Palette`] field, e.g.
+    /// `"base0a"`.
+    pub(crate) const fn field_name(self) -> &'static str {
+        match self {
+            Self::Base0A => "base0a",
+            Self::Base0B => "base0b",
+            Self::Base0C => "base0c",
+            Self::Base0D => "base0d",
+            Self::Base0E => "base0e",
+            Self::Base0F => "base0f",
+            _ => self.name(),
+        }
+    }
+
+    /// Returns the spec name of the slot, e.g. `"base0A"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Base00 => "base00",
+            Self::Base01 => "base01",
+            Self::Base02 => "base02",
+            Self::Base03 => "base03",
+            Self::Base04 => "base04",
+            Self::Base05 => "base05",
+            Self::Base06 => "base06",
+            Self::Base07 => "base07",
+            Self::Base08 => "base08",
+            Self::Base09 => "base09",
+            Self::Base0A => "base0A",
+            Self::Base0B => "base0B",
+            Self::Base0C => "base0C",
+            Self::Base0D => "base0D",
+            Self::Base0E => "base0E",
+            Self::Base0F => "base0F",
+        }
+    }
+}
+
+const SHADES: [Base16Color; 8] = [
+    Base16Color::Base00,
+    Base16Color::Base01,
+    Base16Color::Base02,
+    Base16Color::Base03,
+    Base16Color::Base04,
+    Base16Color::Base05,
+    Base16Color::Base06,
+    Base16Color::Base07,
+];
+
+const ACCENTS: [Base16Color; 8] = [
+    Base16Color::Base08,
+    Base16Color::Base09,
+    Base16Color::Base0A,
+    Base16Color::Base0B,
+    Base16Color::Base0C,
+    Base16Color::Base0D,
+    Base16Color::Base0E,
+    Base16Color::Base0F,
+];
+
+impl IntoIterator for &Base16Palette {
+    type Item = (Base16Color, Color);
+    type IntoIter = core::array::IntoIter<(Base16Color, Color), 16>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Base16Palette {
+    type Item = (Base16Color, Color);
+    type IntoIter = core::array::IntoIter<(Base16Color, Color), 16>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Formats the slot by its spec name, e.g. `base0A`.
+impl fmt::Display for Base16Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Parses a slot name such as `"base0A"`, ignoring case.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::Base16Color;
+/// assert_eq!("base0d".parse::<Base16Color>().unwrap(), Base16Color::Base0D);
+/// assert_eq!("BASE0D".parse::<Base16Color>().unwrap(), Base16Color::Base0D);
+/// assert!("base10".parse::<Base16Color>().is_err());
+/// ```
+impl FromStr for Base16Color {
+    type Err = Base16PaletteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|slot| slot.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Base16PaletteError::InvalidSlot(s.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Base16Color {
+    type Error = Base16PaletteError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Accesses a color by slot, e.g. `palette[Base16Color::Base0D]`.
+impl Index<Base16Color> for Base16Palette {
+    type Output = Color;
+
+    fn index(&self, slot: Base16Color) -> &Color {
+        match slot {
+            Base16Color::Base00 => &self.base00,
+            Base16Color::Base01 => &self.base01,
+            Base16Color::Base02 => &self.base02,
+            Base16Color::Base03 => &self.base03,
+            Base16Color::Base04 => &self.base04,
+            Base16Color::Base05 => &self.base05,
+            Base16Color::Base06 => &self.base06,
+            Base16Color::Base07 => &self.base07,
+            Base16Color::Base08 => &self.base08,
+            Base16Color::Base09 => &self.base09,
+            Base16Color::Base0A => &self.base0a,
+            Base16Color::Base0B => &self.base0b,
+            Base16Color::Base0C => &self.base0c,
+            Base16Color::Base0D => &self.base0d,
+            Base16Color::Base0E => &self.base0e,
+            Base16Color::Base0F => &self.base0f,
+        }
+    }
+}
+
+impl Base16Palette {
+    /// Returns an iterator over all slots and their colors in spec order,
+    /// from base00 to base0F.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Color, DRACULA};
+    /// let (slot, color) = DRACULA.iter().last().unwrap();
+    /// assert_eq!((slot, color), (Base16Color::Base0F, DRACULA.base0f));
+    /// ```
+    pub fn iter(&self) -> core::array::IntoIter<(Base16Color, Color), 16> {
+        Base16Color::ALL
+            .map(|slot| (slot, self.color(slot)))
+            .into_iter()
+    }
+
+    /// Returns the slots whose colors differ between `self` and `other`, as
+    /// `(slot, self_color, other_color)` triples in spec order.
+    ///
+    /// Unlike `==`, this ignores the name, author and slug of the palettes,
+    /// and their [`alpha`](Base16Palette::alpha) channels: a slot whose color
+    /// is the same in both palettes isn't listed even if its alpha differs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::{Base16Color, Base16Palette, DRACULA};
+    /// let tweaked = DRACULA.with_base0d(Color::Blue);
+    /// assert_eq!(
+    ///     DRACULA.diff(&tweaked),
+    ///     [(Base16Color::Base0D, DRACULA.base0d, Color::Blue)]
+    /// );
+    ///
+    /// let translucent = Base16Palette {
+    ///     alpha: [0x80; 16],
+    ///     ..DRACULA
+    /// };
+    /// assert!(DRACULA.diff(&translucent).is_empty());
+    /// ```
+    pub fn diff(&self, other: &Base16Palette) -> Vec<(Base16Color, Color, Color)> {
+        self.iter()
+            .zip(other.iter())
+            .filter(|((_, a), (_, b))| a != b)
+            .map(|((slot, a), (_, b))| (slot, a, b))
+            .collect()
+    }
+
+    /// Returns an iterator over the shades base00 to base07, which run from
+    /// background to foreground.
+    pub fn shades(&self) -> core::array::IntoIter<(Base16Color, Color), 8> {
+        SHADES.map(|slot| (slot, self.color(slot))).into_iter()
+    }
+
+    /// Returns an iterator over the accents base08 to base0F.
+    pub fn accents(&self) -> core::array::IntoIter<(Base16Color, Color), 8> {
+        ACCENTS.map(|slot| (slot, self.color(slot))).into_iter()
+    }
+
+    /// Returns the color of a slot given either as a [`Base16Color`] or by
+    /// name, e.g. `"base0A"`.
+    ///
+    /// Names are matched ignoring case, so style rules read from user
+    /// configuration can be resolved at runtime. Returns `None` for names
+    /// that don't identify a slot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Color, DRACULA};
+    /// assert_eq!(DRACULA.get("base0A"), Some(DRACULA.base0a));
+    /// assert_eq!(DRACULA.get("BASE0a"), Some(DRACULA.base0a));
+    /// assert_eq!(DRACULA.get(Base16Color::Base0A), Some(DRACULA.base0a));
+    /// assert_eq!(DRACULA.get("accent"), None);
+    /// ```
+    pub fn get<S: TryInto<Base16Color>>(&self, slot: S) -> Option<Color> {
+        slot.try_into().ok().map(|slot| self.color(slot))
+    }
+
+    /// Replaces the color of a slot given either as a [`Base16Color`] or by
+    /// name, returning the previous color.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::InvalidSlot`] if a name doesn't identify
+    /// a slot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::DRACULA;
+    /// let mut palette = DRACULA;
+    /// palette.set("base0d", Color::Rgb(0, 0, 255)).unwrap();
+    /// assert_eq!(palette.base0d, Color::Rgb(0, 0, 255));
+    /// assert!(palette.set("base16", Color::Red).is_err());
+    /// ```
+    pub fn set<S>(&mut self, slot: S, color: Color) -> Result<Color, Base16PaletteError>
+    where
+        S: TryInto<Base16Color>,
+        Base16PaletteError: From<S::Error>,
+    {
+        let slot = slot.try_into()?;
+        Ok(core::mem::replace(self.color_mut(slot), color))
+    }
+
+    pub(crate) fn color_mut(&mut self, slot: Base16Color) -> &mut Color {
+        match slot {
+            Base16Color::Base00 => &mut self.base00,
+            Base16Color::Base01 => &mut self.base01,
+            Base16Color::Base02 => &mut self.base02,
+            Base16Color::Base03 => &mut self.base03,
+            Base16Color::Base04 => &mut self.base04,
+            Base16Color::Base05 => &mut self.base05,
+            Base16Color::Base06 => &mut self.base06,
+            Base16Color::Base07 => &mut self.base07,
+            Base16Color::Base08 => &mut self.base08,
+            Base16Color::Base09 => &mut self.base09,
+            Base16Color::Base0A => &mut self.base0a,
+            Base16Color::Base0B => &mut self.base0b,
+            Base16Color::Base0C => &mut self.base0c,
+            Base16Color::Base0D => &mut self.base0d,
+            Base16Color::Base0E => &mut self.base0e,
+            Base16Color::Base0F => &mut self.base0f,
+        }
+    }
+
+    /// Returns the color stored in the given slot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Color, DRACULA};
+    /// assert_eq!(DRACULA.color(Base16Color::Base0D), DRACULA.base0d);
+    /// ```
+    pub const fn color(&self, slot: Base16Color) -> Color {
+        match slot {
+            Base16Color::Base00 => self.base00,
+            Base16Color::Base01 => self.base01,
+            Base16Color::Base02 => self.base02,
+            Base16Color::Base03 => self.base03,
+            Base16Color::Base04 => self.base04,
+            Base16Color::Base05 => self.base05,
+            Base16Color::Base06 => self.base06,
+            Base16Color::Base07 => self.base07,
+            Base16Color::Base08 => self.base08,
+            Base16Color::Base09 => self.base09,
+            Base16Color::Base0A => self.base0a,
+            Base16Color::Base0B => self.base0b,
+            Base16Color::Base0C => self.base0c,
+            Base16Color::Base0D => self.base0d,
+            Base16Color::Base0E => self.base0e,
+            Base16Color::Base0F => self.base0f,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DRACULA;
+
+    #[test]
+    fn display_round_trips_through_from_str() {
+        for slot in Base16Color::ALL {
+            assert_eq!(slot.to_string().parse::<Base16Color>().unwrap(), slot);
+            assert_eq!(DRACULA[slot], DRACULA.color(slot));
+        }
+        assert_eq!(Base16Color::Base0A.to_string(), "base0A");
+    }
+
+    #[test]
+    fn shades_and_accents_cover_all_slots() {
+        let all: Vec<_> = DRACULA.shades().chain(DRACULA.accents()).collect();
+        assert_eq!(all, (&DRACULA).into_iter().collect::<Vec<_>>());
+        assert_eq!(
+            DRACULA.accents().next(),
+            Some((Base16Color::Base08, DRACULA.base08))
+        );
+    }
+}
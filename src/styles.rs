@@ -0,0 +1,186 @@
+//! Ready-made style presets derived from a [`Base16Palette`].
+
+use ratatui::style::{Modifier, Style};
+
+use crate::Base16Palette;
+
+/// Styles for rendering the lines of a unified diff.
+///
+/// Colors follow the base16 guidelines: inserted lines use base0B, deleted
+/// lines use base08 and modified lines use base0E. Every style sets both a
+/// foreground and a background so diff views render consistently on top of
+/// any other widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiffStyles {
+    /// Lines added by the diff (`+`).
+    pub inserted: Style,
+    /// Lines removed by the diff (`-`).
+    pub deleted: Style,
+    /// Lines changed in place, for side-by-side or word diffs.
+    pub modified: Style,
+    /// Hunk headers (`@@ -1,4 +1,5 @@`).
+    pub hunk_header: Style,
+    /// Unchanged context lines.
+    pub context: Style,
+}
+
+impl From<&Base16Palette> for DiffStyles {
+    fn from(palette: &Base16Palette) -> Self {
+        let line = Style::new().bg(palette.base00);
+        Self {
+            inserted: line.fg(palette.base0b),
+            deleted: line.fg(palette.base08),
+            modified: line.fg(palette.base0e),
+            hunk_header: Style::new().fg(palette.base0d).bg(palette.base01),
+            context: line.fg(palette.base05),
+        }
+    }
+}
+
+impl Base16Palette {
+    /// Returns the [`DiffStyles`] for this palette.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let styles = DRACULA.diff_styles();
+    /// assert_eq!(styles.inserted.fg, Some(DRACULA.base0b));
+    /// ```
+    pub fn diff_styles(&self) -> DiffStyles {
+        DiffStyles::from(self)
+    }
+}
+
+/// Styles for the severity levels of log records.
+///
+/// Errors use base08, warnings base0A, info base0B, debug base0D and trace
+/// base03, so log viewers get the same mapping regardless of which widget
+/// renders them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogStyles {
+    /// Style for `ERROR` records.
+    pub error: Style,
+    /// Style for `WARN` records.
+    pub warn: Style,
+    /// Style for `INFO` records.
+    pub info: Style,
+    /// Style for `DEBUG` records.
+    pub debug: Style,
+    /// Style for `TRACE` records.
+    pub trace: Style,
+}
+
+impl LogStyles {
+    /// Returns the style for a level name such as `"warn"` or `"ERROR"`.
+    ///
+    /// Matching is case-insensitive and accepts the common `"warning"`
+    /// spelling. Returns `None` for unknown levels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{LogStyles, DRACULA};
+    /// let styles = LogStyles::from(&DRACULA);
+    /// assert_eq!(styles.level("Warning"), Some(styles.warn));
+    /// ```
+    pub fn level(&self, level: &str) -> Option<Style> {
+        match level.to_ascii_lowercase().as_str() {
+            "error" => Some(self.error),
+            "warn" | "warning" => Some(self.warn),
+            "info" => Some(self.info),
+            "debug" => Some(self.debug),
+            "trace" => Some(self.trace),
+            _ => None,
+        }
+    }
+}
+
+impl From<&Base16Palette> for LogStyles {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            error: Style::new().fg(palette.base08).add_modifier(Modifier::BOLD),
+            warn: Style::new().fg(palette.base0a),
+            info: Style::new().fg(palette.base0b),
+            debug: Style::new().fg(palette.base0d),
+            trace: Style::new().fg(palette.base03),
+        }
+    }
+}
+
+/// Styles for rendering markdown and other lightweight markup.
+///
+/// Each style follows the guideline documented on the corresponding
+/// [`Base16Palette`] field: headings use base0D, bold text base0A, italic
+/// text base0E, code base0B, quotes base0C, links base09 and list bullets
+/// base08.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MarkupStyles {
+    /// Body text.
+    pub text: Style,
+    /// Headings of any level.
+    pub heading: Style,
+    /// Strong emphasis (`**bold**`).
+    pub bold: Style,
+    /// Emphasis (`*italic*`).
+    pub italic: Style,
+    /// Inline code spans and code blocks.
+    pub code: Style,
+    /// Block quotes.
+    pub block_quote: Style,
+    /// Link text and URLs.
+    pub link: Style,
+    /// List bullets and numbers.
+    pub list_bullet: Style,
+}
+
+impl From<&Base16Palette> for MarkupStyles {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            text: Style::new().fg(palette.base05),
+            heading: Style::new().fg(palette.base0d).add_modifier(Modifier::BOLD),
+            bold: Style::new().fg(palette.base0a).add_modifier(Modifier::BOLD),
+            italic: Style::new()
+                .fg(palette.base0e)
+                .add_modifier(Modifier::ITALIC),
+            code: Style::new().fg(palette.base0b).bg(palette.base01),
+            block_quote: Style::new()
+                .fg(palette.base0c)
+                .add_modifier(Modifier::ITALIC),
+            link: Style::new()
+                .fg(palette.base09)
+                .add_modifier(Modifier::UNDERLINED),
+            list_bullet: Style::new().fg(palette.base08),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn diff_styles_follow_guidelines() {
+        let styles = DEFAULT_DARK.diff_styles();
+        assert_eq!(styles.deleted.fg, Some(DEFAULT_DARK.base08));
+        assert_eq!(styles.modified.fg, Some(DEFAULT_DARK.base0e));
+        assert_eq!(styles.context.bg, Some(DEFAULT_DARK.base00));
+    }
+
+    #[test]
+    fn log_styles_by_level_name() {
+        let styles = LogStyles::from(&DEFAULT_DARK);
+        assert_eq!(styles.level("ERROR"), Some(styles.error));
+        assert_eq!(styles.trace.fg, Some(DEFAULT_DARK.base03));
+        assert_eq!(styles.level("fatal"), None);
+    }
+
+    #[test]
+    fn markup_styles_follow_guidelines() {
+        let styles = MarkupStyles::from(&DEFAULT_DARK);
+        assert_eq!(styles.heading.fg, Some(DEFAULT_DARK.base0d));
+        assert!(styles.bold.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(styles.link.fg, Some(DEFAULT_DARK.base09));
+    }
+}
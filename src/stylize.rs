@@ -0,0 +1,117 @@
+//! Shorthands for styling text with the colors of a palette.
+
+use ratatui::style::{Style, Styled};
+
+use crate::{Base16Color, Base16Palette, LogStyles};
+
+/// Styles strings, spans, lines, text and widgets with palette slots and
+/// semantic roles, like ratatui's [`Stylize`](ratatui::style::Stylize) does
+/// with named colors.
+///
+/// It is implemented for every type implementing [`Styled`]. The roles use
+/// the same styles as [`LogStyles`], so messages styled inline match those
+/// of log views.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::{style::Modifier, text::{Line, Span}};
+/// # use ratatui_base16::{Base16Color, Base16Stylize, DRACULA};
+/// let span = Span::raw("deleted")
+///     .base16(Base16Color::Base08, &DRACULA)
+///     .on_base16(Base16Color::Base01, &DRACULA);
+/// assert_eq!(span.style.fg, Some(DRACULA.base08));
+/// assert_eq!(span.style.bg, Some(DRACULA.base01));
+///
+/// let line = Line::from("build failed").error(&DRACULA);
+/// assert_eq!(line.style.fg, Some(DRACULA.base08));
+/// assert!(line.style.add_modifier.contains(Modifier::BOLD));
+///
+/// let hint = "press q to quit".muted(&DRACULA);
+/// assert_eq!(hint.style.fg, Some(DRACULA.base03));
+/// ```
+pub trait Base16Stylize<T>: Sized {
+    /// Sets the foreground to the color of `slot`.
+    fn base16(self, slot: Base16Color, palette: &Base16Palette) -> T;
+
+    /// Sets the background to the color of `slot`.
+    fn on_base16(self, slot: Base16Color, palette: &Base16Palette) -> T;
+
+    /// Styles an error message: bold base08.
+    fn error(self, palette: &Base16Palette) -> T;
+
+    /// Styles a warning: base0A.
+    fn warning(self, palette: &Base16Palette) -> T;
+
+    /// Styles an informational message: base0B.
+    fn info(self, palette: &Base16Palette) -> T;
+
+    /// Styles secondary text such as hints and comments: base03.
+    fn muted(self, palette: &Base16Palette) -> T;
+}
+
+impl<T, U> Base16Stylize<T> for U
+where
+    U: Styled<Item = T>,
+{
+    fn base16(self, slot: Base16Color, palette: &Base16Palette) -> T {
+        let style = self.style().fg(palette.color(slot));
+        self.set_style(style)
+    }
+
+    fn on_base16(self, slot: Base16Color, palette: &Base16Palette) -> T {
+        let style = self.style().bg(palette.color(slot));
+        self.set_style(style)
+    }
+
+    fn error(self, palette: &Base16Palette) -> T {
+        patch(self, LogStyles::from(palette).error)
+    }
+
+    fn warning(self, palette: &Base16Palette) -> T {
+        patch(self, LogStyles::from(palette).warn)
+    }
+
+    fn info(self, palette: &Base16Palette) -> T {
+        patch(self, LogStyles::from(palette).info)
+    }
+
+    fn muted(self, palette: &Base16Palette) -> T {
+        patch(self, LogStyles::from(palette).trace)
+    }
+}
+
+/// Applies `role` on top of the current style of `styled`, keeping its
+/// background and other modifiers.
+fn patch<T, U: Styled<Item = T>>(styled: U, role: Style) -> T {
+    let style = styled.style().patch(role);
+    styled.set_style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        style::{Color, Modifier, Stylize},
+        text::Span,
+    };
+
+    use super::*;
+    use crate::ROSE_PINE;
+
+    #[test]
+    fn roles_keep_the_existing_style() {
+        let span = Span::raw("x")
+            .italic()
+            .on_base16(Base16Color::Base00, &ROSE_PINE);
+        let warning = span.clone().warning(&ROSE_PINE);
+        assert_eq!(warning.style.fg, Some(ROSE_PINE.base0a));
+        assert_eq!(warning.style.bg, Some(ROSE_PINE.base00));
+        assert!(warning.style.add_modifier.contains(Modifier::ITALIC));
+
+        let style = Style::new()
+            .fg(Color::Red)
+            .base16(Base16Color::Base0D, &ROSE_PINE);
+        assert_eq!(style.fg, Some(ROSE_PINE.base0d));
+        assert_eq!(span.info(&ROSE_PINE).style.fg, Some(ROSE_PINE.base0b));
+    }
+}
@@ -0,0 +1,102 @@
+//! Conversion of a [`Base16Palette`] into a [`syntect`] highlighting theme.
+
+use std::str::FromStr;
+
+use ::syntect::highlighting::{
+    Color as SyntectColor, FontStyle, ScopeSelectors, StyleModifier, Theme, ThemeItem,
+    ThemeSettings,
+};
+use ratatui::style::{Color, Modifier};
+
+use crate::{color, export, Base16Palette};
+
+impl Base16Palette {
+    /// Converts the palette into a `syntect::highlighting::Theme`.
+    ///
+    /// Scopes are assigned following the base16 styling guidelines, matching
+    /// the mapping used by the base16 TextMate template. This allows code
+    /// highlighted with `syntect` to match the rest of a ratatui
+    /// application.
+    ///
+    /// This method is only available with the `syntect` feature enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let theme = DRACULA.to_syntect_theme();
+    /// assert_eq!(theme.name.as_deref(), Some("Dracula"));
+    /// ```
+    pub fn to_syntect_theme(&self) -> Theme {
+        let settings = ThemeSettings {
+            foreground: to_syntect(self.base05),
+            background: to_syntect(self.base00),
+            caret: to_syntect(self.base05),
+            line_highlight: to_syntect(self.base01),
+            misspelling: to_syntect(self.base08),
+            accent: to_syntect(self.base0d),
+            bracket_contents_foreground: to_syntect(self.base05),
+            brackets_foreground: to_syntect(self.base05),
+            tags_foreground: to_syntect(self.base0d),
+            highlight: to_syntect(self.base02),
+            find_highlight: to_syntect(self.base0a),
+            find_highlight_foreground: to_syntect(self.base00),
+            gutter: to_syntect(self.base01),
+            gutter_foreground: to_syntect(self.base03),
+            selection: to_syntect(self.base02),
+            selection_border: to_syntect(self.base01),
+            inactive_selection: to_syntect(self.base01),
+            guide: to_syntect(self.base02),
+            active_guide: to_syntect(self.base03),
+            stack_guide: to_syntect(self.base02),
+            shadow: to_syntect(self.base01),
+            ..ThemeSettings::default()
+        };
+
+        let scopes = export::textmate_rules(self)
+            .into_iter()
+            .filter_map(|(scope, color, modifier)| {
+                let font_style = match modifier {
+                    Modifier::BOLD => Some(FontStyle::BOLD),
+                    Modifier::ITALIC => Some(FontStyle::ITALIC),
+                    _ => None,
+                };
+                Some(ThemeItem {
+                    scope: ScopeSelectors::from_str(scope).ok()?,
+                    style: StyleModifier {
+                        foreground: to_syntect(color),
+                        background: None,
+                        font_style,
+                    },
+                })
+            })
+            .collect();
+
+        Theme {
+            name: Some(self.name.to_string()),
+            author: Some(self.author.to_string()),
+            settings,
+            scopes,
+        }
+    }
+}
+
+fn to_syntect(color: Color) -> Option<SyntectColor> {
+    color::to_rgb(color).map(|(r, g, b)| SyntectColor { r, g, b, a: 0xff })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DRACULA;
+
+    #[test]
+    fn syntect_theme_uses_palette_colors() {
+        let theme = DRACULA.to_syntect_theme();
+        let background = theme.settings.background.unwrap();
+        assert_eq!(
+            (background.r, background.g, background.b),
+            (0x28, 0x29, 0x36)
+        );
+        assert!(!theme.scopes.is_empty());
+    }
+}
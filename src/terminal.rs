@@ -0,0 +1,258 @@
+//! Querying the colors of the terminal with OSC 10 and OSC 11.
+
+use std::time::Duration;
+
+use ratatui::style::Color;
+
+use crate::{color, Base16Palette, Base16PaletteError, BUILTIN_PALETTES};
+
+/// The OSC 10 and OSC 11 queries for the default foreground and background,
+/// followed by a primary device attributes request.
+///
+/// Every terminal answers the device attributes request, so its reply marks
+/// the end of the answers and terminals that ignore the color queries don't
+/// make callers wait for the full timeout. The queries are terminated with
+/// BEL rather than ST since older iTerm2 releases ignore ST-terminated
+/// queries.
+const QUERY: &[u8] = b"\x1b]10;?\x07\x1b]11;?\x07\x1b[c";
+
+/// The default foreground and background colors of the terminal, as
+/// reported by the terminal itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TerminalColors {
+    /// Default foreground, or `None` if the terminal didn't report it
+    pub foreground: Option<Color>,
+    /// Default background, or `None` if the terminal didn't report it
+    pub background: Option<Color>,
+}
+
+impl TerminalColors {
+    /// Asks the terminal for its colors, waiting at most 100 milliseconds
+    /// for an answer.
+    ///
+    /// See [`TerminalColors::query_with_timeout`].
+    pub fn query() -> Result<Self, Base16PaletteError> {
+        Self::query_with_timeout(Duration::from_millis(100))
+    }
+
+    /// Asks the terminal for its colors with OSC 10 and OSC 11, waiting at
+    /// most `timeout` for an answer.
+    ///
+    /// The queries are written to and read from the controlling terminal,
+    /// `/dev/tty`, which is briefly put into raw mode. Call this before
+    /// starting an event loop that reads from the terminal, since whichever
+    /// reads first receives the answers. Colors the terminal doesn't report
+    /// are left as `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Base16PaletteError::Io`] if there is no controlling
+    /// terminal, and on platforms other than Unix.
+    pub fn query_with_timeout(timeout: Duration) -> Result<Self, Base16PaletteError> {
+        let reply = exchange(QUERY, timeout)?;
+        Ok(Self {
+            foreground: reply_color(&reply, b"\x1b]10;"),
+            background: reply_color(&reply, b"\x1b]11;"),
+        })
+    }
+
+    /// Returns the builtin palette whose default background (base00) is
+    /// closest to the terminal background, or `None` if the background is
+    /// unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::{TerminalColors, DRACULA};
+    /// let colors = TerminalColors {
+    ///     foreground: None,
+    ///     background: Some(Color::Rgb(0x28, 0x2a, 0x36)),
+    /// };
+    /// assert_eq!(colors.closest_builtin(), Some(&DRACULA));
+    /// ```
+    pub fn closest_builtin(&self) -> Option<&'static Base16Palette> {
+        let background = self.background?;
+        BUILTIN_PALETTES
+            .iter()
+            .filter_map(|palette| Some((color::delta_e(background, palette.base00)?, palette)))
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, palette)| palette)
+    }
+}
+
+/// Finds the answer starting with `prefix` in `reply` and parses its color.
+fn reply_color(reply: &[u8], prefix: &[u8]) -> Option<Color> {
+    let start = reply
+        .windows(prefix.len())
+        .position(|window| window == prefix)?
+        + prefix.len();
+    let rest = &reply[start..];
+    // Answers end with BEL or ST, whichever the terminal prefers.
+    let end = rest.iter().position(|&byte| byte == 0x07 || byte == 0x1b)?;
+    parse_color(std::str::from_utf8(&rest[..end]).ok()?)
+}
+
+/// Parses the `rgb:r/g/b` and `rgba:r/g/b/a` notations of XParseColor, with
+/// one to four hex digits per channel, and `#rrggbb`.
+fn parse_color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        return (hex.len() == 6).then(|| Color::from_u32(value));
+    }
+    let channels = value
+        .strip_prefix("rgb:")
+        .or_else(|| value.strip_prefix("rgba:"))?;
+    let channel = |digits: &str| -> Option<u8> {
+        if digits.is_empty() || digits.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        let max = (1u32 << (4 * digits.len())) - 1;
+        u8::try_from((value * 255 + max / 2) / max).ok()
+    };
+    let mut channels = channels.split('/');
+    let (r, g, b) = (
+        channel(channels.next()?)?,
+        channel(channels.next()?)?,
+        channel(channels.next()?)?,
+    );
+    Some(Color::Rgb(r, g, b))
+}
+
+/// Returns whether `reply` contains the answer to the device attributes
+/// request, `CSI ? … c`.
+fn answered(reply: &[u8]) -> bool {
+    reply.windows(3).enumerate().any(|(index, window)| {
+        window == b"\x1b[?"
+            && reply[index + 3..]
+                .iter()
+                .find(|byte| !byte.is_ascii_digit() && **byte != b';')
+                == Some(&b'c')
+    })
+}
+
+/// Writes `request` to the controlling terminal and collects its answers
+/// until the device attributes reply arrives or `timeout` passes.
+#[cfg(unix)]
+fn exchange(request: &[u8], timeout: Duration) -> std::io::Result<Vec<u8>> {
+    use std::{
+        fs::OpenOptions,
+        io::{self, Read, Write},
+        os::fd::AsRawFd,
+        time::Instant,
+    };
+
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+    let _raw = RawMode::enable(tty.as_raw_fd())?;
+    tty.write_all(request)?;
+    tty.flush()?;
+
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+    let mut buffer = [0; 256];
+    while !answered(&reply) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let mut fd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let millis = libc::c_int::try_from(remaining.as_millis()).unwrap_or(libc::c_int::MAX);
+        // SAFETY: `fd` is a valid pollfd for the duration of the call.
+        match unsafe { libc::poll(&mut fd, 1, millis) } {
+            0 => break,
+            ready if ready < 0 => {
+                let error = io::Error::last_os_error();
+                if error.kind() != io::ErrorKind::Interrupted {
+                    return Err(error);
+                }
+            }
+            _ => match tty.read(&mut buffer)? {
+                0 => break,
+                read => reply.extend_from_slice(&buffer[..read]),
+            },
+        }
+    }
+    Ok(reply)
+}
+
+#[cfg(not(unix))]
+fn exchange(_request: &[u8], _timeout: Duration) -> std::io::Result<Vec<u8>> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "querying the terminal colors is only supported on Unix",
+    ))
+}
+
+/// Puts a terminal into raw mode until dropped, so answers aren't echoed
+/// and can be read before a newline arrives.
+#[cfg(unix)]
+struct RawMode {
+    fd: libc::c_int,
+    original: libc::termios,
+}
+
+#[cfg(unix)]
+impl RawMode {
+    fn enable(fd: libc::c_int) -> std::io::Result<Self> {
+        let mut termios = std::mem::MaybeUninit::<libc::termios>::uninit();
+        // SAFETY: `tcgetattr` initializes `termios` when it succeeds.
+        if unsafe { libc::tcgetattr(fd, termios.as_mut_ptr()) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        // SAFETY: checked above.
+        let original = unsafe { termios.assume_init() };
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+        raw.c_cc[libc::VMIN] = 0;
+        raw.c_cc[libc::VTIME] = 0;
+        // SAFETY: `raw` is a valid termios copied from the terminal.
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(Self { fd, original })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        // SAFETY: `original` was read from the same terminal.
+        unsafe { libc::tcsetattr(self.fd, libc::TCSANOW, &self.original) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_are_parsed() {
+        // kitty answers with four digits per channel and ST, xterm with BEL
+        // when asked with BEL, followed by the device attributes.
+        let reply = b"\x1b]10;rgb:f8f8/f8f8/f2f2\x1b\\\x1b]11;rgb:28/2a/36\x07\x1b[?62;22c";
+        assert!(answered(reply));
+        assert_eq!(
+            reply_color(reply, b"\x1b]10;"),
+            Some(Color::Rgb(0xf8, 0xf8, 0xf2))
+        );
+        assert_eq!(
+            reply_color(reply, b"\x1b]11;"),
+            Some(Color::Rgb(0x28, 0x2a, 0x36))
+        );
+
+        assert!(!answered(b"\x1b]11;rgb:0000/0000/0000\x07"));
+        assert!(answered(b"\x1b[?1;2c"));
+        assert_eq!(parse_color("rgb:f/8/0"), Some(Color::Rgb(0xff, 0x88, 0x00)));
+        assert_eq!(
+            parse_color("rgba:ffff/0000/0000/ffff"),
+            Some(Color::Rgb(0xff, 0, 0))
+        );
+        assert_eq!(parse_color("#282a36"), Some(Color::Rgb(0x28, 0x2a, 0x36)));
+        assert_eq!(parse_color("rgb:12345/0/0"), None);
+    }
+}
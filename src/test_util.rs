@@ -0,0 +1,205 @@
+//! Random valid palettes for fuzzing and property tests.
+//!
+//! With the `test-util` feature, [`Base16Palette`] implements
+//! [`arbitrary::Arbitrary`] and proptest's [`Arbitrary`](proptest::arbitrary::Arbitrary),
+//! so it can be an input of `cargo fuzz` targets and `proptest!` tests.
+//! Every generated palette is one of [`Base16Palette::random`], so its
+//! shades are ordered and its foreground and accents are readable on its
+//! background. [`PaletteConstraints`] narrows them down further.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use ratatui_base16::{test_util::PaletteConstraints, Base16Palette, Variant};
+//!
+//! proptest! {
+//!     fn renders_any_light_palette(
+//!         palette in any_with::<Base16Palette>(
+//!             PaletteConstraints::new().variant(Variant::Light).min_contrast(4.5),
+//!         ),
+//!     ) {
+//!         prop_assert_eq!(palette.variant(), Variant::Light);
+//!     }
+//! }
+//! # renders_any_light_palette();
+//! ```
+
+use arbitrary::Unstructured;
+use proptest::{
+    arbitrary::any,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{generate, Base16Color, Base16Palette, Variant};
+
+/// The contrast ratios [`PaletteConstraints::min_contrast`] accepts. The
+/// upper end can still be reached by the lightest and darkest colors on any
+/// background [`Base16Palette::random`] picks.
+const CONTRAST_RANGE: (f64, f64) = (1.0, 10.0);
+
+/// The slots held to the contrast floor: the default foreground and the
+/// accents.
+const READABLE: [Base16Color; 9] = [
+    Base16Color::Base05,
+    Base16Color::Base08,
+    Base16Color::Base09,
+    Base16Color::Base0A,
+    Base16Color::Base0B,
+    Base16Color::Base0C,
+    Base16Color::Base0D,
+    Base16Color::Base0E,
+    Base16Color::Base0F,
+];
+
+/// Constraints on the palettes generated for tests.
+///
+/// Both the variant and the contrast floor apply to every palette, so a
+/// failing input still satisfies them after shrinking.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_base16::{test_util::PaletteConstraints, FindingKind, Variant};
+/// let constraints = PaletteConstraints::new()
+///     .variant(Variant::Dark)
+///     .min_contrast(7.0);
+/// let palette = constraints.palette(42);
+/// assert_eq!(palette.variant(), Variant::Dark);
+/// assert!(palette
+///     .validate()
+///     .iter()
+///     .all(|finding| finding.kind != FindingKind::LowAccentContrast));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaletteConstraints {
+    variant: Option<Variant>,
+    min_contrast: f64,
+}
+
+impl PaletteConstraints {
+    /// Creates constraints allowing both variants, with the contrast
+    /// [`Base16Palette::random`] guarantees: 4.5 for the foreground and 3.0
+    /// for the accents.
+    pub const fn new() -> Self {
+        Self {
+            variant: None,
+            min_contrast: 1.0,
+        }
+    }
+
+    /// Only generates palettes of `variant`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Raises the contrast ratio of base05 and of the accents, base08 to
+    /// base0F, against base00 to at least `ratio`.
+    ///
+    /// The ratio is clamped to 1.0 to 10.0, the range every generated
+    /// background allows.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn min_contrast(mut self, ratio: f64) -> Self {
+        self.min_contrast = ratio.clamp(CONTRAST_RANGE.0, CONTRAST_RANGE.1);
+        self
+    }
+
+    /// Returns the palette generated from `seed`, always the same for the
+    /// same seed and constraints.
+    pub fn palette(&self, seed: u64) -> Base16Palette {
+        let variant = self.variant.unwrap_or(if seed & 1 == 0 {
+            Variant::Dark
+        } else {
+            Variant::Light
+        });
+        let mut palette = Base16Palette::random(seed, variant);
+        let background = palette.base00;
+        for slot in READABLE {
+            let color = palette.color(slot);
+            *palette.color_mut(slot) =
+                generate::with_contrast(color, background, variant, self.min_contrast);
+        }
+        palette
+    }
+
+    /// Returns a palette drawn from the fuzzer's input. Input that runs out
+    /// reads as zeros, so this only fails if `u` does.
+    pub fn arbitrary_palette(&self, u: &mut Unstructured<'_>) -> arbitrary::Result<Base16Palette> {
+        Ok(self.palette(u.arbitrary()?))
+    }
+
+    /// Returns a proptest strategy for palettes, shrinking towards seed 0.
+    pub fn strategy(self) -> BoxedStrategy<Base16Palette> {
+        any::<u64>()
+            .prop_map(move |seed| self.palette(seed))
+            .boxed()
+    }
+}
+
+impl Default for PaletteConstraints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> arbitrary::Arbitrary<'a> for Base16Palette {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        PaletteConstraints::new().arbitrary_palette(u)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u64 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+impl proptest::arbitrary::Arbitrary for Base16Palette {
+    type Parameters = PaletteConstraints;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(constraints: PaletteConstraints) -> Self::Strategy {
+        constraints.strategy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{strategy::ValueTree, test_runner::TestRunner};
+
+    use super::*;
+    use crate::color;
+
+    #[test]
+    fn palettes_meet_their_constraints() {
+        let constraints = PaletteConstraints::new()
+            .variant(Variant::Light)
+            .min_contrast(CONTRAST_RANGE.1);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let palette = constraints
+                .strategy()
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert_eq!(palette.variant(), Variant::Light);
+            for slot in READABLE {
+                let ratio = color::contrast_ratio(palette.color(slot), palette.base00).unwrap();
+                assert!(
+                    ratio >= CONTRAST_RANGE.1,
+                    "{} {slot}: {ratio}",
+                    palette.name
+                );
+            }
+        }
+
+        let data = [0x5a; 64];
+        let mut u = Unstructured::new(&data);
+        let palette: Base16Palette = u.arbitrary().unwrap();
+        assert_eq!(
+            palette,
+            PaletteConstraints::new().palette(0x5a5a_5a5a_5a5a_5a5a)
+        );
+        assert!(PaletteConstraints::new()
+            .arbitrary_palette(&mut Unstructured::new(&[]))
+            .is_ok());
+    }
+}
@@ -0,0 +1,176 @@
+//! Mapping of tree-sitter highlight capture names onto palette styles.
+
+use std::collections::HashMap;
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::Base16Palette;
+
+/// The standard tree-sitter highlight capture names understood by
+/// [`Base16Palette::tree_sitter_styles`], without the leading `@`.
+///
+/// The order of this slice is stable and matches the styles returned by
+/// [`Base16Palette::tree_sitter_highlight_styles`], so it can be passed
+/// directly to `tree_sitter_highlight::HighlightConfiguration::configure`
+/// and the resulting `Highlight` indices used to look up a style.
+pub const TREE_SITTER_CAPTURES: &[&str] = &[
+    "attribute",
+    "boolean",
+    "character",
+    "comment",
+    "constant",
+    "constant.builtin",
+    "constructor",
+    "embedded",
+    "escape",
+    "float",
+    "function",
+    "function.builtin",
+    "function.macro",
+    "function.method",
+    "keyword",
+    "label",
+    "module",
+    "namespace",
+    "number",
+    "operator",
+    "property",
+    "punctuation",
+    "punctuation.bracket",
+    "punctuation.delimiter",
+    "punctuation.special",
+    "string",
+    "string.escape",
+    "string.regexp",
+    "string.special",
+    "tag",
+    "tag.attribute",
+    "type",
+    "type.builtin",
+    "variable",
+    "variable.builtin",
+    "variable.parameter",
+    "markup.heading",
+    "markup.bold",
+    "markup.italic",
+    "markup.strikethrough",
+    "markup.link",
+    "markup.list",
+    "markup.quote",
+    "markup.raw",
+    "diff.plus",
+    "diff.minus",
+    "diff.delta",
+];
+
+impl Base16Palette {
+    /// Returns the style for every capture name in [`TREE_SITTER_CAPTURES`].
+    ///
+    /// Captures are colored following the base16 styling guidelines, e.g.
+    /// `keyword` uses base0E, `string` uses base0B and `function` uses
+    /// base0D. Only the foreground and modifiers are set, so the styles can be
+    /// patched over a background of choice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let styles = DRACULA.tree_sitter_styles();
+    /// assert_eq!(styles["keyword"].fg, Some(DRACULA.base0e));
+    /// ```
+    pub fn tree_sitter_styles(&self) -> HashMap<&'static str, Style> {
+        TREE_SITTER_CAPTURES
+            .iter()
+            .copied()
+            .zip(self.tree_sitter_highlight_styles())
+            .collect()
+    }
+
+    /// Returns the styles for [`TREE_SITTER_CAPTURES`] in the same order, for
+    /// use with `tree-sitter-highlight`'s `Highlight` indices.
+    pub fn tree_sitter_highlight_styles(&self) -> Vec<Style> {
+        TREE_SITTER_CAPTURES
+            .iter()
+            .map(|capture| self.capture_style(capture))
+            .collect()
+    }
+
+    /// Returns the style for a single tree-sitter capture name.
+    ///
+    /// A leading `@` is ignored. Names that are not listed in
+    /// [`TREE_SITTER_CAPTURES`] fall back to their longest known prefix, so
+    /// `@keyword.control.return` is styled like `keyword`. Unknown names yield
+    /// the default foreground (base05).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::DRACULA;
+    /// let style = DRACULA.tree_sitter_style("@function.call");
+    /// assert_eq!(style.fg, Some(DRACULA.base0d));
+    /// ```
+    pub fn tree_sitter_style(&self, capture: &str) -> Style {
+        let mut name = capture.trim_start_matches('@');
+        loop {
+            if TREE_SITTER_CAPTURES.contains(&name) {
+                return self.capture_style(name);
+            }
+            match name.rsplit_once('.') {
+                Some((parent, _)) => name = parent,
+                None => return Style::new().fg(self.base05),
+            }
+        }
+    }
+
+    fn capture_style(&self, capture: &str) -> Style {
+        let fg = |color: Color| Style::new().fg(color);
+        match capture {
+            "comment" => fg(self.base03).add_modifier(Modifier::ITALIC),
+            "operator" | "punctuation" | "punctuation.bracket" | "punctuation.delimiter" => {
+                fg(self.base05)
+            }
+            "variable" | "variable.parameter" | "property" | "tag" | "function.macro"
+            | "markup.list" | "diff.minus" => fg(self.base08),
+            "boolean" | "character" | "constant" | "constant.builtin" | "number" | "float"
+            | "tag.attribute" | "variable.builtin" => fg(self.base09),
+            "markup.link" => fg(self.base09).add_modifier(Modifier::UNDERLINED),
+            "attribute" | "label" | "type" | "type.builtin" => fg(self.base0a),
+            "markup.bold" => fg(self.base0a).add_modifier(Modifier::BOLD),
+            "string" | "markup.raw" | "diff.plus" => fg(self.base0b),
+            "escape" | "string.escape" | "string.regexp" | "string.special" | "markup.quote" => {
+                fg(self.base0c)
+            }
+            "function" | "function.builtin" | "function.method" | "constructor" | "module"
+            | "namespace" => fg(self.base0d),
+            "markup.heading" => fg(self.base0d).add_modifier(Modifier::BOLD),
+            "keyword" | "diff.delta" => fg(self.base0e),
+            "markup.italic" => fg(self.base0e).add_modifier(Modifier::ITALIC),
+            "embedded" | "punctuation.special" => fg(self.base0f),
+            "markup.strikethrough" => fg(self.base03).add_modifier(Modifier::CROSSED_OUT),
+            _ => fg(self.base05),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DRACULA;
+
+    #[test]
+    fn styles_follow_capture_order() {
+        let styles = DRACULA.tree_sitter_highlight_styles();
+        assert_eq!(styles.len(), TREE_SITTER_CAPTURES.len());
+        let string = TREE_SITTER_CAPTURES.iter().position(|c| *c == "string");
+        assert_eq!(styles[string.unwrap()].fg, Some(DRACULA.base0b));
+    }
+
+    #[test]
+    fn unknown_captures_fall_back_to_prefix() {
+        assert_eq!(
+            DRACULA.tree_sitter_style("@keyword.control.return"),
+            DRACULA.tree_sitter_style("keyword")
+        );
+        assert_eq!(DRACULA.tree_sitter_style("nope").fg, Some(DRACULA.base05));
+    }
+}
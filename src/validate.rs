@@ -0,0 +1,292 @@
+//! Checks of palettes against the base16 styling guidelines.
+
+use std::fmt;
+
+use crate::{color, Base16Color, Base16Palette};
+
+/// The minimum contrast ratio of the default foreground against the default
+/// background, the WCAG AA level for body text.
+const MIN_TEXT_CONTRAST: f64 = 4.5;
+
+/// The minimum contrast ratio of accents against the default background, the
+/// WCAG AA level for large text and graphical objects.
+const MIN_ACCENT_CONTRAST: f64 = 3.0;
+
+/// Accents closer than this CIEDE2000 difference are reported as near
+/// duplicates.
+const MIN_ACCENT_DELTA_E: f64 = 5.0;
+
+/// Accents with a CIE chroma below this are reported as desaturated.
+const MIN_ACCENT_CHROMA: f64 = 10.0;
+
+/// How serious a [`Finding`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The palette deviates from the guidelines but is usable.
+    Warning,
+    /// The palette is likely to render unreadable text.
+    Error,
+}
+
+/// The kind of problem reported by a [`Finding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FindingKind {
+    /// The shades base00 to base07 are not ordered by luminance.
+    UnorderedShades,
+    /// The default foreground (base05) does not contrast enough with the
+    /// default background (base00).
+    LowTextContrast,
+    /// An accent is hard to tell apart from the default background.
+    LowAccentContrast,
+    /// Two accents have exactly the same color.
+    DuplicateAccent,
+    /// Two accents are perceptually almost identical.
+    SimilarAccents,
+    /// An accent is close to gray.
+    DesaturatedAccent,
+}
+
+/// A problem found by [`Base16Palette::validate`] or [`Base16Palette::lint`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// What kind of problem was found.
+    pub kind: FindingKind,
+    /// The slots involved.
+    pub slots: Vec<Base16Color>,
+    /// A human readable description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{severity}: {}", self.message)
+    }
+}
+
+impl Base16Palette {
+    /// Checks the palette against the base16 structural guidelines and
+    /// returns the problems found, most serious first.
+    ///
+    /// The following is checked:
+    ///
+    /// - base00 to base07 run monotonically from dark to light (or light to
+    ///   dark for light schemes),
+    /// - base05 has a contrast ratio of at least 4.5:1 against base00,
+    /// - every accent has a contrast ratio of at least 3:1 against base00.
+    ///
+    /// Colors without a concrete value, such as `Color::Reset`, are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// # use ratatui_base16::{FindingKind, DEFAULT_DARK};
+    /// assert!(DEFAULT_DARK.validate().is_empty());
+    ///
+    /// let findings = DEFAULT_DARK.with_base05(Color::Rgb(0x30, 0x30, 0x30)).validate();
+    /// assert_eq!(findings[0].kind, FindingKind::LowTextContrast);
+    /// ```
+    pub fn validate(&self) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        if let Some((a, b)) = self.unordered_shades() {
+            findings.push(Finding {
+                severity: Severity::Warning,
+                kind: FindingKind::UnorderedShades,
+                slots: vec![a, b],
+                message: format!(
+                    "{a} and {b} are out of order, shades should run from {}",
+                    match self.variant() {
+                        crate::Variant::Dark => "dark to light",
+                        crate::Variant::Light => "light to dark",
+                    }
+                ),
+            });
+        }
+
+        if let Some(ratio) = self.contrast(Base16Color::Base05) {
+            if ratio < MIN_TEXT_CONTRAST {
+                findings.push(Finding {
+                    severity: Severity::Error,
+                    kind: FindingKind::LowTextContrast,
+                    slots: vec![Base16Color::Base05, Base16Color::Base00],
+                    message: format!(
+                        "contrast of base05 against base00 is {ratio:.2}:1, \
+                         below {MIN_TEXT_CONTRAST}:1"
+                    ),
+                });
+            }
+        }
+
+        for (slot, _) in self.accents() {
+            if let Some(ratio) = self.contrast(slot).filter(|r| *r < MIN_ACCENT_CONTRAST) {
+                findings.push(Finding {
+                    severity: Severity::Warning,
+                    kind: FindingKind::LowAccentContrast,
+                    slots: vec![slot, Base16Color::Base00],
+                    message: format!(
+                        "contrast of {slot} against base00 is {ratio:.2}:1, \
+                         below {MIN_ACCENT_CONTRAST}:1"
+                    ),
+                });
+            }
+        }
+
+        findings.sort_by_key(|finding| std::cmp::Reverse(finding.severity));
+        findings
+    }
+
+    /// Looks for likely mistakes in the accents base08 to base0F and returns
+    /// the problems found.
+    ///
+    /// This complements [`validate`](Self::validate) and reports:
+    ///
+    /// - accents with exactly the same color, often a copy-paste error,
+    /// - accents with a CIEDE2000 difference below 5, which are hard to tell
+    ///   apart,
+    /// - accents with a chroma below 10, which look gray.
+    ///
+    /// All lint findings are warnings, as some schemes reuse accents on
+    /// purpose.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Base16Color, FindingKind, DRACULA};
+    /// let findings = DRACULA.lint();
+    /// assert_eq!(findings[0].kind, FindingKind::DuplicateAccent);
+    /// assert_eq!(findings[0].slots, [Base16Color::Base09, Base16Color::Base0E]);
+    /// ```
+    pub fn lint(&self) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let accents: Vec<_> = self.accents().collect();
+
+        for (i, &(a, a_color)) in accents.iter().enumerate() {
+            for &(b, b_color) in &accents[i + 1..] {
+                if a_color == b_color {
+                    findings.push(Finding {
+                        severity: Severity::Warning,
+                        kind: FindingKind::DuplicateAccent,
+                        slots: vec![a, b],
+                        message: format!("{a} and {b} are the same color"),
+                    });
+                } else if let Some(delta) =
+                    color::delta_e(a_color, b_color).filter(|delta| *delta < MIN_ACCENT_DELTA_E)
+                {
+                    findings.push(Finding {
+                        severity: Severity::Warning,
+                        kind: FindingKind::SimilarAccents,
+                        slots: vec![a, b],
+                        message: format!("{a} and {b} are nearly identical (delta E {delta:.2})"),
+                    });
+                }
+            }
+        }
+
+        for &(slot, accent) in &accents {
+            if let Some(chroma) = color::chroma(accent).filter(|c| *c < MIN_ACCENT_CHROMA) {
+                findings.push(Finding {
+                    severity: Severity::Warning,
+                    kind: FindingKind::DesaturatedAccent,
+                    slots: vec![slot],
+                    message: format!("{slot} is nearly gray (chroma {chroma:.2})"),
+                });
+            }
+        }
+
+        findings
+    }
+
+    /// Returns the contrast ratio of `slot` against base00.
+    pub(crate) fn contrast(&self, slot: Base16Color) -> Option<f64> {
+        color::contrast_ratio(self.color(slot), self.base00)
+    }
+
+    /// Returns the first pair of adjacent shades that breaks the luminance
+    /// ordering of base00 to base07.
+    pub(crate) fn unordered_shades(&self) -> Option<(Base16Color, Base16Color)> {
+        let luminances: Vec<(Base16Color, f64)> = self
+            .shades()
+            .map(|(slot, color)| Some((slot, color::relative_luminance(color)?)))
+            .collect::<Option<_>>()?;
+        let dark = self.variant() == crate::Variant::Dark;
+        luminances.windows(2).find_map(|pair| {
+            let ordered = if dark {
+                pair[0].1 <= pair[1].1
+            } else {
+                pair[0].1 >= pair[1].1
+            };
+            (!ordered).then_some((pair[0].0, pair[1].0))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_DARK, DEFAULT_LIGHT, DRACULA, GITHUB_LIGHT};
+
+    #[test]
+    fn pastel_accents_on_light_background_are_reported() {
+        let findings = DEFAULT_LIGHT.validate();
+        assert!(findings
+            .iter()
+            .all(|f| f.kind == FindingKind::LowAccentContrast));
+        assert!(findings.iter().any(|f| f.slots[0] == Base16Color::Base0A));
+    }
+
+    #[test]
+    fn unordered_shades_are_reported() {
+        // Github's base04 is lighter than base03.
+        let findings = GITHUB_LIGHT.validate();
+        let finding = findings
+            .iter()
+            .find(|f| f.kind == FindingKind::UnorderedShades)
+            .unwrap();
+        assert_eq!(finding.slots, [Base16Color::Base03, Base16Color::Base04]);
+        assert_eq!(finding.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn lint_reports_copy_paste_accents() {
+        let kinds: Vec<_> = DRACULA
+            .lint()
+            .into_iter()
+            .map(|f| (f.kind, f.slots))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (
+                    FindingKind::DuplicateAccent,
+                    vec![Base16Color::Base09, Base16Color::Base0E]
+                ),
+                (
+                    FindingKind::DuplicateAccent,
+                    vec![Base16Color::Base0A, Base16Color::Base0F]
+                ),
+            ]
+        );
+        assert!(DEFAULT_DARK.lint().is_empty());
+    }
+
+    #[test]
+    fn lint_reports_gray_and_similar_accents() {
+        use ratatui::style::Color;
+        let palette = DEFAULT_DARK
+            .with_base0f(Color::Rgb(0x80, 0x80, 0x80))
+            .with_base09(Color::Rgb(0xad, 0x47, 0x43));
+        let kinds: Vec<_> = palette.lint().into_iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            [FindingKind::SimilarAccents, FindingKind::DesaturatedAccent]
+        );
+    }
+}
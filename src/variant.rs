@@ -0,0 +1,58 @@
+//! Light and dark scheme variants.
+
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::{color, Base16Palette};
+
+/// Whether a scheme has a dark or a light background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Light text on a dark background.
+    Dark,
+    /// Dark text on a light background.
+    Light,
+}
+
+impl Variant {
+    /// Returns the lowercase name used in scheme files, `"dark"` or
+    /// `"light"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Base16Palette {
+    /// Returns whether the palette is a dark or a light scheme.
+    ///
+    /// A scheme is considered dark when its default background (base00) is
+    /// darker than its default foreground (base05). Palettes whose colors
+    /// have no concrete value, such as `Color::Reset`, are treated as dark.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_base16::{Variant, DEFAULT_DARK, DEFAULT_LIGHT};
+    /// assert_eq!(DEFAULT_DARK.variant(), Variant::Dark);
+    /// assert_eq!(DEFAULT_LIGHT.variant(), Variant::Light);
+    /// ```
+    pub fn variant(&self) -> Variant {
+        match (
+            color::relative_luminance(self.base00),
+            color::relative_luminance(self.base05),
+        ) {
+            (Some(background), Some(foreground)) if background > foreground => Variant::Light,
+            _ => Variant::Dark,
+        }
+    }
+}
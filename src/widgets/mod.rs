@@ -0,0 +1,29 @@
+//! Style bundles for the built-in ratatui widgets.
+//!
+//! A [`Theme`] groups ready-made styles for the most commonly used widgets so
+//! applications don't have to derive them from a [`Base16Palette`] by hand.
+//!
+//! ```rust
+//! use ratatui::widgets::{Block, List};
+//! use ratatui_base16::{widgets::Theme, DRACULA};
+//!
+//! let theme = Theme::from(&DRACULA);
+//! let block = theme.block.apply(Block::bordered().title("Files"), true);
+//! let list = theme.list.apply(List::new(["Cargo.toml", "src"]).block(block));
+//! ```
+//!
+//! The [`PalettePreview`] widget renders the palette itself, which is useful
+//! for debugging themes, and the [`ThemePicker`] lets users choose between
+//! palettes.
+//!
+//! [`Base16Palette`]: crate::Base16Palette
+
+mod picker;
+mod preview;
+mod theme;
+
+pub use picker::{ThemePicker, ThemePickerState};
+pub use preview::PalettePreview;
+pub use theme::{
+    BlockTheme, GaugeTheme, ListTheme, ParagraphTheme, ScrollbarTheme, TableTheme, TabsTheme, Theme,
+};
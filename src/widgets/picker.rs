@@ -0,0 +1,265 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, List, ListState, StatefulWidget, Widget},
+};
+
+use crate::{widgets::Theme, Base16Color, Base16Palette, BUILTIN_PALETTES, DEFAULT_DARK};
+
+/// A stateful widget listing palettes to choose from.
+///
+/// Each entry shows the palette name followed by swatches of its sixteen
+/// colors, and the picker itself is styled with the currently selected
+/// palette so the selection is previewed live. The list of palettes, the
+/// search filter and the selection live in a [`ThemePickerState`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+/// use ratatui_base16::widgets::{ThemePicker, ThemePickerState};
+///
+/// let mut state = ThemePickerState::new();
+/// state.set_filter("pine");
+/// state.select_next();
+///
+/// let area = Rect::new(0, 0, 40, 10);
+/// let mut buf = Buffer::empty(area);
+/// ThemePicker::new().render(area, &mut buf, &mut state);
+/// assert_eq!(state.selected().unwrap().name, "Rosé Pine Dawn");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ThemePicker<'a> {
+    block: Option<Block<'a>>,
+}
+
+impl<'a> ThemePicker<'a> {
+    /// Creates a new picker.
+    pub const fn new() -> Self {
+        Self { block: None }
+    }
+
+    /// Wraps the picker in a [`Block`].
+    ///
+    /// The border and title styles of the block are taken from the selected
+    /// palette.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl StatefulWidget for ThemePicker<'_> {
+    type State = ThemePickerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let palette = state.selected().unwrap_or(&DEFAULT_DARK).clone();
+        let theme = Theme::from(&palette);
+
+        buf.set_style(area, theme.paragraph.text);
+        let area = match self.block {
+            Some(block) => {
+                let block = theme.block.apply(block, true);
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        let [filter_area, list_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+        Line::from(vec![
+            Span::styled("/ ", Style::new().fg(palette.base0d)),
+            Span::raw(state.filter.as_str()),
+        ])
+        .render(filter_area, buf);
+
+        let width = state
+            .matches
+            .iter()
+            .map(|&index| state.palettes[index].name.chars().count())
+            .max()
+            .unwrap_or_default();
+        let items = state.matches.iter().map(|&index| {
+            let entry = &state.palettes[index];
+            let mut spans = vec![Span::raw(format!("{:width$} ", entry.name))];
+            spans.extend(
+                Base16Color::ALL
+                    .iter()
+                    .map(|slot| Span::styled("█", Style::new().fg(entry.color(*slot)))),
+            );
+            Line::from(spans)
+        });
+        let list = theme.list.apply(List::new(items));
+        StatefulWidget::render(list, list_area, buf, &mut state.list_state);
+    }
+}
+
+/// The state of a [`ThemePicker`]: the available palettes, the search filter
+/// and the current selection.
+#[derive(Debug, Clone)]
+pub struct ThemePickerState {
+    palettes: Vec<Base16Palette>,
+    filter: String,
+    /// Indices into `palettes` of the entries matching `filter`.
+    matches: Vec<usize>,
+    list_state: ListState,
+}
+
+impl Default for ThemePickerState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThemePickerState {
+    /// Creates a state listing all [`BUILTIN_PALETTES`], with the first one
+    /// selected.
+    pub fn new() -> Self {
+        Self::with_palettes(BUILTIN_PALETTES.iter().cloned())
+    }
+
+    /// Creates a state listing only the given palettes.
+    pub fn with_palettes(palettes: impl IntoIterator<Item = Base16Palette>) -> Self {
+        let mut state = Self {
+            palettes: palettes.into_iter().collect(),
+            filter: String::new(),
+            matches: Vec::new(),
+            list_state: ListState::default(),
+        };
+        state.update_matches();
+        state
+    }
+
+    /// Adds user-supplied palettes to the list.
+    pub fn extend(&mut self, palettes: impl IntoIterator<Item = Base16Palette>) {
+        self.palettes.extend(palettes);
+        self.update_matches();
+    }
+
+    /// Returns all palettes known to the picker, regardless of the filter.
+    pub fn palettes(&self) -> &[Base16Palette] {
+        &self.palettes
+    }
+
+    /// Returns the palettes matching the current filter, in display order.
+    pub fn matches(&self) -> impl Iterator<Item = &Base16Palette> {
+        self.matches.iter().map(|&index| &self.palettes[index])
+    }
+
+    /// Returns the current search filter.
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Replaces the search filter.
+    ///
+    /// Palettes whose name contains the filter, ignoring case, are listed.
+    /// The selection is kept if the selected palette still matches, and
+    /// otherwise moves to the first match.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        self.update_matches();
+    }
+
+    /// Appends a character to the search filter, e.g. on a key press.
+    pub fn push_filter(&mut self, c: char) {
+        self.filter.push(c);
+        self.update_matches();
+    }
+
+    /// Removes the last character of the search filter, e.g. on backspace.
+    pub fn pop_filter(&mut self) {
+        self.filter.pop();
+        self.update_matches();
+    }
+
+    /// Returns the selected palette, if any palette matches the filter.
+    pub fn selected(&self) -> Option<&Base16Palette> {
+        let index = *self.matches.get(self.list_state.selected()?)?;
+        self.palettes.get(index)
+    }
+
+    /// Selects the next matching palette, wrapping around at the end.
+    pub fn select_next(&mut self) {
+        let len = self.matches.len();
+        if len > 0 {
+            let next = self.list_state.selected().map_or(0, |i| (i + 1) % len);
+            self.list_state.select(Some(next));
+        }
+    }
+
+    /// Selects the previous matching palette, wrapping around at the start.
+    pub fn select_previous(&mut self) {
+        let len = self.matches.len();
+        if len > 0 {
+            let previous = self
+                .list_state
+                .selected()
+                .map_or(0, |i| (i + len - 1) % len);
+            self.list_state.select(Some(previous));
+        }
+    }
+
+    fn update_matches(&mut self) {
+        let selected = self
+            .list_state
+            .selected()
+            .and_then(|i| self.matches.get(i).copied());
+        let filter = self.filter.to_lowercase();
+        self.matches = (0..self.palettes.len())
+            .filter(|&index| self.palettes[index].name.to_lowercase().contains(&filter))
+            .collect();
+        let position = selected
+            .and_then(|selected| self.matches.iter().position(|&index| index == selected))
+            .or((!self.matches.is_empty()).then_some(0));
+        self.list_state.select(position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DRACULA, ROSE_PINE, ROSE_PINE_MOON};
+
+    #[test]
+    fn filter_keeps_selection_when_possible() {
+        let mut state = ThemePickerState::new();
+        state.set_filter("ROSÉ");
+        assert_eq!(state.matches().count(), 3);
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected().unwrap().name, ROSE_PINE_MOON.name);
+
+        state.push_filter(' ');
+        state.push_filter('p');
+        state.push_filter('i');
+        state.push_filter('n');
+        state.push_filter('e');
+        state.push_filter(' ');
+        state.push_filter('m');
+        assert_eq!(state.selected().unwrap().name, ROSE_PINE_MOON.name);
+
+        state.set_filter("dracula");
+        assert_eq!(state.selected().unwrap().name, DRACULA.name);
+        state.set_filter("no such theme");
+        assert!(state.selected().is_none());
+    }
+
+    #[test]
+    fn renders_with_selected_palette() {
+        let mut state = ThemePickerState::with_palettes([DRACULA, ROSE_PINE]);
+        state.select_previous();
+        let area = Rect::new(0, 0, 30, 3);
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new().render(area, &mut buf, &mut state);
+
+        let name: String = (2..11).map(|x| buf[(x, 2)].symbol()).collect();
+        assert_eq!(name, "Rosé Pine");
+        assert_eq!(buf[(0, 0)].bg, ROSE_PINE.base00);
+    }
+}
@@ -0,0 +1,122 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Widget},
+};
+
+use crate::{color, Base16Color, Base16Palette};
+
+/// A widget that renders the sixteen colors of a palette as a grid of
+/// swatches, each labelled with its slot name and hex value.
+///
+/// This is handy for debugging themes inside a running application and for
+/// theme settings screens.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::widgets::Block;
+/// use ratatui_base16::{widgets::PalettePreview, DRACULA};
+///
+/// let preview = PalettePreview::new(&DRACULA)
+///     .columns(2)
+///     .block(Block::bordered().title(DRACULA.name));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PalettePreview<'a> {
+    palette: &'a Base16Palette,
+    block: Option<Block<'a>>,
+    columns: u16,
+}
+
+impl<'a> PalettePreview<'a> {
+    /// Creates a preview of `palette` laid out in four columns.
+    pub const fn new(palette: &'a Base16Palette) -> Self {
+        Self {
+            palette,
+            block: None,
+            columns: 4,
+        }
+    }
+
+    /// Sets the number of columns of the grid, clamped to `1..=16`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns.clamp(1, 16);
+        self
+    }
+
+    /// Wraps the preview in a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl Widget for PalettePreview<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &PalettePreview<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let text = Style::new().fg(self.palette.base05).bg(self.palette.base00);
+        buf.set_style(area, text);
+        let area = match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+
+        let columns = usize::from(self.columns);
+        let rows = Base16Color::ALL.len().div_ceil(columns);
+        let row_areas = Layout::vertical(vec![Constraint::Length(1); rows]).split(area);
+        for (slots, row_area) in Base16Color::ALL.chunks(columns).zip(row_areas.iter()) {
+            let cell_areas =
+                Layout::horizontal(vec![Constraint::Ratio(1, columns as u32); columns])
+                    .split(*row_area);
+            for (slot, cell_area) in slots.iter().zip(cell_areas.iter()) {
+                let color = self.palette.color(*slot);
+                let hex = color::to_hex(color).unwrap_or_else(|| "reset".to_string());
+                Line::from(vec![
+                    Span::styled("    ", Style::new().bg(color)),
+                    Span::raw(format!(" {} {hex}", slot.name())),
+                ])
+                .style(text)
+                .render(*cell_area, buf);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DRACULA;
+
+    #[test]
+    fn renders_swatches_and_labels() {
+        let area = Rect::new(0, 0, 20, 16);
+        let mut buf = Buffer::empty(area);
+        PalettePreview::new(&DRACULA)
+            .columns(1)
+            .render(area, &mut buf);
+
+        let line = |y: u16| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(line(0), "     base00 #282936 ");
+        assert_eq!(line(13), "     base0D #62d6e8 ");
+        assert_eq!(buf[(0, 13)].bg, DRACULA.base0d);
+        assert_eq!(buf[(5, 13)].fg, DRACULA.base05);
+    }
+}
@@ -0,0 +1,327 @@
+use std::borrow::Cow;
+
+use ratatui::{
+    style::{Modifier, Style},
+    symbols,
+    text::Span,
+    widgets::{Block, Gauge, LineGauge, List, Paragraph, Scrollbar, Table, Tabs},
+};
+
+use crate::{color, Base16Palette};
+
+/// Style bundles for all supported widgets, derived from a single palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// Styles for [`List`].
+    pub list: ListTheme,
+    /// Styles for [`Table`].
+    pub table: TableTheme,
+    /// Styles for [`Block`].
+    pub block: BlockTheme,
+    /// Styles for [`Paragraph`].
+    pub paragraph: ParagraphTheme,
+    /// Styles for [`Tabs`].
+    pub tabs: TabsTheme,
+    /// Styles for [`Scrollbar`].
+    pub scrollbar: ScrollbarTheme,
+    /// Styles for [`Gauge`] and [`LineGauge`].
+    pub gauge: GaugeTheme,
+}
+
+impl From<&Base16Palette> for Theme {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            list: ListTheme::from(palette),
+            table: TableTheme::from(palette),
+            block: BlockTheme::from(palette),
+            paragraph: ParagraphTheme::from(palette),
+            tabs: TabsTheme::from(palette),
+            scrollbar: ScrollbarTheme::from(palette),
+            gauge: GaugeTheme::from(palette),
+        }
+    }
+}
+
+/// Styles for a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListTheme {
+    /// Style of unselected items.
+    pub item: Style,
+    /// Style of the selected item.
+    pub selected: Style,
+    /// Symbol displayed in front of the selected item.
+    pub highlight_symbol: &'static str,
+}
+
+impl ListTheme {
+    /// Applies the item and selection styles to a [`List`].
+    pub fn apply<'a>(&self, list: List<'a>) -> List<'a> {
+        list.style(self.item)
+            .highlight_style(self.selected)
+            .highlight_symbol(self.highlight_symbol)
+    }
+}
+
+impl From<&Base16Palette> for ListTheme {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            item: Style::new().fg(palette.base05).bg(palette.base00),
+            selected: Style::new()
+                .fg(palette.base0d)
+                .bg(palette.base02)
+                .add_modifier(Modifier::BOLD),
+            highlight_symbol: "> ",
+        }
+    }
+}
+
+/// Styles for a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TableTheme {
+    /// Style of the header row.
+    pub header: Style,
+    /// Style of even rows.
+    pub row: Style,
+    /// Style of odd rows, for striped tables.
+    pub alt_row: Style,
+    /// Style of the selected row.
+    pub selected: Style,
+}
+
+impl TableTheme {
+    /// Returns the style for the row at `index`, alternating between
+    /// [`row`](Self::row) and [`alt_row`](Self::alt_row).
+    pub fn row_style(&self, index: usize) -> Style {
+        if index & 1 == 0 {
+            self.row
+        } else {
+            self.alt_row
+        }
+    }
+
+    /// Applies the row and selection styles to a [`Table`].
+    ///
+    /// The header style has to be set on the header [`Row`] itself, and
+    /// striping requires styling each row with [`row_style`].
+    ///
+    /// [`Row`]: ratatui::widgets::Row
+    /// [`row_style`]: Self::row_style
+    pub fn apply<'a>(&self, table: Table<'a>) -> Table<'a> {
+        table.style(self.row).row_highlight_style(self.selected)
+    }
+}
+
+impl From<&Base16Palette> for TableTheme {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            header: Style::new()
+                .fg(palette.base0d)
+                .bg(palette.base01)
+                .add_modifier(Modifier::BOLD),
+            row: Style::new().fg(palette.base05).bg(palette.base00),
+            alt_row: Style::new().fg(palette.base05).bg(palette.base01),
+            selected: Style::new()
+                .fg(palette.base0d)
+                .bg(palette.base02)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+/// Styles for a [`Block`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockTheme {
+    /// Style of the borders of an unfocused block.
+    pub border: Style,
+    /// Style of the borders of a focused block.
+    pub focused_border: Style,
+    /// Style of the title.
+    pub title: Style,
+}
+
+impl BlockTheme {
+    /// Applies the border and title styles to a [`Block`].
+    pub fn apply<'a>(&self, block: Block<'a>, focused: bool) -> Block<'a> {
+        let border = if focused {
+            self.focused_border
+        } else {
+            self.border
+        };
+        block.border_style(border).title_style(self.title)
+    }
+}
+
+impl From<&Base16Palette> for BlockTheme {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            border: Style::new().fg(palette.base03),
+            focused_border: Style::new().fg(palette.base0d),
+            title: Style::new().fg(palette.base05).add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+/// Styles for a [`Paragraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParagraphTheme {
+    /// Style of the text.
+    pub text: Style,
+}
+
+impl ParagraphTheme {
+    /// Applies the text style to a [`Paragraph`].
+    pub fn apply<'a>(&self, paragraph: Paragraph<'a>) -> Paragraph<'a> {
+        paragraph.style(self.text)
+    }
+}
+
+impl From<&Base16Palette> for ParagraphTheme {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            text: Style::new().fg(palette.base05).bg(palette.base00),
+        }
+    }
+}
+
+/// Styles for [`Tabs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TabsTheme {
+    /// Style of the selected tab.
+    pub active: Style,
+    /// Style of the other tabs.
+    pub inactive: Style,
+    /// Style of the divider between tabs.
+    pub divider: Style,
+}
+
+impl TabsTheme {
+    /// Applies the tab and divider styles to [`Tabs`].
+    pub fn apply<'a>(&self, tabs: Tabs<'a>) -> Tabs<'a> {
+        tabs.style(self.inactive)
+            .highlight_style(self.active)
+            .divider(Span::styled(symbols::line::VERTICAL, self.divider))
+    }
+}
+
+impl From<&Base16Palette> for TabsTheme {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            active: Style::new()
+                .fg(palette.base0d)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            inactive: Style::new().fg(palette.base04),
+            divider: Style::new().fg(palette.base03),
+        }
+    }
+}
+
+/// Styles for a [`Scrollbar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrollbarTheme {
+    /// Style of the thumb that indicates the current position.
+    pub thumb: Style,
+    /// Style of the track the thumb moves along.
+    pub track: Style,
+}
+
+impl ScrollbarTheme {
+    /// Applies the thumb and track styles to a [`Scrollbar`].
+    pub fn apply<'a>(&self, scrollbar: Scrollbar<'a>) -> Scrollbar<'a> {
+        scrollbar.thumb_style(self.thumb).track_style(self.track)
+    }
+}
+
+impl From<&Base16Palette> for ScrollbarTheme {
+    fn from(palette: &Base16Palette) -> Self {
+        Self {
+            thumb: Style::new().fg(palette.base04),
+            track: Style::new().fg(palette.base02),
+        }
+    }
+}
+
+/// Styles for a [`Gauge`] or [`LineGauge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GaugeTheme {
+    /// Style of the filled part of the gauge.
+    pub filled: Style,
+    /// Style of the unfilled part of the gauge.
+    pub unfilled: Style,
+    /// Style of the label drawn on top of the gauge.
+    pub label: Style,
+}
+
+impl GaugeTheme {
+    /// Applies the filled and unfilled styles to a [`Gauge`].
+    ///
+    /// A `Gauge` draws the filled part with the foreground and the unfilled
+    /// part with the background of a single style, so the foreground of
+    /// [`unfilled`](Self::unfilled) becomes the background of the gauge.
+    pub fn apply<'a>(&self, gauge: Gauge<'a>) -> Gauge<'a> {
+        gauge.gauge_style(Style {
+            bg: self.unfilled.fg,
+            ..self.filled
+        })
+    }
+
+    /// Applies the filled and unfilled styles to a [`LineGauge`].
+    pub fn apply_line<'a>(&self, gauge: LineGauge<'a>) -> LineGauge<'a> {
+        gauge
+            .filled_style(self.filled)
+            .unfilled_style(self.unfilled)
+    }
+
+    /// Returns a label span styled with [`label`](Self::label), to be
+    /// passed to `Gauge::label` or `LineGauge::label`.
+    pub fn label<'a>(&self, text: impl Into<Cow<'a, str>>) -> Span<'a> {
+        Span::styled(text, self.label)
+    }
+}
+
+impl From<&Base16Palette> for GaugeTheme {
+    fn from(palette: &Base16Palette) -> Self {
+        // The label is drawn over the fill, so it takes whichever end of the
+        // shades stands out more from it.
+        let contrast = |slot| color::contrast_ratio(slot, palette.base0d).unwrap_or(0.0);
+        let label = if contrast(palette.base07) > contrast(palette.base00) {
+            palette.base07
+        } else {
+            palette.base00
+        };
+        Self {
+            filled: Style::new().fg(palette.base0d),
+            unfilled: Style::new().fg(palette.base02),
+            label: Style::new().fg(label).add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DARK;
+
+    #[test]
+    fn table_rows_alternate() {
+        let theme = TableTheme::from(&DEFAULT_DARK);
+        assert_eq!(theme.row_style(0), theme.row);
+        assert_eq!(theme.row_style(1), theme.alt_row);
+        assert_eq!(theme.row_style(2), theme.row);
+    }
+
+    #[test]
+    fn focused_block_uses_accent() {
+        let theme = Theme::from(&DEFAULT_DARK);
+        assert_eq!(theme.block.focused_border.fg, Some(DEFAULT_DARK.base0d));
+        assert_eq!(theme.block.border.fg, Some(DEFAULT_DARK.base03));
+    }
+
+    #[test]
+    fn gauge_theme_is_readable_on_light_schemes() {
+        let theme = GaugeTheme::from(&crate::GITHUB_LIGHT);
+        let label_contrast =
+            crate::color::contrast_ratio(theme.label.fg.unwrap(), theme.filled.fg.unwrap());
+        assert!(label_contrast.unwrap() > 4.5);
+        assert_ne!(theme.filled, theme.unfilled);
+    }
+}
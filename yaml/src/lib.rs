@@ -0,0 +1,144 @@
+//! YAML helpers shared by [`ratatui-base16`](https://docs.rs/ratatui-base16)
+//! and its macros, so scheme files read the same at runtime and at compile
+//! time.
+//!
+//! This crate is an implementation detail of `ratatui-base16` and should not
+//! be used directly.
+
+use serde_json::{Map, Value};
+
+/// Reads slots written as bare integers in YAML, such as `base00: 282828`,
+/// by the digits they are written with.
+///
+/// YAML parses such values as decimal numbers, but scheme files always mean
+/// hex digits, so the scalar of every top-level key `is_slot` accepts is read
+/// from `text` and stored as a string for the color parser. A scalar that
+/// can't be found, as in a flow mapping, is read by its decimal digits, which
+/// fails for colors that lost leading zeros instead of silently producing
+/// another color.
+pub fn hex_integers(text: &str, table: &mut Map<String, Value>, is_slot: impl Fn(&str) -> bool) {
+    for (key, value) in table.iter_mut() {
+        let Value::Number(number) = value else {
+            continue;
+        };
+        if is_slot(key) {
+            let scalar = scalar_text(text, key).unwrap_or_else(|| number.to_string());
+            *value = Value::String(scalar);
+        }
+    }
+}
+
+/// Returns the text of the value of the top-level `key: value` line.
+///
+/// Only lines without indentation can hold keys of the top-level mapping, as
+/// nested collections are indented. The lines of block scalars are skipped,
+/// and so are those of quoted scalars, which may continue unindented.
+fn scalar_text(text: &str, key: &str) -> Option<String> {
+    let mut state = State::Plain;
+    for line in text.lines() {
+        match state {
+            State::Block if line.is_empty() || line.starts_with([' ', '\t']) => continue,
+            State::Quoted(_) => {}
+            _ => {
+                if let Some(value) = value_of(line, key) {
+                    let value = value.split(" #").next()?.trim();
+                    return (!value.is_empty()).then(|| value.to_string());
+                }
+                state = State::Plain;
+            }
+        }
+        state = scan(line, state);
+    }
+    None
+}
+
+/// What the end of a line leaves open.
+#[derive(Clone, Copy)]
+enum State {
+    Plain,
+    /// A `|` or `>` block scalar, whose indented lines follow.
+    Block,
+    /// A scalar quoted with the given character.
+    Quoted(char),
+}
+
+/// Returns what follows the colon if `line` defines `key`, quoted or not, at
+/// the start of the line.
+fn value_of<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let rest = [("\"", "\""), ("'", "'"), ("", "")]
+        .into_iter()
+        .find_map(|(open, close)| {
+            line.strip_prefix(open)?
+                .strip_prefix(key)?
+                .strip_prefix(close)
+        })?;
+    rest.trim_start_matches([' ', '\t']).strip_prefix(':')
+}
+
+/// Returns the state at the end of `line`, given the state at its start.
+fn scan(line: &str, mut state: State) -> State {
+    let mut chars = line.chars().peekable();
+    // Quotes only open a scalar at the start of a token, not in `don't`.
+    let mut token_start = true;
+    let mut last_token = None;
+    while let Some(c) = chars.next() {
+        match (state, c) {
+            (State::Quoted('"'), '\\') => {
+                chars.next();
+            }
+            (State::Quoted('\''), '\'') if chars.peek() == Some(&'\'') => {
+                chars.next();
+            }
+            (State::Quoted(open), c) if c == open => state = State::Plain,
+            (State::Quoted(_), _) => {}
+            (_, '"' | '\'') if token_start => state = State::Quoted(c),
+            (_, '#') if token_start => break,
+            (_, c) => {
+                if token_start && !c.is_whitespace() {
+                    last_token = Some(c);
+                }
+                token_start = c.is_whitespace() || ":[{,-".contains(c);
+            }
+        }
+    }
+    match (state, last_token) {
+        (State::Quoted(_), _) => state,
+        (_, Some('|' | '>')) => State::Block,
+        _ => State::Plain,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn only_top_level_scalars_are_read() {
+        let text = concat!(
+            "description: |\n  base00: was 111111\n  it's \"open\n\n",
+            "note: \"a quoted\nbase01: 222222\"\n",
+            "author: don't # base01: 333333\n",
+            "nested:\n  base02: 444444\n",
+            "base00: 282936 # background\n",
+            "'base01': 0x3a3c4e\n",
+            "base02: 4d4f68\n",
+        );
+        let mut table = Map::new();
+        table.insert("base00".into(), json!(282936));
+        table.insert("base01".into(), json!(0x3a3c4e));
+        table.insert("base02".into(), json!(4));
+        table.insert("author".into(), json!(1));
+        hex_integers(text, &mut table, |key| key.starts_with("base"));
+        assert_eq!(table["base00"], "282936");
+        assert_eq!(table["base01"], "0x3a3c4e");
+        assert_eq!(table["base02"], "4d4f68");
+        assert_eq!(table["author"], 1);
+
+        let mut table = Map::new();
+        table.insert("base00".into(), json!(282936));
+        hex_integers("{ base00: 282936 }", &mut table, |_| true);
+        assert_eq!(table["base00"], "282936");
+    }
+}